@@ -1,2 +1,29 @@
 pub const MERKLE_ROOT_SEED: &[u8] = b"merkle_root";
 pub const CLAIM_RECEIPT_SEED: &[u8] = b"receipt";
+pub const CLAIM_BITMAP_SEED: &[u8] = b"bitmap";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const METADATA_SEED: &[u8] = b"metadata";
+pub const CLOSED_AIRDROP_SEED: &[u8] = b"closed";
+pub const VAULT_RECORD_SEED: &[u8] = b"vault";
+/// Metadata string limits, in bytes, matching Metaplex token metadata
+pub const MAX_METADATA_NAME_LEN: usize = 32;
+pub const MAX_METADATA_URI_LEN: usize = 200;
+/// Funding fees are capped at 100% of the deposit
+pub const MAX_FEE_BPS: u16 = 10_000;
+/// Leaves tracked per `ClaimBitmap` account, keeping each under the 10 KiB limit for accounts created by CPI
+pub const CLAIM_BITMAP_BITS: u32 = 8 * 8192;
+
+/// Leaves are sha256(address || amount) and nodes sha256(left || right)
+pub const HASH_VERSION_V1: u8 = 1;
+/// Leaves and nodes carry distinct one-byte prefixes, so a leaf can never pass for a node
+pub const HASH_VERSION_V2: u8 = 2;
+/// Leaves are keccak256(keccak256(abi.encode(bytes32 address, uint256 amount))) and nodes keccak256
+/// of the sorted pair, so OpenZeppelin's `MerkleProof.verify` accepts the root and proofs
+pub const HASH_VERSION_KECCAK: u8 = 3;
+pub const LEAF_PREFIX: &[u8] = &[0x00];
+pub const NODE_PREFIX: &[u8] = &[0x01];
+
+/// Leaves commit to the base58 text of the claimant's address; encoding it on-chain is costly
+pub const LEAF_ENCODING_BASE58: u8 = 0;
+/// Leaves commit to the raw 32 pubkey bytes
+pub const LEAF_ENCODING_PUBKEY: u8 = 1;
@@ -6,4 +6,86 @@ pub enum AirdropError {
     InvalidAmount,
     #[msg("Invalid proof")] 
     InvalidProof,
+    #[msg("Expiry must be in the future")]
+    InvalidExpiry,
+    #[msg("Airdrop has not expired yet")]
+    AirdropNotExpired,
+    #[msg("Signer is not the airdrop authority")]
+    Unauthorized,
+    #[msg("A destination token account is required unless burning")]
+    MissingDestination,
+    #[msg("Claim window must open before expiry and end after it opens, no later than expiry")]
+    InvalidClaimWindow,
+    #[msg("Claiming has not started yet")]
+    ClaimNotStarted,
+    #[msg("Claiming has ended")]
+    ClaimEnded,
+    #[msg("Vesting schedule must satisfy start <= cliff <= end and start < end")]
+    InvalidVestingSchedule,
+    #[msg("Nothing is claimable yet")]
+    NothingToClaim,
+    #[msg("Only cumulative airdrops can publish a new root")]
+    NotCumulative,
+    #[msg("Airdrop is paused")]
+    AirdropPaused,
+    #[msg("Vault still holds tokens; claw them back first")]
+    VaultNotEmpty,
+    #[msg("Close the vaults of the airdrop's other mints first")]
+    VaultsStillOpen,
+    #[msg("Funding another mint of a multi-mint airdrop needs its vault record")]
+    VaultRecordMissing,
+    #[msg("Airdrop must be closed before its receipts")]
+    AirdropNotClosed,
+    #[msg("An airdrop at this address was closed and cannot be created again")]
+    AirdropAlreadyClosed,
+    #[msg("Voucher airdrops cannot be cumulative")]
+    InvalidVoucherConfig,
+    #[msg("Instruction does not match the airdrop's distribution type")]
+    WrongDistributionType,
+    #[msg("Voucher must be signed by the airdrop's voucher signer")]
+    InvalidVoucherSignature,
+    #[msg("Voucher has expired")]
+    VoucherExpired,
+    #[msg("Unsupported hash version")]
+    UnsupportedHashVersion,
+    #[msg("Unsupported leaf encoding")]
+    UnsupportedLeafEncoding,
+    #[msg("Claim bitmaps need 1 to 2^31 leaves and a one-shot, index-bound sha256 tree")]
+    InvalidBitmapConfig,
+    #[msg("Claim bitmap account does not cover this leaf")]
+    InvalidBitmapAccount,
+    #[msg("Leaf has already been claimed")]
+    AlreadyClaimed,
+    #[msg("Mint is not part of this airdrop")]
+    InvalidMint,
+    #[msg("Multi-mint airdrops only support receipt-based merkle claims")]
+    InvalidMultiMintConfig,
+    #[msg("SOL airdrops cannot be cumulative, multi-mint, voucher or bitmap airdrops")]
+    InvalidSolConfig,
+    #[msg("Vault does not hold enough to pay this claim")]
+    InsufficientVaultBalance,
+    #[msg("Recipient would hold less than the rent-exempt minimum; fund it with some SOL first")]
+    RecipientNotRentExempt,
+    #[msg("Claim would pay out more than the airdrop has been funded with")]
+    ClaimExceedsFunding,
+    #[msg("Mint-on-claim airdrops need the merkle root as mint authority and only support merkle claims with receipts")]
+    InvalidMintOnClaimConfig,
+    #[msg("Signer is not the config admin")]
+    NotConfigAdmin,
+    #[msg("Signer is not the program's upgrade authority")]
+    NotUpgradeAuthority,
+    #[msg("Funding fee cannot exceed 10000 basis points")]
+    InvalidFeeConfig,
+    #[msg("Fee recipient does not match the program config")]
+    InvalidFeeRecipient,
+    #[msg("Metadata name is limited to 32 bytes and URIs to 200 bytes")]
+    MetadataTooLong,
+    #[msg("Claim gate needs a minimum balance of at least 1")]
+    InvalidGateConfig,
+    #[msg("This airdrop is gated: pass the claimant's token account of the gate mint")]
+    GateAccountMissing,
+    #[msg("Gate token account is not the claimant's account of the gate mint")]
+    InvalidGateAccount,
+    #[msg("Claimant does not hold enough of the gate mint")]
+    GateBalanceTooLow,
 }
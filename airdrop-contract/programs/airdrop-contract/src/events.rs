@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+// `mint` is `Pubkey::default()` for SOL airdrops, matching `MerkleRoot::mint`
+
+#[event]
+#[derive(Debug)]
+pub struct AirdropCreated {
+    pub merkle_root: Pubkey,
+    pub root: [u8; 32],
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    /// Deposited into the vault, before any Token-2022 transfer fee
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct Claimed {
+    pub merkle_root: Pubkey,
+    pub root: [u8; 32],
+    /// Address named in the leaf; a claim link pays someone else on its behalf
+    pub claimant: Pubkey,
+    pub mint: Pubkey,
+    /// Paid out by this claim, which is less than the allocation while vesting
+    pub amount: u64,
+    pub leaf_index: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoucherClaimed {
+    pub merkle_root: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ClaimBitmapInitialized {
+    pub merkle_root: Pubkey,
+    pub index: u32,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AirdropFunded {
+    pub merkle_root: Pubkey,
+    pub mint: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RootUpdated {
+    pub merkle_root: Pubkey,
+    pub root: [u8; 32],
+    pub epoch: u32,
+    /// Top-up deposited alongside the new root
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct PauseSet {
+    pub merkle_root: Pubkey,
+    pub paused: bool,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ClawedBack {
+    pub merkle_root: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    /// `None` when the tokens were burned
+    pub destination: Option<Pubkey>,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VaultClosed {
+    pub merkle_root: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AirdropClosed {
+    pub merkle_root: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ClaimReceiptClosed {
+    pub merkle_root: Pubkey,
+    pub claimant: Pubkey,
+    pub payer: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ClaimBitmapClosed {
+    pub merkle_root: Pubkey,
+    pub index: u32,
+}
+
+/// Emitted when the config is initialized and whenever the admin changes it
+#[event]
+#[derive(Debug)]
+pub struct ConfigUpdated {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub fee_recipient: Pubkey,
+    /// 0 unless the fee is charged on deposits
+    pub funded_fee_bps: u16,
+    /// 0 unless the fee is charged per claim
+    pub claim_fee_lamports: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProtocolFeePaid {
+    pub merkle_root: Pubkey,
+    pub payer: Pubkey,
+    pub fee_recipient: Pubkey,
+    /// `Pubkey::default()` when the fee is paid in lamports
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct MetadataUpdated {
+    pub merkle_root: Pubkey,
+    pub metadata: Pubkey,
+    pub name: String,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct MetadataClosed {
+    pub merkle_root: Pubkey,
+    pub metadata: Pubkey,
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TransferChecked};
+use crate::errors::AirdropError;
+use crate::events::ProtocolFeePaid;
+use crate::state::Config;
+use crate::transfer;
+
+/// The program config, or `None` while it has not been initialized and no fees apply.
+///
+/// The caller pins `config` to the `[CONFIG_SEED]` PDA, so only this program can have written it.
+pub fn load_config(config: &AccountInfo) -> Result<Option<Config>> {
+    if config.data_is_empty() {
+        return Ok(None);
+    }
+    let data = config.try_borrow_data()?;
+    Ok(Some(Config::try_deserialize(&mut data.as_ref())?))
+}
+
+/// Charge the configured per-claim fee, if any, from `ctx.accounts.from` (the claim's fee payer).
+pub fn charge_claim_fee<'info>(
+    config: &AccountInfo<'info>,
+    ctx: CpiContext<'_, '_, '_, 'info, Transfer<'info>>,
+    merkle_root: Pubkey,
+) -> Result<()> {
+    let Some(config) = load_config(config)? else {
+        return Ok(());
+    };
+    charge_lamports(&config, ctx, merkle_root, config.fee.claim_fee())
+}
+
+/// Charge the configured funding fee on a deposit of `amount` lamports, if any.
+pub fn charge_sol_funding_fee<'info>(
+    config: &AccountInfo<'info>,
+    ctx: CpiContext<'_, '_, '_, 'info, Transfer<'info>>,
+    merkle_root: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let Some(config) = load_config(config)? else {
+        return Ok(());
+    };
+    charge_lamports(&config, ctx, merkle_root, config.fee.funding_fee(amount))
+}
+
+/// Charge the configured funding fee on a deposit of `amount` tokens, if any. `ctx` moves the
+/// fee from the depositor's token account to the fee recipient's associated token account.
+pub fn charge_token_funding_fee<'info>(
+    config: &AccountInfo<'info>,
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    mint: &InterfaceAccount<'info, Mint>,
+    merkle_root: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let Some(config) = load_config(config)? else {
+        return Ok(());
+    };
+    let fee = config.fee.funding_fee(amount);
+    if fee == 0 {
+        return Ok(());
+    }
+    let fee_recipient_token_account =
+        get_associated_token_address_with_program_id(&config.fee_recipient, &mint.key(), ctx.program.key);
+    require_keys_eq!(
+        ctx.accounts.to.key(),
+        fee_recipient_token_account,
+        AirdropError::InvalidFeeRecipient
+    );
+
+    let payer = ctx.accounts.authority.key();
+    transfer::transfer_checked(ctx, fee, mint.decimals)?;
+
+    emit!(ProtocolFeePaid {
+        merkle_root,
+        payer,
+        fee_recipient: config.fee_recipient,
+        mint: mint.key(),
+        amount: fee,
+    });
+
+    Ok(())
+}
+
+fn charge_lamports<'info>(
+    config: &Config,
+    ctx: CpiContext<'_, '_, '_, 'info, Transfer<'info>>,
+    merkle_root: Pubkey,
+    fee: u64,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+    require_keys_eq!(ctx.accounts.to.key(), config.fee_recipient, AirdropError::InvalidFeeRecipient);
+
+    let payer = ctx.accounts.from.key();
+    system_program::transfer(ctx, fee)?;
+
+    emit!(ProtocolFeePaid {
+        merkle_root,
+        payer,
+        fee_recipient: config.fee_recipient,
+        mint: Pubkey::default(),
+        amount: fee,
+    });
+
+    Ok(())
+}
@@ -1,16 +1,21 @@
-use anchor_lang::{prelude::*, solana_program::hash::hashv};
-use anchor_spl::{associated_token::AssociatedToken, token::{self, TransferChecked}, token_interface::{Mint, TokenAccount, TokenInterface}};
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::transfer::{self, ClaimFee, Payout};
 use crate::state::{ClaimReceipt, MerkleRoot};
-use crate::constants::CLAIM_RECEIPT_SEED;
+use crate::constants::{CLAIM_RECEIPT_SEED, CONFIG_SEED};
 use crate::errors::AirdropError;
+use crate::events::Claimed;
 
 #[derive(Accounts)]
 pub struct Claim<'info> {
-    #[account(mut)]
+    /// Recipient named in the leaf; only needs to sign
     pub authority: Signer<'info>,
+    /// Covers fees and rent; may be a sponsor or the recipient themselves
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         init_if_needed,
-        payer = authority,
+        payer = payer,
         associated_token::mint = mint,
         associated_token::authority = authority,
         associated_token::token_program = token_program,
@@ -24,60 +29,73 @@ pub struct Claim<'info> {
     )]
     pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
     pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, constraint = merkle_root.accepts_mint(&mint.key()) @ AirdropError::InvalidMint)]
     pub merkle_root: Account<'info, MerkleRoot>,
     #[account(
-        init,
-        payer = authority,
+        init_if_needed,
+        payer = payer,
         space = 8 + ClaimReceipt::INIT_SPACE,
-        seeds = [CLAIM_RECEIPT_SEED, merkle_root.key().as_ref(), authority.key().as_ref()],
+        seeds = [
+            CLAIM_RECEIPT_SEED,
+            merkle_root.key().as_ref(),
+            authority.key().as_ref(),
+            ClaimReceipt::mint_seed(merkle_root.receipt_mint(mint.to_account_info().key)),
+        ],
         bump
     )]
     pub claim_receipt: Account<'info, ClaimReceipt>,
+    /// The claimant's token account of the gate mint; required when the airdrop is gated
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the config's fee recipient when a claim fee is charged
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub fn handler(ctx: Context<Claim>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Claim<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
     let merkle_root = &ctx.accounts.merkle_root;
+    let now = Clock::get()?.unix_timestamp;
+    merkle_root.verify_claim(&ctx.accounts.authority.key(), &ctx.accounts.mint.key(), &proof, amount, leaf_index, now)?;
 
-    let address = &ctx.accounts.authority.key().to_string();
-    let mut hash = hashv(&[address.as_bytes(), &amount.to_le_bytes()]);
-    let mut proof_index = leaf_index;
-
-    for neighbor_hash in proof {
-        if proof_index % 2 == 0 {
-            hash = hashv(&[hash.as_ref(), neighbor_hash.as_ref()]);
-        } else {
-            hash = hashv(&[neighbor_hash.as_ref(), hash.as_ref()]);
-        }
+    // Release whatever has vested since the last claim
+    let claimable = merkle_root.claimable_amount(amount, ctx.accounts.claim_receipt.claimed_so_far, now)?;
 
-        proof_index /= 2;
-    }
+    transfer::pay_claim(
+        &mut ctx.accounts.merkle_root,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.gate_token_account.as_deref(),
+        Payout::Vault {
+            vault: &ctx.accounts.merkle_root_token_account,
+            destination: &ctx.accounts.authority_token_account,
+            mint: &ctx.accounts.mint,
+            token_program: &ctx.accounts.token_program,
+            hook_accounts: ctx.remaining_accounts,
+        },
+        ClaimFee {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.config.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        claimable,
+    )?;
+    ctx.accounts.claim_receipt.record_claim(ctx.accounts.payer.key(), amount, claimable);
 
-    if hash.as_ref() != merkle_root.hash {
-        return Err(AirdropError::InvalidProof.into());
-    }
-
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let accounts = TransferChecked {
-        from: ctx.accounts.merkle_root_token_account.to_account_info(),
-        to: ctx.accounts.authority_token_account.to_account_info(),
-        authority: ctx.accounts.merkle_root.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-    };
-
-    let seeds = &[
-        crate::constants::MERKLE_ROOT_SEED,
-        merkle_root.hash.as_ref(),
-        &[ctx.accounts.merkle_root.bump],
-    ];
-    let signer = &[&seeds[..]];
-
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, accounts, signer);
-    token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    let merkle_root = &ctx.accounts.merkle_root;
+    emit!(Claimed {
+        merkle_root: merkle_root.key(),
+        root: merkle_root.hash,
+        claimant: ctx.accounts.authority.key(),
+        mint: ctx.accounts.mint.key(),
+        amount: claimable,
+        leaf_index,
+        timestamp: now,
+    });
 
-    ctx.accounts.claim_receipt.amount = amount;
-    
     Ok(())
-}
\ No newline at end of file
+}
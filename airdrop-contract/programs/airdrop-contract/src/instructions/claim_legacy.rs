@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked}};
+use crate::transfer;
+use crate::state::{ClaimReceipt, LegacyMerkleRoot};
+use crate::constants::CLAIM_RECEIPT_SEED;
+use crate::events::Claimed;
+
+#[derive(Accounts)]
+pub struct ClaimLegacy<'info> {
+    /// Recipient named in the leaf; only needs to sign
+    pub authority: Signer<'info>,
+    /// Covers rent; may be a sponsor or the recipient themselves
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: first-release merkle root; `LegacyMerkleRoot::load` checks its owner, layout,
+    /// seeds and mint
+    pub merkle_root: UncheckedAccount<'info>,
+    /// Claims were one-shot, so an existing receipt, including one the first release wrote,
+    /// means the leaf has been paid
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ClaimReceipt::INIT_SPACE,
+        seeds = [CLAIM_RECEIPT_SEED, merkle_root.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Pay out a leaf of an airdrop created by the program's first release, whose terms had no
+/// window, vesting or protocol fee.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimLegacy<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+    let merkle_root = LegacyMerkleRoot::load(&ctx.accounts.merkle_root, &ctx.accounts.mint.key())?;
+    merkle_root.verify_proof(&ctx.accounts.authority.key(), &proof, amount, leaf_index)?;
+
+    let seeds = merkle_root.signer_seeds();
+    let signer = &[&seeds[..]];
+    let accounts = TransferChecked {
+        from: ctx.accounts.merkle_root_token_account.to_account_info(),
+        to: ctx.accounts.authority_token_account.to_account_info(),
+        authority: ctx.accounts.merkle_root.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), accounts, signer)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    transfer::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    ctx.accounts.claim_receipt.record_claim(ctx.accounts.payer.key(), amount, amount);
+
+    emit!(Claimed {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        root: merkle_root.hash,
+        claimant: ctx.accounts.authority.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        leaf_index,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
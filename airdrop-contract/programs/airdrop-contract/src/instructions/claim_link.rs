@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::transfer::{self, ClaimFee, Payout};
+use crate::state::{ClaimReceipt, MerkleRoot};
+use crate::constants::{CLAIM_RECEIPT_SEED, CONFIG_SEED};
+use crate::errors::AirdropError;
+use crate::events::Claimed;
+
+/// Claim an allocation whose leaf names a throwaway keypair handed out in a
+/// claim link. The link holder signs with that key and picks the destination.
+#[derive(Accounts)]
+pub struct ClaimLink<'info> {
+    /// Ephemeral key from the claim link; named in the leaf
+    pub authority: Signer<'info>,
+    /// Covers fees and rent; usually the destination wallet or a sponsor
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: any wallet chosen by the link holder; only owns the destination token account
+    pub destination: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = destination,
+        associated_token::token_program = token_program,
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, constraint = merkle_root.accepts_mint(&mint.key()) @ AirdropError::InvalidMint)]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ClaimReceipt::INIT_SPACE,
+        seeds = [
+            CLAIM_RECEIPT_SEED,
+            merkle_root.key().as_ref(),
+            authority.key().as_ref(),
+            ClaimReceipt::mint_seed(merkle_root.receipt_mint(mint.to_account_info().key)),
+        ],
+        bump
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+    /// The destination's token account of the gate mint; required when the airdrop is gated
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the config's fee recipient when a claim fee is charged
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimLink<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+    let now = Clock::get()?.unix_timestamp;
+    merkle_root.verify_claim(&ctx.accounts.authority.key(), &ctx.accounts.mint.key(), &proof, amount, leaf_index, now)?;
+
+    let claimable = merkle_root.claimable_amount(amount, ctx.accounts.claim_receipt.claimed_so_far, now)?;
+
+    transfer::pay_claim(
+        &mut ctx.accounts.merkle_root,
+        &ctx.accounts.destination.key(),
+        ctx.accounts.gate_token_account.as_deref(),
+        Payout::Vault {
+            vault: &ctx.accounts.merkle_root_token_account,
+            destination: &ctx.accounts.destination_token_account,
+            mint: &ctx.accounts.mint,
+            token_program: &ctx.accounts.token_program,
+            hook_accounts: ctx.remaining_accounts,
+        },
+        ClaimFee {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.config.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        claimable,
+    )?;
+    ctx.accounts.claim_receipt.record_claim(ctx.accounts.payer.key(), amount, claimable);
+
+    let merkle_root = &ctx.accounts.merkle_root;
+    emit!(Claimed {
+        merkle_root: merkle_root.key(),
+        root: merkle_root.hash,
+        claimant: ctx.accounts.authority.key(),
+        mint: ctx.accounts.mint.key(),
+        amount: claimable,
+        leaf_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::transfer::{self, ClaimFee, Payout};
+use crate::state::{ClaimReceipt, MerkleRoot};
+use crate::constants::{CLAIM_RECEIPT_SEED, CONFIG_SEED};
+use crate::errors::AirdropError;
+use crate::events::Claimed;
+
+#[derive(Accounts)]
+pub struct ClaimMint<'info> {
+    /// Recipient named in the leaf; only needs to sign
+    pub authority: Signer<'info>,
+    /// Covers fees and rent; may be a sponsor or the recipient themselves
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        has_one = mint,
+        constraint = merkle_root.mint_on_claim @ AirdropError::WrongDistributionType,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ClaimReceipt::INIT_SPACE,
+        seeds = [CLAIM_RECEIPT_SEED, merkle_root.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+    /// The claimant's token account of the gate mint; required when the airdrop is gated
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the config's fee recipient when a claim fee is charged
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<ClaimMint>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+    let now = Clock::get()?.unix_timestamp;
+    merkle_root.verify_claim(&ctx.accounts.authority.key(), &ctx.accounts.mint.key(), &proof, amount, leaf_index, now)?;
+
+    // Release whatever has vested since the last claim
+    let claimable = merkle_root.claimable_amount(amount, ctx.accounts.claim_receipt.claimed_so_far, now)?;
+
+    transfer::pay_claim(
+        &mut ctx.accounts.merkle_root,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.gate_token_account.as_deref(),
+        Payout::MintTo {
+            destination: &ctx.accounts.authority_token_account,
+            mint: &ctx.accounts.mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        ClaimFee {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.config.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        claimable,
+    )?;
+    ctx.accounts.claim_receipt.record_claim(ctx.accounts.payer.key(), amount, claimable);
+
+    let merkle_root = &ctx.accounts.merkle_root;
+    emit!(Claimed {
+        merkle_root: merkle_root.key(),
+        root: merkle_root.hash,
+        claimant: ctx.accounts.authority.key(),
+        mint: merkle_root.mint,
+        amount: claimable,
+        leaf_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::transfer::{self, ClaimFee, Payout};
+use crate::state::{ClaimReceipt, MerkleRoot};
+use crate::constants::{CLAIM_RECEIPT_SEED, CONFIG_SEED};
+use crate::errors::AirdropError;
+use crate::events::Claimed;
+
+#[derive(Accounts)]
+pub struct ClaimSol<'info> {
+    /// Recipient named in the leaf; signs and receives the lamports
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// Covers fees and rent; may be a sponsor or the recipient themselves
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, constraint = merkle_root.native_sol @ AirdropError::WrongDistributionType)]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ClaimReceipt::INIT_SPACE,
+        seeds = [CLAIM_RECEIPT_SEED, merkle_root.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+    /// The claimant's token account of the gate mint; required when the airdrop is gated
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the config's fee recipient when a claim fee is charged
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimSol>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+    let now = Clock::get()?.unix_timestamp;
+    merkle_root.verify_claim(&ctx.accounts.authority.key(), &merkle_root.mint, &proof, amount, leaf_index, now)?;
+
+    // Release whatever has vested since the last claim
+    let claimable = merkle_root.claimable_amount(amount, ctx.accounts.claim_receipt.claimed_so_far, now)?;
+
+    transfer::pay_claim(
+        &mut ctx.accounts.merkle_root,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.gate_token_account.as_deref(),
+        Payout::Lamports { recipient: ctx.accounts.authority.to_account_info() },
+        ClaimFee {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.config.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        claimable,
+    )?;
+    ctx.accounts.claim_receipt.record_claim(ctx.accounts.payer.key(), amount, claimable);
+
+    let merkle_root = &ctx.accounts.merkle_root;
+    emit!(Claimed {
+        merkle_root: merkle_root.key(),
+        root: merkle_root.hash,
+        claimant: ctx.accounts.authority.key(),
+        mint: merkle_root.mint,
+        amount: claimable,
+        leaf_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
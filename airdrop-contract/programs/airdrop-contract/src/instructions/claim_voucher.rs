@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::transfer::{self, ClaimFee, Payout};
+use crate::state::{ClaimReceipt, MerkleRoot, Voucher};
+use crate::constants::{CLAIM_RECEIPT_SEED, CONFIG_SEED};
+use crate::errors::AirdropError;
+use crate::events::VoucherClaimed;
+
+/// Claim from a voucher airdrop. The transaction must include an Ed25519
+/// program instruction, immediately before this one, verifying the voucher.
+#[derive(Accounts)]
+#[instruction(voucher: Voucher)]
+pub struct ClaimVoucher<'info> {
+    /// CHECK: must match the voucher's recipient; only owns the destination token account
+    #[account(address = voucher.recipient)]
+    pub recipient: UncheckedAccount<'info>,
+    /// Covers fees and rent; the recipient or anyone relaying the voucher
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, has_one = mint)]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ClaimReceipt::INIT_SPACE,
+        seeds = [CLAIM_RECEIPT_SEED, merkle_root.key().as_ref(), voucher.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+    /// CHECK: the instructions sysvar, checked by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    /// The recipient's token account of the gate mint; required when the airdrop is gated
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the config's fee recipient when a claim fee is charged
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimVoucher<'info>>, voucher: Voucher) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+    let signer = merkle_root.voucher_signer.ok_or(AirdropError::WrongDistributionType)?;
+    let now = Clock::get()?.unix_timestamp;
+    merkle_root.check_claimable(now)?;
+    require!(now < voucher.expires_at, AirdropError::VoucherExpired);
+    voucher.verify_signature(&ctx.accounts.instructions_sysvar, &signer, &merkle_root.key())?;
+
+    let claimable = merkle_root.claimable_amount(voucher.amount, ctx.accounts.claim_receipt.claimed_so_far, now)?;
+
+    transfer::pay_claim(
+        &mut ctx.accounts.merkle_root,
+        &ctx.accounts.recipient.key(),
+        ctx.accounts.gate_token_account.as_deref(),
+        Payout::Vault {
+            vault: &ctx.accounts.merkle_root_token_account,
+            destination: &ctx.accounts.recipient_token_account,
+            mint: &ctx.accounts.mint,
+            token_program: &ctx.accounts.token_program,
+            hook_accounts: ctx.remaining_accounts,
+        },
+        ClaimFee {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.config.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        claimable,
+    )?;
+    ctx.accounts.claim_receipt.record_claim(ctx.accounts.payer.key(), voucher.amount, claimable);
+
+    let merkle_root = &ctx.accounts.merkle_root;
+    emit!(VoucherClaimed {
+        merkle_root: merkle_root.key(),
+        recipient: voucher.recipient,
+        mint: ctx.accounts.mint.key(),
+        amount: claimable,
+        nonce: voucher.nonce,
+        timestamp: now,
+    });
+
+    Ok(())
+}
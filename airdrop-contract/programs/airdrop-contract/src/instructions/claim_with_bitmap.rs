@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::transfer::{self, ClaimFee, Payout};
+use crate::state::{ClaimBitmap, MerkleRoot};
+use crate::constants::{CLAIM_BITMAP_SEED, CONFIG_SEED};
+use crate::errors::AirdropError;
+use crate::events::Claimed;
+
+#[derive(Accounts)]
+pub struct ClaimWithBitmap<'info> {
+    /// Recipient named in the leaf; only needs to sign
+    pub authority: Signer<'info>,
+    /// Covers fees and token account rent; may be a sponsor or the recipient themselves
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, has_one = mint)]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        mut,
+        seeds = [CLAIM_BITMAP_SEED, merkle_root.key().as_ref(), &claim_bitmap.index.to_le_bytes()],
+        bump = claim_bitmap.bump,
+    )]
+    pub claim_bitmap: Account<'info, ClaimBitmap>,
+    /// The claimant's token account of the gate mint; required when the airdrop is gated
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the config's fee recipient when a claim fee is charged
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimWithBitmap<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+    let now = Clock::get()?.unix_timestamp;
+    let position = merkle_root.verify_bitmap_claim(&ctx.accounts.authority.key(), &proof, amount, leaf_index, now)?;
+
+    let claim_bitmap = &mut ctx.accounts.claim_bitmap;
+    let (index, bit) = ClaimBitmap::locate(position);
+    require!(claim_bitmap.index == index, AirdropError::InvalidBitmapAccount);
+    claim_bitmap.set_claimed(bit)?;
+
+    transfer::pay_claim(
+        &mut ctx.accounts.merkle_root,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.gate_token_account.as_deref(),
+        Payout::Vault {
+            vault: &ctx.accounts.merkle_root_token_account,
+            destination: &ctx.accounts.authority_token_account,
+            mint: &ctx.accounts.mint,
+            token_program: &ctx.accounts.token_program,
+            hook_accounts: ctx.remaining_accounts,
+        },
+        ClaimFee {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.config.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        amount,
+    )?;
+
+    let merkle_root = &ctx.accounts.merkle_root;
+    emit!(Claimed {
+        merkle_root: merkle_root.key(),
+        root: merkle_root.hash,
+        claimant: ctx.accounts.authority.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        leaf_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
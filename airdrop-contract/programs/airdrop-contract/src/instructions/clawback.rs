@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::transfer;
+use crate::state::MerkleRoot;
+use crate::errors::AirdropError;
+use crate::events::ClawedBack;
+
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority @ AirdropError::Unauthorized,
+        constraint = merkle_root.accepts_mint(&mint.key()) @ AirdropError::InvalidMint,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Receives the unclaimed balance. Omit when burning.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Clawback<'info>>, burn: bool) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+
+    if Clock::get()?.unix_timestamp < merkle_root.expires_at {
+        return Err(AirdropError::AirdropNotExpired.into());
+    }
+
+    let amount = ctx.accounts.merkle_root_token_account.amount;
+    let seeds = merkle_root.signer_seeds();
+    let signer = &[&seeds[..]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    let destination = if burn {
+        let accounts = Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.merkle_root_token_account.to_account_info(),
+            authority: merkle_root.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, accounts, signer);
+        token_interface::burn(cpi_ctx, amount)?;
+        None
+    } else {
+        let destination = ctx
+            .accounts
+            .destination_token_account
+            .as_ref()
+            .ok_or(AirdropError::MissingDestination)?;
+        let accounts = TransferChecked {
+            from: ctx.accounts.merkle_root_token_account.to_account_info(),
+            to: destination.to_account_info(),
+            authority: merkle_root.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, accounts, signer).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+        transfer::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+        Some(destination.key())
+    };
+
+    emit!(ClawedBack {
+        merkle_root: merkle_root.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        destination,
+    });
+
+    Ok(())
+}
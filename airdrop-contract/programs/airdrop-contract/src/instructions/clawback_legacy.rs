@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::transfer;
+use crate::state::LegacyMerkleRoot;
+use crate::errors::AirdropError;
+use crate::events::ClawedBack;
+use crate::program::AirdropContract;
+
+/// Legacy airdrops record no authority or expiry, so the program's upgrade authority may
+/// return their unclaimed tokens at any time, typically to whoever created the airdrop.
+#[derive(Accounts)]
+pub struct ClawbackLegacy<'info> {
+    pub authority: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, AirdropContract>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ AirdropError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    /// CHECK: first-release merkle root; `LegacyMerkleRoot::load` checks its owner, layout,
+    /// seeds and mint
+    pub merkle_root: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Receives the unclaimed balance
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClawbackLegacy<'info>>) -> Result<()> {
+    let merkle_root = LegacyMerkleRoot::load(&ctx.accounts.merkle_root, &ctx.accounts.mint.key())?;
+    let amount = ctx.accounts.merkle_root_token_account.amount;
+
+    let seeds = merkle_root.signer_seeds();
+    let signer = &[&seeds[..]];
+    let accounts = TransferChecked {
+        from: ctx.accounts.merkle_root_token_account.to_account_info(),
+        to: ctx.accounts.destination_token_account.to_account_info(),
+        authority: ctx.accounts.merkle_root.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), accounts, signer)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    transfer::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    emit!(ClawedBack {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        destination: Some(ctx.accounts.destination_token_account.key()),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::MerkleRoot;
+use crate::errors::AirdropError;
+use crate::events::ClawedBack;
+
+#[derive(Accounts)]
+pub struct ClawbackSol<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ AirdropError::Unauthorized,
+        constraint = merkle_root.native_sol @ AirdropError::WrongDistributionType,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+}
+
+/// Send the unclaimed lamports of an expired SOL airdrop to `destination`, leaving the rent.
+pub fn handler(ctx: Context<ClawbackSol>) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+
+    if Clock::get()?.unix_timestamp < merkle_root.expires_at {
+        return Err(AirdropError::AirdropNotExpired.into());
+    }
+
+    let amount = MerkleRoot::sol_balance(&merkle_root.to_account_info())?;
+    ctx.accounts.merkle_root.sub_lamports(amount)?;
+    ctx.accounts.destination.add_lamports(amount)?;
+
+    emit!(ClawedBack {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        mint: Pubkey::default(),
+        amount,
+        destination: Some(ctx.accounts.destination.key()),
+    });
+
+    Ok(())
+}
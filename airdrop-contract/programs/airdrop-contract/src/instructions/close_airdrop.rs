@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface};
+use crate::state::{ClosedAirdrop, MerkleRoot};
+use crate::constants::CLOSED_AIRDROP_SEED;
+use crate::errors::AirdropError;
+use crate::events::AirdropClosed;
+
+#[derive(Accounts)]
+pub struct CloseAirdrop<'info> {
+    /// Receives the rent of the merkle root account and its vault
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ AirdropError::Unauthorized,
+        has_one = mint,
+        // SOL and mint-on-claim airdrops have their own close instructions
+        constraint = !merkle_root.mint_on_claim && !merkle_root.native_sol @ AirdropError::WrongDistributionType,
+        close = authority,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    /// Stops the merkle root from ever being created again once its receipts can be closed
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ClosedAirdrop::INIT_SPACE,
+        seeds = [CLOSED_AIRDROP_SEED, merkle_root.key().as_ref()],
+        bump
+    )]
+    pub closed_airdrop: Account<'info, ClosedAirdrop>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CloseAirdrop>) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+
+    let now = Clock::get()?.unix_timestamp;
+    if now < merkle_root.expires_at {
+        return Err(AirdropError::AirdropNotExpired.into());
+    }
+    // A multi-mint airdrop's other vaults could not be emptied once the merkle root is gone
+    require!(merkle_root.open_vaults == 0, AirdropError::VaultsStillOpen);
+    // Unclaimed tokens must be clawed back first
+    if ctx.accounts.merkle_root_token_account.amount != 0 {
+        return Err(AirdropError::VaultNotEmpty.into());
+    }
+
+    let seeds = merkle_root.signer_seeds();
+    let signer = &[&seeds[..]];
+    let accounts = CloseAccount {
+        account: ctx.accounts.merkle_root_token_account.to_account_info(),
+        destination: ctx.accounts.authority.to_account_info(),
+        authority: merkle_root.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), accounts, signer);
+    token_interface::close_account(cpi_ctx)?;
+
+    ctx.accounts.closed_airdrop.closed_at = now;
+    ctx.accounts.closed_airdrop.authority = ctx.accounts.authority.key();
+
+    emit!(AirdropClosed {
+        merkle_root: merkle_root.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{ClaimBitmap, ClosedAirdrop};
+use crate::constants::{CLAIM_BITMAP_SEED, CLOSED_AIRDROP_SEED};
+use crate::errors::AirdropError;
+use crate::events::ClaimBitmapClosed;
+
+/// The authority that closed a bitmap airdrop takes back the rent it paid for the
+/// bitmaps. Only allowed once the airdrop is closed, when no claim can read them.
+#[derive(Accounts)]
+pub struct CloseClaimBitmap<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: must be the closed merkle root account the bitmap belongs to
+    #[account(constraint = merkle_root.data_is_empty() @ AirdropError::AirdropNotClosed)]
+    pub merkle_root: UncheckedAccount<'info>,
+    #[account(
+        has_one = authority @ AirdropError::Unauthorized,
+        seeds = [CLOSED_AIRDROP_SEED, merkle_root.key().as_ref()],
+        bump
+    )]
+    pub closed_airdrop: Account<'info, ClosedAirdrop>,
+    #[account(
+        mut,
+        seeds = [CLAIM_BITMAP_SEED, merkle_root.key().as_ref(), &claim_bitmap.index.to_le_bytes()],
+        bump = claim_bitmap.bump,
+        close = authority,
+    )]
+    pub claim_bitmap: Account<'info, ClaimBitmap>,
+}
+
+pub fn handler(ctx: Context<CloseClaimBitmap>) -> Result<()> {
+    emit!(ClaimBitmapClosed {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        index: ctx.accounts.claim_bitmap.index,
+    });
+
+    Ok(())
+}
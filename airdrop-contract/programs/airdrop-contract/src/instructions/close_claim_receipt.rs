@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::ClaimReceipt;
+use crate::constants::CLAIM_RECEIPT_SEED;
+use crate::errors::AirdropError;
+use crate::events::ClaimReceiptClosed;
+
+/// Anyone may close a receipt once its airdrop has been closed; the rent
+/// always goes back to whoever paid for it on the first claim.
+#[derive(Accounts)]
+pub struct CloseClaimReceipt<'info> {
+    /// CHECK: only used to derive the receipt address
+    pub claimant: UncheckedAccount<'info>,
+    /// CHECK: receives the rent; must match the payer recorded on the receipt
+    #[account(mut, address = claim_receipt.payer)]
+    pub payer: UncheckedAccount<'info>,
+    /// CHECK: must be the closed merkle root account the receipt was issued for
+    #[account(constraint = merkle_root.data_is_empty() @ AirdropError::AirdropNotClosed)]
+    pub merkle_root: UncheckedAccount<'info>,
+    /// CHECK: only used to derive receipt addresses of a multi-mint airdrop
+    pub mint: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [
+            CLAIM_RECEIPT_SEED,
+            merkle_root.key().as_ref(),
+            claimant.key().as_ref(),
+            ClaimReceipt::mint_seed(mint.as_ref().map(|mint| mint.key)),
+        ],
+        bump,
+        close = payer,
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+}
+
+pub fn handler(ctx: Context<CloseClaimReceipt>) -> Result<()> {
+    emit!(ClaimReceiptClosed {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        claimant: ctx.accounts.claimant.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
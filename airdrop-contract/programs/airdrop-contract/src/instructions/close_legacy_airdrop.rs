@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface};
+use crate::state::LegacyMerkleRoot;
+use crate::errors::AirdropError;
+use crate::events::AirdropClosed;
+use crate::program::AirdropContract;
+
+/// Legacy airdrops record no authority, so the program's upgrade authority closes them. No
+/// tombstone is left: nothing can create an account at legacy seeds any more.
+#[derive(Accounts)]
+pub struct CloseLegacyAirdrop<'info> {
+    /// Receives the rent of the merkle root account and its vault
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, AirdropContract>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ AirdropError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    /// CHECK: first-release merkle root; `LegacyMerkleRoot::load` checks its owner, layout,
+    /// seeds and mint
+    #[account(mut)]
+    pub merkle_root: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CloseLegacyAirdrop>) -> Result<()> {
+    let merkle_root = LegacyMerkleRoot::load(&ctx.accounts.merkle_root, &ctx.accounts.mint.key())?;
+
+    // Unclaimed tokens must be clawed back or claimed first
+    if ctx.accounts.merkle_root_token_account.amount != 0 {
+        return Err(AirdropError::VaultNotEmpty.into());
+    }
+
+    let seeds = merkle_root.signer_seeds();
+    let signer = &[&seeds[..]];
+    let accounts = CloseAccount {
+        account: ctx.accounts.merkle_root_token_account.to_account_info(),
+        destination: ctx.accounts.authority.to_account_info(),
+        authority: ctx.accounts.merkle_root.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), accounts, signer);
+    token_interface::close_account(cpi_ctx)?;
+
+    // Not an `#[account]`, so close the root by hand the way `close = authority` would
+    let root = ctx.accounts.merkle_root.to_account_info();
+    ctx.accounts.authority.add_lamports(root.lamports())?;
+    root.sub_lamports(root.lamports())?;
+    root.assign(&System::id());
+    root.resize(0)?;
+
+    emit!(AirdropClosed {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
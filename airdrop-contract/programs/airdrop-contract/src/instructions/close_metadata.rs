@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::{AirdropMetadata, MerkleRoot};
+use crate::constants::METADATA_SEED;
+use crate::errors::AirdropError;
+use crate::events::MetadataClosed;
+
+/// Returns the metadata's rent; done before closing the merkle root, while the
+/// authority can still be checked against it.
+#[derive(Accounts)]
+pub struct CloseMetadata<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(has_one = authority @ AirdropError::Unauthorized)]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, merkle_root.key().as_ref()],
+        bump = metadata.bump,
+        close = authority,
+    )]
+    pub metadata: Account<'info, AirdropMetadata>,
+}
+
+pub fn handler(ctx: Context<CloseMetadata>) -> Result<()> {
+    emit!(MetadataClosed {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        metadata: ctx.accounts.metadata.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{self, Mint, SetAuthority, TokenInterface};
+use crate::state::{ClosedAirdrop, MerkleRoot};
+use crate::constants::CLOSED_AIRDROP_SEED;
+use crate::errors::AirdropError;
+use crate::events::AirdropClosed;
+
+#[derive(Accounts)]
+pub struct CloseMintAirdrop<'info> {
+    /// Receives the rent and the mint authority back
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ AirdropError::Unauthorized,
+        has_one = mint,
+        constraint = merkle_root.mint_on_claim @ AirdropError::WrongDistributionType,
+        close = authority,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    /// Stops the merkle root from ever being created again once its receipts can be closed
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ClosedAirdrop::INIT_SPACE,
+        seeds = [CLOSED_AIRDROP_SEED, merkle_root.key().as_ref()],
+        bump
+    )]
+    pub closed_airdrop: Account<'info, ClosedAirdrop>,
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Close an expired mint-on-claim airdrop. Unclaimed tokens were never minted, so there is
+/// nothing to claw back; the mint authority returns to the airdrop authority.
+pub fn handler(ctx: Context<CloseMintAirdrop>) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+
+    let now = Clock::get()?.unix_timestamp;
+    if now < merkle_root.expires_at {
+        return Err(AirdropError::AirdropNotExpired.into());
+    }
+
+    let seeds = merkle_root.signer_seeds();
+    let signer = &[&seeds[..]];
+    let accounts = SetAuthority {
+        current_authority: merkle_root.to_account_info(),
+        account_or_mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), accounts, signer);
+    token_interface::set_authority(cpi_ctx, AuthorityType::MintTokens, Some(ctx.accounts.authority.key()))?;
+
+    ctx.accounts.closed_airdrop.closed_at = now;
+    ctx.accounts.closed_airdrop.authority = ctx.accounts.authority.key();
+
+    emit!(AirdropClosed {
+        merkle_root: merkle_root.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{ClosedAirdrop, MerkleRoot};
+use crate::constants::CLOSED_AIRDROP_SEED;
+use crate::errors::AirdropError;
+use crate::events::AirdropClosed;
+
+#[derive(Accounts)]
+pub struct CloseSolAirdrop<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ AirdropError::Unauthorized,
+        constraint = merkle_root.native_sol @ AirdropError::WrongDistributionType,
+        close = authority,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    /// Stops the merkle root from ever being created again once its receipts can be closed
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ClosedAirdrop::INIT_SPACE,
+        seeds = [CLOSED_AIRDROP_SEED, merkle_root.key().as_ref()],
+        bump
+    )]
+    pub closed_airdrop: Account<'info, ClosedAirdrop>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Close an expired SOL airdrop. The merkle root is its own vault, so the rent and any
+/// lamports not yet clawed back both go to the authority.
+pub fn handler(ctx: Context<CloseSolAirdrop>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    if now < ctx.accounts.merkle_root.expires_at {
+        return Err(AirdropError::AirdropNotExpired.into());
+    }
+
+    ctx.accounts.closed_airdrop.closed_at = now;
+    ctx.accounts.closed_airdrop.authority = ctx.accounts.authority.key();
+
+    emit!(AirdropClosed {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface};
+use crate::state::{MerkleRoot, VaultRecord};
+use crate::constants::VAULT_RECORD_SEED;
+use crate::errors::AirdropError;
+use crate::events::VaultClosed;
+
+/// Closes the vault of one of a multi-mint airdrop's additional mints. The vault of
+/// `merkle_root.mint` is closed together with the airdrop by `close_airdrop`.
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ AirdropError::Unauthorized,
+        constraint = merkle_root.multi_mint && mint.key() != merkle_root.mint @ AirdropError::InvalidMint,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        mut,
+        seeds = [VAULT_RECORD_SEED, merkle_root.key().as_ref(), mint.key().as_ref()],
+        bump,
+        close = authority,
+    )]
+    pub vault_record: Account<'info, VaultRecord>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CloseVault>) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+
+    if Clock::get()?.unix_timestamp < merkle_root.expires_at {
+        return Err(AirdropError::AirdropNotExpired.into());
+    }
+    if ctx.accounts.merkle_root_token_account.amount != 0 {
+        return Err(AirdropError::VaultNotEmpty.into());
+    }
+
+    let seeds = merkle_root.signer_seeds();
+    let signer = &[&seeds[..]];
+    let accounts = CloseAccount {
+        account: ctx.accounts.merkle_root_token_account.to_account_info(),
+        destination: ctx.accounts.authority.to_account_info(),
+        authority: merkle_root.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), accounts, signer);
+    token_interface::close_account(cpi_ctx)?;
+
+    let merkle_root = &mut ctx.accounts.merkle_root;
+    merkle_root.open_vaults -= 1;
+
+    emit!(VaultClosed {
+        merkle_root: merkle_root.key(),
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
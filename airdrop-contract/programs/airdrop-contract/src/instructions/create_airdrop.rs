@@ -1,11 +1,42 @@
 use anchor_lang::prelude::*;
 use anchor_lang::prelude::InterfaceAccount;
-use anchor_spl::{associated_token::AssociatedToken, token::{self, TransferChecked}, token_interface::{Mint, TokenAccount, TokenInterface}};
-use crate::state::merkle_root::MerkleRoot;
-use crate::constants::MERKLE_ROOT_SEED;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked}};
+use crate::{fees, transfer};
+use crate::state::{merkle_root::MerkleRoot, ClaimGate, VestingSchedule};
+use crate::constants::{CLOSED_AIRDROP_SEED, CONFIG_SEED, HASH_VERSION_KECCAK, LEAF_ENCODING_PUBKEY, MERKLE_ROOT_SEED};
+use crate::errors::AirdropError;
+use crate::events::AirdropCreated;
+
+/// Airdrop settings fixed at creation time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateAirdropParams {
+    /// When the authority may claw back unclaimed tokens
+    pub expires_at: i64,
+    pub claim_start: Option<i64>,
+    pub claim_end: Option<i64>,
+    pub vesting: Option<VestingSchedule>,
+    /// Allow the authority to publish new cumulative roots with `update_root`
+    pub cumulative: bool,
+    /// Leaf and node hashing scheme the tree was built with (`HASH_VERSION_*`)
+    pub hash_version: u8,
+    /// How leaves encode the claimant's address (`LEAF_ENCODING_*`)
+    pub leaf_encoding: u8,
+    /// Make this a voucher airdrop signed by this key; `merkle_root_hash` then only identifies it
+    pub voucher_signer: Option<Pubkey>,
+    /// Track claims in bitmap accounts sized for this many leaves instead of per-claimer receipts
+    pub bitmap_leaf_count: Option<u32>,
+    /// Distinguishes airdrops of the same root by the same creator
+    pub nonce: u64,
+    /// Leaves name their mint; vaults for mints other than `mint` are opened by `fund_airdrop`
+    pub multi_mint: bool,
+    /// Leaves in the tree, recorded for accounting; 0 for voucher airdrops
+    pub num_leaves: u32,
+    /// Only pay claimants holding at least a minimum balance of a token
+    pub gate: Option<ClaimGate>,
+}
 
 #[derive(Accounts)]
-#[instruction(merkle_root_hash: [u8; 32], amount: u64)]
+#[instruction(merkle_root_hash: [u8; 32], amount: u64, params: CreateAirdropParams)]
 pub struct CreateAirdrop<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -29,20 +60,42 @@ pub struct CreateAirdrop<'info> {
         init,
         payer = authority,
         space = 8 + MerkleRoot::INIT_SPACE,
-        seeds = [MERKLE_ROOT_SEED, merkle_root_hash.as_ref()],
+        // Namespaced by creator so nobody can squat the PDA of a root they have seen
+        seeds = [
+            MERKLE_ROOT_SEED,
+            authority.key().as_ref(),
+            &params.nonce.to_le_bytes(),
+            merkle_root_hash.as_ref(),
+        ],
         bump
     )]
     pub merkle_root: Account<'info, MerkleRoot>,
+    /// CHECK: tombstone of an earlier airdrop at this address; must not exist
+    #[account(
+        seeds = [CLOSED_AIRDROP_SEED, merkle_root.key().as_ref()],
+        bump,
+        constraint = closed_airdrop.data_is_empty() @ AirdropError::AirdropAlreadyClosed,
+    )]
+    pub closed_airdrop: UncheckedAccount<'info>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the fee recipient's associated token account when a funding fee is charged
+    #[account(mut)]
+    pub fee_recipient_token_account: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub fn handler(
-    ctx: Context<CreateAirdrop>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateAirdrop<'info>>,
     merkle_root_hash: [u8; 32],
     amount: u64,
+    params: CreateAirdropParams,
 ) -> Result<()> {
+    validate_params(&params)?;
+
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let accounts = TransferChecked {
         from: ctx.accounts.authority_token_account.to_account_info(),
@@ -50,14 +103,131 @@ pub fn handler(
         authority: ctx.accounts.authority.to_account_info(),
         mint: ctx.accounts.mint.to_account_info(),
     };
-    let cpi_ctx = CpiContext::new(cpi_program, accounts);
-    token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    let cpi_ctx = CpiContext::new(cpi_program, accounts).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    transfer::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    // Protocol fee, if the program config charges one on deposits
+    let accounts = TransferChecked {
+        from: ctx.accounts.authority_token_account.to_account_info(),
+        to: ctx.accounts.fee_recipient_token_account.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    fees::charge_token_funding_fee(&ctx.accounts.config, cpi_ctx, &ctx.accounts.mint, ctx.accounts.merkle_root.key(), amount)?;
 
     let bump = ctx.bumps.merkle_root;
-    let merkle_root = &mut ctx.accounts.merkle_root;
+    initialize_merkle_root(
+        &mut ctx.accounts.merkle_root,
+        merkle_root_hash,
+        bump,
+        ctx.accounts.mint.key(),
+        ctx.accounts.authority.key(),
+        params,
+    );
+    let received = transfer::amount_received(&transfer::epoch_transfer_fee(&ctx.accounts.mint)?, amount)?;
+    ctx.accounts.merkle_root.record_deposit(received)?;
+
+    emit!(AirdropCreated {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        root: merkle_root_hash,
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Checks on the creation settings shared by every kind of airdrop.
+pub(crate) fn validate_params(params: &CreateAirdropParams) -> Result<()> {
+    if params.expires_at <= Clock::get()?.unix_timestamp {
+        return Err(AirdropError::InvalidExpiry.into());
+    }
+    if let Some(end) = params.claim_end {
+        require!(end <= params.expires_at, AirdropError::InvalidClaimWindow);
+        if let Some(start) = params.claim_start {
+            require!(start < end, AirdropError::InvalidClaimWindow);
+        }
+    }
+    // Claims must open before the authority can claw the tokens back
+    if let Some(start) = params.claim_start {
+        require!(start < params.expires_at, AirdropError::InvalidClaimWindow);
+    }
+    if let Some(vesting) = &params.vesting {
+        require!(vesting.is_valid(), AirdropError::InvalidVestingSchedule);
+    }
+    if let Some(gate) = &params.gate {
+        require!(gate.is_valid(), AirdropError::InvalidGateConfig);
+    }
+    require!(
+        MerkleRoot::is_supported_hash_version(params.hash_version),
+        AirdropError::UnsupportedHashVersion
+    );
+    require!(
+        MerkleRoot::is_supported_leaf_encoding(params.leaf_encoding),
+        AirdropError::UnsupportedLeafEncoding
+    );
+    // abi.encode has no notion of base58 text
+    require!(
+        params.hash_version != HASH_VERSION_KECCAK || params.leaf_encoding == LEAF_ENCODING_PUBKEY,
+        AirdropError::UnsupportedLeafEncoding
+    );
+    require!(
+        !(params.cumulative && params.voucher_signer.is_some()),
+        AirdropError::InvalidVoucherConfig
+    );
+    require!(
+        !params.multi_mint || (params.voucher_signer.is_none() && params.bitmap_leaf_count.is_none()),
+        AirdropError::InvalidMultiMintConfig
+    );
+    if let Some(leaf_count) = params.bitmap_leaf_count {
+        // A set bit means "fully paid", and keccak proofs do not bind the leaf index
+        require!(
+            (1..=1 << 31).contains(&leaf_count)
+                && !params.cumulative
+                && params.vesting.is_none()
+                && params.voucher_signer.is_none()
+                && params.hash_version != HASH_VERSION_KECCAK,
+            AirdropError::InvalidBitmapConfig
+        );
+    }
+    Ok(())
+}
+
+/// Fill in a freshly created merkle root account.
+pub(crate) fn initialize_merkle_root(
+    merkle_root: &mut MerkleRoot,
+    merkle_root_hash: [u8; 32],
+    bump: u8,
+    mint: Pubkey,
+    authority: Pubkey,
+    params: CreateAirdropParams,
+) {
     merkle_root.hash = merkle_root_hash;
+    merkle_root.seed_hash = merkle_root_hash;
     merkle_root.bump = bump;
-    merkle_root.mint = ctx.accounts.mint.key();
-    
-    Ok(())
+    merkle_root.mint = mint;
+    merkle_root.authority = authority;
+    merkle_root.expires_at = params.expires_at;
+    merkle_root.claim_start = params.claim_start;
+    merkle_root.claim_end = params.claim_end;
+    merkle_root.vesting = params.vesting;
+    merkle_root.cumulative = params.cumulative;
+    merkle_root.epoch = 0;
+    merkle_root.paused = false;
+    merkle_root.hash_version = params.hash_version;
+    merkle_root.leaf_encoding = params.leaf_encoding;
+    merkle_root.voucher_signer = params.voucher_signer;
+    merkle_root.bitmap_leaf_count = params.bitmap_leaf_count;
+    merkle_root.creator = authority;
+    merkle_root.nonce = params.nonce.to_le_bytes();
+    merkle_root.multi_mint = params.multi_mint;
+    merkle_root.native_sol = false;
+    merkle_root.total_amount = 0;
+    merkle_root.claimed_amount = 0;
+    merkle_root.num_claims = 0;
+    merkle_root.num_leaves = params.num_leaves;
+    merkle_root.mint_on_claim = false;
+    merkle_root.gate = params.gate;
 }
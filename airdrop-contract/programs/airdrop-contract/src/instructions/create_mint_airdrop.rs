@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::Mint;
+use crate::state::merkle_root::MerkleRoot;
+use crate::constants::{CLOSED_AIRDROP_SEED, MERKLE_ROOT_SEED};
+use crate::errors::AirdropError;
+use crate::instructions::create_airdrop::{initialize_merkle_root, validate_params, CreateAirdropParams};
+use crate::events::AirdropCreated;
+
+#[derive(Accounts)]
+#[instruction(merkle_root_hash: [u8; 32], amount: u64, params: CreateAirdropParams)]
+pub struct CreateMintAirdrop<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MerkleRoot::INIT_SPACE,
+        seeds = [
+            MERKLE_ROOT_SEED,
+            authority.key().as_ref(),
+            &params.nonce.to_le_bytes(),
+            merkle_root_hash.as_ref(),
+        ],
+        bump
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    /// CHECK: tombstone of an earlier airdrop at this address; must not exist
+    #[account(
+        seeds = [CLOSED_AIRDROP_SEED, merkle_root.key().as_ref()],
+        bump,
+        constraint = closed_airdrop.data_is_empty() @ AirdropError::AirdropAlreadyClosed,
+    )]
+    pub closed_airdrop: UncheckedAccount<'info>,
+    /// Mint authority must already have been handed to the merkle root PDA
+    #[account(
+        constraint = mint.mint_authority == COption::Some(merkle_root.key())
+            @ AirdropError::InvalidMintOnClaimConfig,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create an airdrop that mints to each recipient as they claim, up to `amount` in total.
+pub fn handler(
+    ctx: Context<CreateMintAirdrop>,
+    merkle_root_hash: [u8; 32],
+    amount: u64,
+    params: CreateAirdropParams,
+) -> Result<()> {
+    validate_params(&params)?;
+    // Only plain receipt claims know how to mint
+    require!(
+        !params.cumulative
+            && !params.multi_mint
+            && params.voucher_signer.is_none()
+            && params.bitmap_leaf_count.is_none(),
+        AirdropError::InvalidMintOnClaimConfig
+    );
+    require!(amount > 0, AirdropError::InvalidAmount);
+
+    let bump = ctx.bumps.merkle_root;
+    let merkle_root = &mut ctx.accounts.merkle_root;
+    initialize_merkle_root(
+        merkle_root,
+        merkle_root_hash,
+        bump,
+        ctx.accounts.mint.key(),
+        ctx.accounts.authority.key(),
+        params,
+    );
+    merkle_root.mint_on_claim = true;
+    // Nothing is deposited; the declared total caps what claims may mint
+    merkle_root.record_deposit(amount)?;
+
+    emit!(AirdropCreated {
+        merkle_root: merkle_root.key(),
+        root: merkle_root_hash,
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+    });
+
+    Ok(())
+}
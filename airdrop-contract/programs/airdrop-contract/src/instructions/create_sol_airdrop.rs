@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::fees;
+use crate::state::merkle_root::MerkleRoot;
+use crate::constants::{CLOSED_AIRDROP_SEED, CONFIG_SEED, MERKLE_ROOT_SEED};
+use crate::errors::AirdropError;
+use crate::instructions::create_airdrop::{initialize_merkle_root, validate_params, CreateAirdropParams};
+use crate::events::AirdropCreated;
+
+#[derive(Accounts)]
+#[instruction(merkle_root_hash: [u8; 32], amount: u64, params: CreateAirdropParams)]
+pub struct CreateSolAirdrop<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// Holds the airdropped lamports on top of its own rent
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MerkleRoot::INIT_SPACE,
+        seeds = [
+            MERKLE_ROOT_SEED,
+            authority.key().as_ref(),
+            &params.nonce.to_le_bytes(),
+            merkle_root_hash.as_ref(),
+        ],
+        bump
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    /// CHECK: tombstone of an earlier airdrop at this address; must not exist
+    #[account(
+        seeds = [CLOSED_AIRDROP_SEED, merkle_root.key().as_ref()],
+        bump,
+        constraint = closed_airdrop.data_is_empty() @ AirdropError::AirdropAlreadyClosed,
+    )]
+    pub closed_airdrop: UncheckedAccount<'info>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the config's fee recipient when a funding fee is charged
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create an airdrop of native SOL, depositing `amount` lamports into the merkle root account.
+pub fn handler(
+    ctx: Context<CreateSolAirdrop>,
+    merkle_root_hash: [u8; 32],
+    amount: u64,
+    params: CreateAirdropParams,
+) -> Result<()> {
+    validate_params(&params)?;
+    // Only plain receipt claims know how to pay lamports
+    require!(
+        !params.cumulative
+            && !params.multi_mint
+            && params.voucher_signer.is_none()
+            && params.bitmap_leaf_count.is_none(),
+        AirdropError::InvalidSolConfig
+    );
+    require!(amount > 0, AirdropError::InvalidAmount);
+
+    let accounts = Transfer {
+        from: ctx.accounts.authority.to_account_info(),
+        to: ctx.accounts.merkle_root.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    // Protocol fee, if the program config charges one on deposits
+    let accounts = Transfer {
+        from: ctx.accounts.authority.to_account_info(),
+        to: ctx.accounts.fee_recipient.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), accounts);
+    fees::charge_sol_funding_fee(&ctx.accounts.config, cpi_ctx, ctx.accounts.merkle_root.key(), amount)?;
+
+    let bump = ctx.bumps.merkle_root;
+    let merkle_root = &mut ctx.accounts.merkle_root;
+    initialize_merkle_root(
+        merkle_root,
+        merkle_root_hash,
+        bump,
+        Pubkey::default(),
+        ctx.accounts.authority.key(),
+        params,
+    );
+    merkle_root.native_sol = true;
+    merkle_root.record_deposit(amount)?;
+
+    emit!(AirdropCreated {
+        merkle_root: merkle_root.key(),
+        root: merkle_root_hash,
+        mint: Pubkey::default(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::transfer::{self, ClaimFee, Payout};
+use crate::state::{ClaimReceipt, MerkleRoot};
+use crate::constants::{CLAIM_RECEIPT_SEED, CONFIG_SEED};
+use crate::errors::AirdropError;
+use crate::events::Claimed;
+
+/// Push a claim to the recipient named in the leaf without their signature. Authority only:
+/// claim link leaves name throwaway keys, and only the authority knows which ones those are.
+#[derive(Accounts)]
+pub struct Distribute<'info> {
+    /// Airdrop authority; covers fees and rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: recipient named in the leaf; only owns the destination token account
+    pub recipient: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        has_one = authority @ AirdropError::Unauthorized,
+        constraint = merkle_root.accepts_mint(&mint.key()) @ AirdropError::InvalidMint,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    /// Same receipt the recipient's own claim would use, so neither can pay twice
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ClaimReceipt::INIT_SPACE,
+        seeds = [
+            CLAIM_RECEIPT_SEED,
+            merkle_root.key().as_ref(),
+            recipient.key().as_ref(),
+            ClaimReceipt::mint_seed(merkle_root.receipt_mint(mint.to_account_info().key)),
+        ],
+        bump
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+    /// The recipient's token account of the gate mint; required when the airdrop is gated
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the config's fee recipient when a claim fee is charged
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Distribute<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+    let now = Clock::get()?.unix_timestamp;
+    merkle_root.verify_claim(&ctx.accounts.recipient.key(), &ctx.accounts.mint.key(), &proof, amount, leaf_index, now)?;
+
+    // Release whatever has vested since the last claim
+    let claimable = merkle_root.claimable_amount(amount, ctx.accounts.claim_receipt.claimed_so_far, now)?;
+
+    transfer::pay_claim(
+        &mut ctx.accounts.merkle_root,
+        &ctx.accounts.recipient.key(),
+        ctx.accounts.gate_token_account.as_deref(),
+        Payout::Vault {
+            vault: &ctx.accounts.merkle_root_token_account,
+            destination: &ctx.accounts.recipient_token_account,
+            mint: &ctx.accounts.mint,
+            token_program: &ctx.accounts.token_program,
+            hook_accounts: ctx.remaining_accounts,
+        },
+        ClaimFee {
+            payer: ctx.accounts.authority.to_account_info(),
+            config: ctx.accounts.config.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        claimable,
+    )?;
+    ctx.accounts.claim_receipt.record_claim(ctx.accounts.authority.key(), amount, claimable);
+
+    let merkle_root = &ctx.accounts.merkle_root;
+    emit!(Claimed {
+        merkle_root: merkle_root.key(),
+        root: merkle_root.hash,
+        claimant: ctx.accounts.recipient.key(),
+        mint: ctx.accounts.mint.key(),
+        amount: claimable,
+        leaf_index,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked}};
+use crate::{fees, transfer};
+use crate::state::{MerkleRoot, VaultRecord};
+use crate::constants::{CONFIG_SEED, VAULT_RECORD_SEED};
+use crate::errors::AirdropError;
+use crate::events::AirdropFunded;
+
+#[derive(Accounts)]
+pub struct FundAirdrop<'info> {
+    /// Anyone may add tokens to an open vault; only the authority can open one for another mint
+    /// of a multi-mint airdrop, or ever take tokens back out
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = funder,
+        associated_token::token_program = token_program,
+    )]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Opened here for the other mints of a multi-mint airdrop
+    #[account(
+        init_if_needed,
+        payer = funder,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, constraint = merkle_root.accepts_mint(&mint.key()) @ AirdropError::InvalidMint)]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    /// Required when funding one of a multi-mint airdrop's additional mints
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + VaultRecord::INIT_SPACE,
+        seeds = [VAULT_RECORD_SEED, merkle_root.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub vault_record: Option<Account<'info, VaultRecord>>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the fee recipient's associated token account when a funding fee is charged
+    #[account(mut)]
+    pub fee_recipient_token_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Top up the vault of an existing airdrop by `amount`.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, FundAirdrop<'info>>, amount: u64) -> Result<()> {
+    require!(amount > 0, AirdropError::InvalidAmount);
+
+    // Count the vaults of additional mints so the airdrop cannot be closed while they are open
+    let merkle_root = &mut ctx.accounts.merkle_root;
+    let mint = ctx.accounts.mint.key();
+    if merkle_root.multi_mint && mint != merkle_root.mint {
+        let vault_record = ctx.accounts.vault_record.as_mut().ok_or(AirdropError::VaultRecordMissing)?;
+        if vault_record.mint == Pubkey::default() {
+            // A vault nobody can empty would keep the airdrop from ever closing, so only the
+            // authority may open one
+            require_keys_eq!(ctx.accounts.funder.key(), merkle_root.authority, AirdropError::Unauthorized);
+            vault_record.mint = mint;
+            merkle_root.open_vaults = merkle_root.open_vaults.checked_add(1).ok_or(AirdropError::InvalidAmount)?;
+        }
+    }
+
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let accounts = TransferChecked {
+        from: ctx.accounts.funder_token_account.to_account_info(),
+        to: ctx.accounts.merkle_root_token_account.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(cpi_program, accounts).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    transfer::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    // Protocol fee, if the program config charges one on deposits
+    let accounts = TransferChecked {
+        from: ctx.accounts.funder_token_account.to_account_info(),
+        to: ctx.accounts.fee_recipient_token_account.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    fees::charge_token_funding_fee(&ctx.accounts.config, cpi_ctx, &ctx.accounts.mint, ctx.accounts.merkle_root.key(), amount)?;
+
+    let received = transfer::amount_received(&transfer::epoch_transfer_fee(&ctx.accounts.mint)?, amount)?;
+    ctx.accounts.merkle_root.record_deposit(received)?;
+
+    emit!(AirdropFunded {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        mint: ctx.accounts.mint.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+    });
+
+    Ok(())
+}
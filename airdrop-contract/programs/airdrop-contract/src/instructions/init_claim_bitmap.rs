@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{ClaimBitmap, MerkleRoot};
+use crate::constants::CLAIM_BITMAP_SEED;
+use crate::errors::AirdropError;
+use crate::events::ClaimBitmapInitialized;
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct InitClaimBitmap<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(has_one = authority @ AirdropError::Unauthorized)]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        init,
+        payer = authority,
+        space = ClaimBitmap::space(merkle_root.bitmap_len(index)),
+        seeds = [CLAIM_BITMAP_SEED, merkle_root.key().as_ref(), &index.to_le_bytes()],
+        bump
+    )]
+    pub claim_bitmap: Account<'info, ClaimBitmap>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitClaimBitmap>, index: u32) -> Result<()> {
+    let merkle_root = &ctx.accounts.merkle_root;
+    require!(index < merkle_root.bitmap_account_count(), AirdropError::InvalidBitmapConfig);
+
+    let claim_bitmap = &mut ctx.accounts.claim_bitmap;
+    claim_bitmap.index = index;
+    claim_bitmap.bump = ctx.bumps.claim_bitmap;
+    claim_bitmap.bits = vec![0; merkle_root.bitmap_len(index)];
+
+    emit!(ClaimBitmapInitialized {
+        merkle_root: merkle_root.key(),
+        index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, ProtocolFee};
+use crate::constants::CONFIG_SEED;
+use crate::errors::AirdropError;
+use crate::program::AirdropContract;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, AirdropContract>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(payer.key())
+            @ AirdropError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the program's singleton config. Only the program's upgrade authority can, so nobody
+/// can claim the admin role between the deploy and the operator's own initialization.
+pub fn handler(ctx: Context<InitializeConfig>, admin: Pubkey, fee_recipient: Pubkey, fee: ProtocolFee) -> Result<()> {
+    require!(fee.is_valid(), AirdropError::InvalidFeeConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.fee_recipient = fee_recipient;
+    config.fee = fee;
+    config.bump = ctx.bumps.config;
+
+    emit!(config.updated_event(config.key()));
+
+    Ok(())
+}
@@ -1,4 +1,58 @@
 pub mod create_airdrop;
+pub mod create_sol_airdrop;
+pub mod create_mint_airdrop;
 pub mod claim;
+pub mod claim_link;
+pub mod claim_sol;
+pub mod claim_mint;
+pub mod claim_voucher;
+pub mod claim_with_bitmap;
+pub mod claim_legacy;
+pub mod distribute;
+pub mod init_claim_bitmap;
+pub mod clawback;
+pub mod clawback_sol;
+pub mod clawback_legacy;
+pub mod fund_airdrop;
+pub mod update_root;
+pub mod set_paused;
+pub mod close_airdrop;
+pub mod close_claim_receipt;
+pub mod close_claim_bitmap;
+pub mod close_sol_airdrop;
+pub mod close_mint_airdrop;
+pub mod close_vault;
+pub mod close_legacy_airdrop;
+pub mod initialize_config;
+pub mod update_config;
+pub mod update_metadata;
+pub mod close_metadata;
 pub use create_airdrop::*;
-pub use claim::*;
\ No newline at end of file
+pub use create_sol_airdrop::*;
+pub use create_mint_airdrop::*;
+pub use claim::*;
+pub use claim_link::*;
+pub use claim_sol::*;
+pub use claim_mint::*;
+pub use claim_voucher::*;
+pub use claim_with_bitmap::*;
+pub use claim_legacy::*;
+pub use distribute::*;
+pub use init_claim_bitmap::*;
+pub use clawback::*;
+pub use clawback_sol::*;
+pub use clawback_legacy::*;
+pub use fund_airdrop::*;
+pub use update_root::*;
+pub use set_paused::*;
+pub use close_airdrop::*;
+pub use close_claim_receipt::*;
+pub use close_claim_bitmap::*;
+pub use close_sol_airdrop::*;
+pub use close_mint_airdrop::*;
+pub use close_vault::*;
+pub use close_legacy_airdrop::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use update_metadata::*;
+pub use close_metadata::*;
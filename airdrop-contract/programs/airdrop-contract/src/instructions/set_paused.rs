@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::MerkleRoot;
+use crate::errors::AirdropError;
+use crate::events::PauseSet;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ AirdropError::Unauthorized,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+}
+
+pub fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.merkle_root.paused = paused;
+
+    emit!(PauseSet {
+        merkle_root: ctx.accounts.merkle_root.key(),
+        paused,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{Config, ProtocolFee};
+use crate::constants::CONFIG_SEED;
+use crate::errors::AirdropError;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ AirdropError::NotConfigAdmin,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Change any of the config's settings; `None` leaves a setting as it is.
+pub fn handler(
+    ctx: Context<UpdateConfig>,
+    admin: Option<Pubkey>,
+    fee_recipient: Option<Pubkey>,
+    fee: Option<ProtocolFee>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    if let Some(admin) = admin {
+        config.admin = admin;
+    }
+    if let Some(fee_recipient) = fee_recipient {
+        config.fee_recipient = fee_recipient;
+    }
+    if let Some(fee) = fee {
+        require!(fee.is_valid(), AirdropError::InvalidFeeConfig);
+        config.fee = fee;
+    }
+
+    emit!(config.updated_event(config.key()));
+
+    Ok(())
+}
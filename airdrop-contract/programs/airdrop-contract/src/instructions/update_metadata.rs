@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{AirdropMetadata, MerkleRoot, MetadataParams};
+use crate::constants::METADATA_SEED;
+use crate::errors::AirdropError;
+use crate::events::MetadataUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(has_one = authority @ AirdropError::Unauthorized)]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    /// Sized for the longest fields up front, so later edits never need a realloc
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AirdropMetadata::INIT_SPACE,
+        seeds = [METADATA_SEED, merkle_root.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, AirdropMetadata>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Create or overwrite the airdrop's metadata (authority only).
+pub fn handler(ctx: Context<UpdateMetadata>, params: MetadataParams) -> Result<()> {
+    require!(params.is_valid(), AirdropError::MetadataTooLong);
+
+    let metadata = &mut ctx.accounts.metadata;
+    metadata.merkle_root = ctx.accounts.merkle_root.key();
+    metadata.creator = ctx.accounts.authority.key();
+    metadata.name = params.name;
+    metadata.description_uri = params.description_uri;
+    metadata.image_uri = params.image_uri;
+    metadata.website = params.website;
+    metadata.bump = ctx.bumps.metadata;
+
+    emit!(MetadataUpdated {
+        merkle_root: metadata.merkle_root,
+        metadata: metadata.key(),
+        name: metadata.name.clone(),
+    });
+
+    Ok(())
+}
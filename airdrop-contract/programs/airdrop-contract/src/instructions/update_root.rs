@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::{fees, transfer};
+use crate::state::MerkleRoot;
+use crate::constants::CONFIG_SEED;
+use crate::errors::AirdropError;
+use crate::events::RootUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateRoot<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ AirdropError::Unauthorized,
+        has_one = mint,
+    )]
+    pub merkle_root: Account<'info, MerkleRoot>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = merkle_root,
+        associated_token::token_program = token_program,
+    )]
+    pub merkle_root_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: program config PDA; no fee is charged while it is uninitialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: must be the fee recipient's associated token account when a funding fee is charged
+    #[account(mut)]
+    pub fee_recipient_token_account: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Publish the next epoch's cumulative root and top up the vault by `amount`.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, UpdateRoot<'info>>, merkle_root_hash: [u8; 32], amount: u64, num_leaves: u32) -> Result<()> {
+    if !ctx.accounts.merkle_root.cumulative {
+        return Err(AirdropError::NotCumulative.into());
+    }
+
+    if amount > 0 {
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let accounts = TransferChecked {
+            from: ctx.accounts.authority_token_account.to_account_info(),
+            to: ctx.accounts.merkle_root_token_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, accounts).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+        transfer::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+        // Protocol fee, if the program config charges one on deposits
+        let accounts = TransferChecked {
+            from: ctx.accounts.authority_token_account.to_account_info(),
+            to: ctx.accounts.fee_recipient_token_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+        fees::charge_token_funding_fee(&ctx.accounts.config, cpi_ctx, &ctx.accounts.mint, ctx.accounts.merkle_root.key(), amount)?;
+
+        let received = transfer::amount_received(&transfer::epoch_transfer_fee(&ctx.accounts.mint)?, amount)?;
+        ctx.accounts.merkle_root.record_deposit(received)?;
+    }
+
+    let merkle_root = &mut ctx.accounts.merkle_root;
+    merkle_root.hash = merkle_root_hash;
+    merkle_root.epoch += 1;
+    merkle_root.num_leaves = num_leaves;
+
+    emit!(RootUpdated {
+        merkle_root: merkle_root.key(),
+        root: merkle_root_hash,
+        epoch: merkle_root.epoch,
+        amount,
+    });
+
+    Ok(())
+}
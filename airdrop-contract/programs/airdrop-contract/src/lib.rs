@@ -6,20 +6,137 @@ declare_id!("F6fHBUyYyaW14CxjSnJjLck8vMmWew3PbCnt5TMqRdZX");
 pub mod instructions;
 pub mod state;
 pub mod errors;
+pub mod events;
 pub mod constants;
+pub mod transfer;
+pub mod fees;
+
+use crate::state::{MetadataParams, ProtocolFee, Voucher};
 
 #[program]
 pub mod airdrop_contract {
     use super::*;
-    use crate::instructions::{create_airdrop::{self, CreateAirdrop}, claim::{self, Claim}};
+    use crate::instructions::{create_airdrop::{self, CreateAirdrop, CreateAirdropParams}, create_sol_airdrop::{self, CreateSolAirdrop}, create_mint_airdrop::{self, CreateMintAirdrop}, claim::{self, Claim}, claim_link::{self, ClaimLink}, claim_sol::{self, ClaimSol}, claim_mint::{self, ClaimMint}, claim_voucher::{self, ClaimVoucher}, claim_with_bitmap::{self, ClaimWithBitmap}, claim_legacy::{self, ClaimLegacy}, distribute::{self, Distribute}, init_claim_bitmap::{self, InitClaimBitmap}, clawback::{self, Clawback}, clawback_sol::{self, ClawbackSol}, clawback_legacy::{self, ClawbackLegacy}, fund_airdrop::{self, FundAirdrop}, update_root::{self, UpdateRoot}, set_paused::{self, SetPaused}, close_airdrop::{self, CloseAirdrop}, close_claim_receipt::{self, CloseClaimReceipt}, close_claim_bitmap::{self, CloseClaimBitmap}, close_sol_airdrop::{self, CloseSolAirdrop}, close_mint_airdrop::{self, CloseMintAirdrop}, close_vault::{self, CloseVault}, close_legacy_airdrop::{self, CloseLegacyAirdrop}, initialize_config::{self, InitializeConfig}, update_config::{self, UpdateConfig}, update_metadata::{self, UpdateMetadata}, close_metadata::{self, CloseMetadata}};
+
+    pub fn create_airdrop<'info>(ctx: Context<'_, '_, '_, 'info, CreateAirdrop<'info>>, merkle_root_hash: [u8; 32], amount: u64, params: CreateAirdropParams) -> Result<()> {
+        create_airdrop::handler(ctx, merkle_root_hash, amount, params)
+    }
+
+    pub fn create_sol_airdrop(ctx: Context<CreateSolAirdrop>, merkle_root_hash: [u8; 32], amount: u64, params: CreateAirdropParams) -> Result<()> {
+        create_sol_airdrop::handler(ctx, merkle_root_hash, amount, params)
+    }
 
-    pub fn create_airdrop(ctx: Context<CreateAirdrop>, merkle_root_hash: [u8; 32], amount: u64) -> Result<()> {
-        create_airdrop::handler(ctx, merkle_root_hash, amount)
+    pub fn create_mint_airdrop(ctx: Context<CreateMintAirdrop>, merkle_root_hash: [u8; 32], amount: u64, params: CreateAirdropParams) -> Result<()> {
+        create_mint_airdrop::handler(ctx, merkle_root_hash, amount, params)
     }
 
-    pub fn claim(ctx: Context<Claim>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+    pub fn claim<'info>(ctx: Context<'_, '_, '_, 'info, Claim<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
         claim::handler(ctx, proof, amount, leaf_index)
     }
+
+    pub fn claim_link<'info>(ctx: Context<'_, '_, '_, 'info, ClaimLink<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+        claim_link::handler(ctx, proof, amount, leaf_index)
+    }
+
+    pub fn claim_sol(ctx: Context<ClaimSol>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+        claim_sol::handler(ctx, proof, amount, leaf_index)
+    }
+
+    pub fn claim_mint(ctx: Context<ClaimMint>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+        claim_mint::handler(ctx, proof, amount, leaf_index)
+    }
+
+    pub fn claim_voucher<'info>(ctx: Context<'_, '_, '_, 'info, ClaimVoucher<'info>>, voucher: Voucher) -> Result<()> {
+        claim_voucher::handler(ctx, voucher)
+    }
+
+    pub fn init_claim_bitmap(ctx: Context<InitClaimBitmap>, index: u32) -> Result<()> {
+        init_claim_bitmap::handler(ctx, index)
+    }
+
+    pub fn claim_with_bitmap<'info>(ctx: Context<'_, '_, '_, 'info, ClaimWithBitmap<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+        claim_with_bitmap::handler(ctx, proof, amount, leaf_index)
+    }
+
+    pub fn claim_legacy<'info>(ctx: Context<'_, '_, '_, 'info, ClaimLegacy<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+        claim_legacy::handler(ctx, proof, amount, leaf_index)
+    }
+
+    pub fn distribute<'info>(ctx: Context<'_, '_, '_, 'info, Distribute<'info>>, proof: Vec<[u8; 32]>, amount: u64, leaf_index: u32) -> Result<()> {
+        distribute::handler(ctx, proof, amount, leaf_index)
+    }
+
+    pub fn clawback<'info>(ctx: Context<'_, '_, '_, 'info, Clawback<'info>>, burn: bool) -> Result<()> {
+        clawback::handler(ctx, burn)
+    }
+
+    pub fn clawback_sol(ctx: Context<ClawbackSol>) -> Result<()> {
+        clawback_sol::handler(ctx)
+    }
+
+    pub fn clawback_legacy<'info>(ctx: Context<'_, '_, '_, 'info, ClawbackLegacy<'info>>) -> Result<()> {
+        clawback_legacy::handler(ctx)
+    }
+
+    pub fn fund_airdrop<'info>(ctx: Context<'_, '_, '_, 'info, FundAirdrop<'info>>, amount: u64) -> Result<()> {
+        fund_airdrop::handler(ctx, amount)
+    }
+
+    pub fn update_root<'info>(ctx: Context<'_, '_, '_, 'info, UpdateRoot<'info>>, merkle_root_hash: [u8; 32], amount: u64, num_leaves: u32) -> Result<()> {
+        update_root::handler(ctx, merkle_root_hash, amount, num_leaves)
+    }
+
+    pub fn pause(ctx: Context<SetPaused>) -> Result<()> {
+        set_paused::handler(ctx, true)
+    }
+
+    pub fn unpause(ctx: Context<SetPaused>) -> Result<()> {
+        set_paused::handler(ctx, false)
+    }
+
+    pub fn close_airdrop(ctx: Context<CloseAirdrop>) -> Result<()> {
+        close_airdrop::handler(ctx)
+    }
+
+    pub fn close_claim_receipt(ctx: Context<CloseClaimReceipt>) -> Result<()> {
+        close_claim_receipt::handler(ctx)
+    }
+
+    pub fn close_claim_bitmap(ctx: Context<CloseClaimBitmap>) -> Result<()> {
+        close_claim_bitmap::handler(ctx)
+    }
+
+    pub fn close_sol_airdrop(ctx: Context<CloseSolAirdrop>) -> Result<()> {
+        close_sol_airdrop::handler(ctx)
+    }
+
+    pub fn close_mint_airdrop(ctx: Context<CloseMintAirdrop>) -> Result<()> {
+        close_mint_airdrop::handler(ctx)
+    }
+
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+        close_vault::handler(ctx)
+    }
+
+    pub fn close_legacy_airdrop(ctx: Context<CloseLegacyAirdrop>) -> Result<()> {
+        close_legacy_airdrop::handler(ctx)
+    }
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>, admin: Pubkey, fee_recipient: Pubkey, fee: ProtocolFee) -> Result<()> {
+        initialize_config::handler(ctx, admin, fee_recipient, fee)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, admin: Option<Pubkey>, fee_recipient: Option<Pubkey>, fee: Option<ProtocolFee>) -> Result<()> {
+        update_config::handler(ctx, admin, fee_recipient, fee)
+    }
+
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, params: MetadataParams) -> Result<()> {
+        update_metadata::handler(ctx, params)
+    }
+
+    pub fn close_metadata(ctx: Context<CloseMetadata>) -> Result<()> {
+        close_metadata::handler(ctx)
+    }
 }
 
 // Re-export for convenience so external crates/tests can `use airdrop_contract::instructions::*;`
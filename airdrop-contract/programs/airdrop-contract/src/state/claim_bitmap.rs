@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::constants::CLAIM_BITMAP_BITS;
+use crate::errors::AirdropError;
+
+/// One slice of a bitmap airdrop's claim flags: bit `i` is set once the leaf at
+/// position `index * CLAIM_BITMAP_BITS + i` has been claimed.
+#[account]
+pub struct ClaimBitmap {
+    pub index: u32,
+    pub bump: u8,
+    pub bits: Vec<u8>,
+}
+
+impl ClaimBitmap {
+    /// Account size for a bitmap holding `len` bytes of flags.
+    pub fn space(len: usize) -> usize {
+        8 + 4 + 1 + 4 + len
+    }
+
+    /// The bitmap account `index` and the bit within it that track the leaf at `position`.
+    pub fn locate(position: u32) -> (u32, u32) {
+        (position / CLAIM_BITMAP_BITS, position % CLAIM_BITMAP_BITS)
+    }
+
+    pub fn is_claimed(&self, bit: u32) -> bool {
+        self.bits
+            .get((bit / 8) as usize)
+            .is_some_and(|byte| byte & (1 << (bit % 8)) != 0)
+    }
+
+    /// Flip `bit`, failing if it was already set.
+    pub fn set_claimed(&mut self, bit: u32) -> Result<()> {
+        let byte = self
+            .bits
+            .get_mut((bit / 8) as usize)
+            .ok_or(AirdropError::InvalidBitmapAccount)?;
+        let mask = 1 << (bit % 8);
+        require!(*byte & mask == 0, AirdropError::AlreadyClaimed);
+        *byte |= mask;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bitmap(index: u32, len: usize) -> ClaimBitmap {
+        ClaimBitmap { index, bump: 255, bits: vec![0; len] }
+    }
+
+    #[test]
+    fn test_locate_across_account_boundaries() {
+        assert_eq!(ClaimBitmap::locate(0), (0, 0));
+        assert_eq!(ClaimBitmap::locate(CLAIM_BITMAP_BITS - 1), (0, CLAIM_BITMAP_BITS - 1));
+        assert_eq!(ClaimBitmap::locate(CLAIM_BITMAP_BITS), (1, 0));
+        assert_eq!(ClaimBitmap::locate(2 * CLAIM_BITMAP_BITS + 9), (2, 9));
+        assert_eq!(ClaimBitmap::locate(u32::MAX), (u32::MAX / CLAIM_BITMAP_BITS, CLAIM_BITMAP_BITS - 1));
+    }
+
+    #[test]
+    fn test_set_claimed_flips_one_bit() {
+        let mut first = bitmap(0, (CLAIM_BITMAP_BITS / 8) as usize);
+        let mut second = bitmap(1, 1);
+
+        // The last leaf of the first account and the first leaf of the next are separate flags
+        let (index, bit) = ClaimBitmap::locate(CLAIM_BITMAP_BITS - 1);
+        assert_eq!(index, first.index);
+        first.set_claimed(bit).unwrap();
+        assert_eq!(*first.bits.last().unwrap(), 0b1000_0000);
+        assert!(first.is_claimed(bit));
+        assert!(!first.is_claimed(bit - 1));
+
+        let (index, bit) = ClaimBitmap::locate(CLAIM_BITMAP_BITS);
+        assert_eq!(index, second.index);
+        assert!(!second.is_claimed(bit));
+        second.set_claimed(bit).unwrap();
+        assert_eq!(second.bits, [0b0000_0001]);
+        assert!(second.set_claimed(bit).is_err());
+    }
+
+    #[test]
+    fn test_bits_past_the_account_are_rejected() {
+        let mut last = bitmap(3, 2);
+        assert!(!last.is_claimed(16));
+        assert!(last.set_claimed(16).is_err());
+        assert!(last.set_claimed(15).is_ok());
+    }
+}
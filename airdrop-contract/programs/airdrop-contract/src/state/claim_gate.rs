@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::errors::AirdropError;
+
+/// Claims are only paid to holders of at least `min_balance` of `mint` at claim time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+pub struct ClaimGate {
+    pub mint: Pubkey,
+    pub min_balance: u64,
+}
+
+impl ClaimGate {
+    pub fn is_valid(&self) -> bool {
+        self.min_balance > 0
+    }
+
+    /// Check that `gate_token_account` holds enough of the gate mint and belongs to `holder`.
+    pub fn check(&self, holder: &Pubkey, gate_token_account: Option<&TokenAccount>) -> Result<()> {
+        let account = gate_token_account.ok_or(AirdropError::GateAccountMissing)?;
+        require_keys_eq!(account.mint, self.mint, AirdropError::InvalidGateAccount);
+        require_keys_eq!(account.owner, *holder, AirdropError::InvalidGateAccount);
+        require!(account.amount >= self.min_balance, AirdropError::GateBalanceTooLow);
+        Ok(())
+    }
+}
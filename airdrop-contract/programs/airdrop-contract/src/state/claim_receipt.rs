@@ -3,5 +3,26 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct ClaimReceipt {
+    /// Total allocation committed to by the claimant's leaf
     pub amount: u64,
+    pub claimed_so_far: u64,
+    /// Paid the receipt's rent and receives it back when the receipt is closed
+    pub payer: Pubkey,
+}
+
+impl ClaimReceipt {
+    /// Trailing PDA seed that keys receipts of multi-mint airdrops by mint. An empty
+    /// seed adds nothing to the derivation, so single-mint receipts keep their address.
+    pub fn mint_seed(mint: Option<&Pubkey>) -> &[u8] {
+        mint.map_or(&[], |mint| mint.as_ref())
+    }
+
+    /// Record a payout, remembering who paid the rent on the first claim.
+    pub fn record_claim(&mut self, payer: Pubkey, amount: u64, claimed: u64) {
+        if self.claimed_so_far == 0 {
+            self.payer = payer;
+        }
+        self.amount = amount;
+        self.claimed_so_far += claimed;
+    }
 }
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Left at `[CLOSED_AIRDROP_SEED, merkle_root]` when an airdrop is closed. Its claim receipts
+/// may be closed afterwards, so the merkle root PDA must never be created again: a fresh
+/// deployment at the same address would pay every recipient a second time.
+#[account]
+#[derive(InitSpace)]
+pub struct ClosedAirdrop {
+    pub closed_at: i64,
+    /// The airdrop's authority, who may still close its claim bitmaps
+    pub authority: Pubkey,
+}
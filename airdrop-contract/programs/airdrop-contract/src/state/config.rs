@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_FEE_BPS;
+use crate::events::ConfigUpdated;
+
+/// What the program charges for its use, paid to the config's fee recipient.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ProtocolFee {
+    None,
+    /// Basis points of every amount deposited into an airdrop, paid by the depositor on top of it
+    FundedBps(u16),
+    /// Flat lamports per claim, paid by the claim's fee payer
+    ClaimLamports(u64),
+}
+
+impl ProtocolFee {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            ProtocolFee::FundedBps(bps) => *bps <= MAX_FEE_BPS,
+            _ => true,
+        }
+    }
+
+    /// Fee on depositing `amount`, rounded down.
+    pub fn funding_fee(&self, amount: u64) -> u64 {
+        match self {
+            ProtocolFee::FundedBps(bps) => (amount as u128 * *bps as u128 / MAX_FEE_BPS as u128) as u64,
+            _ => 0,
+        }
+    }
+
+    pub fn claim_fee(&self) -> u64 {
+        match self {
+            ProtocolFee::ClaimLamports(lamports) => *lamports,
+            _ => 0,
+        }
+    }
+}
+
+/// Program-wide settings, a singleton PDA at `[CONFIG_SEED]`. Until it is initialized no fees
+/// are charged.
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    /// May change any of these settings, including handing over the admin role
+    pub admin: Pubkey,
+    /// Wallet that receives lamport fees and owns the token accounts receiving token fees
+    pub fee_recipient: Pubkey,
+    pub fee: ProtocolFee,
+    pub bump: u8,
+}
+
+impl Config {
+    pub fn updated_event(&self, config: Pubkey) -> ConfigUpdated {
+        ConfigUpdated {
+            config,
+            admin: self.admin,
+            fee_recipient: self.fee_recipient,
+            funded_fee_bps: match self.fee {
+                ProtocolFee::FundedBps(bps) => bps,
+                _ => 0,
+            },
+            claim_fee_lamports: self.fee.claim_fee(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_funding_fee_rounds_down() {
+        let fee = ProtocolFee::FundedBps(250);
+        assert_eq!(fee.funding_fee(10_000), 250);
+        assert_eq!(fee.funding_fee(39), 0);
+        assert_eq!(fee.funding_fee(41), 1);
+        assert_eq!(fee.funding_fee(0), 0);
+        assert_eq!(fee.claim_fee(), 0);
+    }
+
+    #[test]
+    fn test_funding_fee_does_not_overflow() {
+        assert_eq!(ProtocolFee::FundedBps(MAX_FEE_BPS).funding_fee(u64::MAX), u64::MAX);
+        assert_eq!(ProtocolFee::FundedBps(1).funding_fee(u64::MAX), u64::MAX / MAX_FEE_BPS as u64);
+    }
+
+    #[test]
+    fn test_only_the_configured_fee_applies() {
+        assert_eq!(ProtocolFee::None.funding_fee(1_000), 0);
+        assert_eq!(ProtocolFee::None.claim_fee(), 0);
+        assert_eq!(ProtocolFee::ClaimLamports(5_000).funding_fee(1_000), 0);
+        assert_eq!(ProtocolFee::ClaimLamports(5_000).claim_fee(), 5_000);
+    }
+
+    #[test]
+    fn test_bps_above_the_maximum_are_invalid() {
+        assert!(ProtocolFee::FundedBps(MAX_FEE_BPS).is_valid());
+        assert!(!ProtocolFee::FundedBps(MAX_FEE_BPS + 1).is_valid());
+        assert!(ProtocolFee::ClaimLamports(u64::MAX).is_valid());
+    }
+}
@@ -0,0 +1,142 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv, Discriminator};
+use crate::constants::MERKLE_ROOT_SEED;
+use crate::errors::AirdropError;
+use crate::state::MerkleRoot;
+
+/// Merkle root account written by the program's first release, at `[MERKLE_ROOT_SEED, hash]`.
+/// It predates every `MerkleRoot` field after `mint`, so it has no authority, expiry or
+/// accounting, but it was written under the same discriminator. That is also why it is not an
+/// `#[account]`: the IDL cannot list two accounts with one discriminator, so instructions take
+/// it unchecked and read it with [`LegacyMerkleRoot::load`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyMerkleRoot {
+    pub hash: [u8; 32],
+    pub bump: u8,
+    pub mint: Pubkey,
+}
+
+impl LegacyMerkleRoot {
+    /// Account size, discriminator included
+    pub const LEN: usize = 8 + 32 + 1 + 32;
+
+    /// Read a first-release root of `mint` from `info`, which must be owned by this program,
+    /// have the original size and discriminator, and sit at the root-only PDA its fields derive.
+    pub fn load(info: &AccountInfo, mint: &Pubkey) -> Result<Self> {
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() == Self::LEN && data[..8] == *MerkleRoot::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let root = Self::deserialize(&mut &data[8..])?;
+
+        let address = Pubkey::create_program_address(&root.signer_seeds(), &crate::ID)
+            .map_err(|_| ErrorCode::ConstraintSeeds)?;
+        require_keys_eq!(address, info.key(), ErrorCode::ConstraintSeeds);
+        require_keys_eq!(root.mint, *mint, AirdropError::InvalidMint);
+        Ok(root)
+    }
+
+    /// PDA seeds used when the merkle root signs for its vault.
+    pub fn signer_seeds(&self) -> Vec<&[u8]> {
+        vec![MERKLE_ROOT_SEED, self.hash.as_ref(), std::slice::from_ref(&self.bump)]
+    }
+
+    /// Check that `(claimant, amount)` is the leaf at `leaf_index`, hashed the way the first
+    /// release did: sha256 of the base58 address and little-endian amount, and unprefixed
+    /// sha256 nodes ordered by position.
+    pub fn verify_proof(&self, claimant: &Pubkey, proof: &[[u8; 32]], amount: u64, leaf_index: u32) -> Result<()> {
+        let mut hash = hashv(&[claimant.to_string().as_bytes(), &amount.to_le_bytes()]).to_bytes();
+        let mut proof_index = leaf_index;
+
+        for neighbor_hash in proof {
+            hash = if proof_index % 2 == 0 {
+                hashv(&[&hash, neighbor_hash])
+            } else {
+                hashv(&[neighbor_hash, &hash])
+            }
+            .to_bytes();
+
+            proof_index /= 2;
+        }
+
+        require!(hash == self.hash, AirdropError::InvalidProof);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    /// Account data exactly as the first release's `create_airdrop` wrote it
+    fn baseline_account(root: [u8; 32], bump: u8, mint: Pubkey) -> Vec<u8> {
+        let mut data = hash(b"account:MerkleRoot").to_bytes()[..8].to_vec();
+        data.extend_from_slice(&root);
+        data.push(bump);
+        data.extend_from_slice(mint.as_ref());
+        data
+    }
+
+    /// Load `data` as a root of `mint` stored at `key` under `owner`
+    fn load(key: Pubkey, owner: Pubkey, mut data: Vec<u8>, mint: &Pubkey) -> Result<LegacyMerkleRoot> {
+        let mut lamports = 0;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        LegacyMerkleRoot::load(&info, mint)
+    }
+
+    fn leaf(claimant: &Pubkey, amount: u64) -> [u8; 32] {
+        hashv(&[claimant.to_string().as_bytes(), &amount.to_le_bytes()]).to_bytes()
+    }
+
+    #[test]
+    fn test_reads_baseline_layout() {
+        let (root, mint) = ([7u8; 32], Pubkey::new_unique());
+        let (address, bump) = Pubkey::find_program_address(&[MERKLE_ROOT_SEED, &root], &crate::ID);
+        let data = baseline_account(root, bump, mint);
+        assert_eq!(data.len(), LegacyMerkleRoot::LEN);
+
+        let legacy = load(address, crate::ID, data.clone(), &mint).unwrap();
+        assert_eq!(legacy.hash, root);
+        assert_eq!(legacy.bump, bump);
+        assert_eq!(legacy.mint, mint);
+        // Too short for the current layout, so only the legacy type can read it
+        assert!(MerkleRoot::try_deserialize(&mut data.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_rejects_other_accounts() {
+        let (root, mint) = ([7u8; 32], Pubkey::new_unique());
+        let (address, bump) = Pubkey::find_program_address(&[MERKLE_ROOT_SEED, &root], &crate::ID);
+        let data = baseline_account(root, bump, mint);
+
+        assert!(load(address, crate::ID, data.clone(), &Pubkey::new_unique()).is_err());
+        assert!(load(address, Pubkey::new_unique(), data.clone(), &mint).is_err());
+        assert!(load(Pubkey::new_unique(), crate::ID, data.clone(), &mint).is_err());
+        let mut longer = data.clone();
+        longer.push(0);
+        assert!(load(address, crate::ID, longer, &mint).is_err());
+        let mut other = data;
+        other[0] ^= 1;
+        assert!(load(address, crate::ID, other, &mint).is_err());
+    }
+
+    #[test]
+    fn test_verifies_baseline_proofs() {
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (left, right) = (leaf(&alice, 100), leaf(&bob, 200));
+        let legacy = LegacyMerkleRoot {
+            hash: hashv(&[&left, &right]).to_bytes(),
+            bump: 255,
+            mint: Pubkey::new_unique(),
+        };
+
+        // Leaves sit at 2 and 3 in the heap-ordered tree
+        assert!(legacy.verify_proof(&alice, &[right], 100, 2).is_ok());
+        assert!(legacy.verify_proof(&bob, &[left], 200, 3).is_ok());
+        assert!(legacy.verify_proof(&alice, &[right], 101, 2).is_err());
+        assert!(legacy.verify_proof(&alice, &[right], 100, 3).is_err());
+        assert!(legacy.verify_proof(&alice, &[left], 200, 3).is_err());
+    }
+}
@@ -1,9 +1,357 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+use anchor_spl::token_interface::TokenAccount;
+use solana_keccak_hasher as keccak;
+use crate::constants::{CLAIM_BITMAP_BITS, HASH_VERSION_KECCAK, HASH_VERSION_V1, HASH_VERSION_V2, LEAF_ENCODING_BASE58, LEAF_ENCODING_PUBKEY, LEAF_PREFIX, MERKLE_ROOT_SEED, NODE_PREFIX};
+use crate::errors::AirdropError;
+use crate::state::{ClaimGate, VestingSchedule};
 
 #[account]
 #[derive(InitSpace)]
 pub struct MerkleRoot {
+    /// Current root that claims are verified against
     pub hash: [u8; 32],
+    /// Root the PDA was derived from; stays fixed when a cumulative airdrop publishes new roots
+    pub seed_hash: [u8; 32],
     pub bump: u8,
     pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub expires_at: i64,
+    pub claim_start: Option<i64>,
+    pub claim_end: Option<i64>,
+    pub vesting: Option<VestingSchedule>,
+    /// Leaves commit to lifetime totals and the authority may publish new roots
+    pub cumulative: bool,
+    pub epoch: u32,
+    /// Set by the authority to halt claims in an emergency
+    pub paused: bool,
+    /// Leaf and node hashing scheme, one of the `HASH_VERSION_*` constants
+    pub hash_version: u8,
+    /// How leaves commit to the claimant's address, one of the `LEAF_ENCODING_*` constants
+    pub leaf_encoding: u8,
+    /// Set for voucher airdrops: claims carry an ed25519 voucher from this key instead of a merkle proof
+    pub voucher_signer: Option<Pubkey>,
+    /// Set for bitmap airdrops: claims flip a bit in `ClaimBitmap` accounts instead of creating receipts
+    pub bitmap_leaf_count: Option<u32>,
+    /// Creator the PDA is namespaced under
+    pub creator: Pubkey,
+    /// Creator-chosen nonce as it appears in the PDA seeds (little-endian u64)
+    pub nonce: [u8; 8],
+    /// Leaves commit to `(claimant, mint, amount)` and each mint has its own vault;
+    /// `mint` is then just the one funded at creation
+    pub multi_mint: bool,
+    /// Pays out lamports held by this account instead of SPL tokens; `mint` is then unused
+    pub native_sol: bool,
+    /// Everything that has reached the vault, net of any transfer fee on the way in.
+    /// Stays 0 for multi-mint airdrops, whose base units of different mints cannot be added up.
+    pub total_amount: u64,
+    /// Everything claims have taken out of the vault, including transfer fees they covered.
+    /// Stays 0 for multi-mint airdrops.
+    pub claimed_amount: u64,
+    /// Successful claim instructions; each installment of a vesting or cumulative claim counts
+    pub num_claims: u64,
+    /// Leaves in the current tree; 0 for voucher airdrops
+    pub num_leaves: u32,
+    /// Claims mint `mint` to the recipient, this account being its mint authority, instead of
+    /// paying from a vault; `total_amount` is then the declared cap on what may be minted
+    pub mint_on_claim: bool,
+    /// Claimants must hold this token when they claim, not just at snapshot time
+    pub gate: Option<ClaimGate>,
+    /// Vaults of a multi-mint airdrop's additional mints that `close_vault` has yet to close
+    pub open_vaults: u16,
+}
+
+impl MerkleRoot {
+    /// PDA seeds used when the merkle root signs for its vault.
+    pub fn signer_seeds(&self) -> Vec<&[u8]> {
+        vec![
+            MERKLE_ROOT_SEED,
+            self.creator.as_ref(),
+            &self.nonce,
+            self.seed_hash.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+
+    /// Count `amount` arriving in the vault. Multi-mint airdrops keep no aggregate; each vault's
+    /// balance is its own limit.
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        if self.multi_mint {
+            return Ok(());
+        }
+        self.total_amount = self
+            .total_amount
+            .checked_add(amount)
+            .ok_or(AirdropError::InvalidAmount)?;
+        Ok(())
+    }
+
+    /// Count a claim taking `amount` out of the vault, refusing to pay out more than was funded.
+    /// Multi-mint airdrops only count the claim.
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.num_claims += 1;
+        if self.multi_mint {
+            return Ok(());
+        }
+        self.claimed_amount = self
+            .claimed_amount
+            .checked_add(amount)
+            .filter(|claimed| *claimed <= self.total_amount)
+            .ok_or(AirdropError::ClaimExceedsFunding)?;
+        Ok(())
+    }
+
+    /// Whether this airdrop pays out `mint` from a vault.
+    pub fn accepts_mint(&self, mint: &Pubkey) -> bool {
+        !self.native_sol && !self.mint_on_claim && (self.multi_mint || *mint == self.mint)
+    }
+
+    /// Lamports a SOL airdrop holds beyond what keeps this account rent-exempt.
+    pub fn sol_balance(info: &AccountInfo) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(info.data_len());
+        Ok(info.lamports().saturating_sub(rent_exempt))
+    }
+
+    /// Mint to key claim receipts by, which only multi-mint airdrops do.
+    pub fn receipt_mint<'a>(&self, mint: &'a Pubkey) -> Option<&'a Pubkey> {
+        self.multi_mint.then_some(mint)
+    }
+
+    /// Run every check a merkle claim must pass: claimable now and
+    /// `(claimant, mint, amount)` proven to be the leaf at `leaf_index`. Keccak trees
+    /// hash sorted pairs, so their proofs verify whatever `leaf_index` is passed.
+    pub fn verify_claim(&self, claimant: &Pubkey, mint: &Pubkey, proof: &[[u8; 32]], amount: u64, leaf_index: u32, now: i64) -> Result<()> {
+        require!(
+            self.voucher_signer.is_none() && self.bitmap_leaf_count.is_none(),
+            AirdropError::WrongDistributionType
+        );
+        self.check_claimable(now)?;
+        self.verify_proof(claimant, mint, proof, amount, leaf_index)
+    }
+
+    /// Bitmap counterpart of `verify_claim`, returning the leaf's position in the
+    /// leaf layer. The claim flag is keyed by that position, so the proof must pin it:
+    /// exactly one sibling per level and a `leaf_index` inside the leaf layer.
+    pub fn verify_bitmap_claim(&self, claimant: &Pubkey, proof: &[[u8; 32]], amount: u64, leaf_index: u32, now: i64) -> Result<u32> {
+        let leaf_count = self.bitmap_leaf_count.ok_or(AirdropError::WrongDistributionType)?;
+        self.check_claimable(now)?;
+
+        let depth = leaf_count.next_power_of_two().trailing_zeros();
+        require!(proof.len() == depth as usize && leaf_index >> depth == 1, AirdropError::InvalidProof);
+        let position = leaf_index - (1 << depth);
+        require!(position < leaf_count, AirdropError::InvalidProof);
+
+        self.verify_proof(claimant, &self.mint, proof, amount, leaf_index)?;
+        Ok(position)
+    }
+
+    /// Number of `ClaimBitmap` accounts a bitmap airdrop needs.
+    pub fn bitmap_account_count(&self) -> u32 {
+        self.bitmap_leaf_count.map_or(0, |leaves| leaves.div_ceil(CLAIM_BITMAP_BITS))
+    }
+
+    /// Bytes of flags in bitmap account `index`, or 0 past the last one.
+    pub fn bitmap_len(&self, index: u32) -> usize {
+        let leaves = self.bitmap_leaf_count.unwrap_or(0);
+        let bits = leaves
+            .saturating_sub(index.saturating_mul(CLAIM_BITMAP_BITS))
+            .min(CLAIM_BITMAP_BITS);
+        bits.div_ceil(8) as usize
+    }
+
+    fn verify_proof(&self, claimant: &Pubkey, mint: &Pubkey, proof: &[[u8; 32]], amount: u64, leaf_index: u32) -> Result<()> {
+        let mut hash = self.leaf_hash(claimant, mint, amount);
+        let mut proof_index = leaf_index;
+
+        for neighbor_hash in proof {
+            if proof_index % 2 == 0 {
+                hash = self.node_hash(&hash, neighbor_hash);
+            } else {
+                hash = self.node_hash(neighbor_hash, &hash);
+            }
+
+            proof_index /= 2;
+        }
+
+        require!(hash == self.hash, AirdropError::InvalidProof);
+        Ok(())
+    }
+
+    /// Whether `hash_version` names a scheme this program can verify.
+    pub fn is_supported_hash_version(hash_version: u8) -> bool {
+        matches!(hash_version, HASH_VERSION_V1 | HASH_VERSION_V2 | HASH_VERSION_KECCAK)
+    }
+
+    /// Whether `leaf_encoding` names an encoding this program can verify.
+    pub fn is_supported_leaf_encoding(leaf_encoding: u8) -> bool {
+        matches!(leaf_encoding, LEAF_ENCODING_BASE58 | LEAF_ENCODING_PUBKEY)
+    }
+
+    /// Multi-mint leaves insert the raw mint bytes between the address and the amount.
+    fn leaf_hash(&self, claimant: &Pubkey, mint: &Pubkey, amount: u64) -> [u8; 32] {
+        let mint: &[u8] = if self.multi_mint { mint.as_ref() } else { &[] };
+        if self.hash_version == HASH_VERSION_KECCAK {
+            // abi.encode(bytes32[, bytes32], uint256): the amount as a big-endian 32-byte word
+            let mut amount_word = [0u8; 32];
+            amount_word[24..].copy_from_slice(&amount.to_be_bytes());
+            let inner = keccak::hashv(&[claimant.as_ref(), mint, &amount_word]);
+            return keccak::hashv(&[inner.as_ref()]).to_bytes();
+        }
+
+        let base58;
+        let address = match self.leaf_encoding {
+            LEAF_ENCODING_BASE58 => {
+                base58 = claimant.to_string();
+                base58.as_bytes()
+            }
+            _ => claimant.as_ref(),
+        };
+        let amount = amount.to_le_bytes();
+        match self.hash_version {
+            HASH_VERSION_V1 => hashv(&[address, mint, &amount]),
+            _ => hashv(&[LEAF_PREFIX, address, mint, &amount]),
+        }
+        .to_bytes()
+    }
+
+    fn node_hash(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        match self.hash_version {
+            HASH_VERSION_V1 => hashv(&[left, right]),
+            HASH_VERSION_KECCAK => {
+                let (low, high) = if left <= right { (left, right) } else { (right, left) };
+                return keccak::hashv(&[low, high]).to_bytes();
+            }
+            _ => hashv(&[NODE_PREFIX, left, right]),
+        }
+        .to_bytes()
+    }
+
+    /// Checks shared by every claim path: not paused and inside the claim window.
+    pub fn check_claimable(&self, now: i64) -> Result<()> {
+        require!(!self.paused, AirdropError::AirdropPaused);
+        self.check_claim_window(now)
+    }
+
+    /// For gated airdrops, check that `holder` passed enough of the gate mint.
+    pub fn check_gate(&self, holder: &Pubkey, gate_token_account: Option<&TokenAccount>) -> Result<()> {
+        match &self.gate {
+            Some(gate) => gate.check(holder, gate_token_account),
+            None => Ok(()),
+        }
+    }
+
+    /// Amount to release now given what the receipt has already paid out.
+    pub fn claimable_amount(&self, amount: u64, claimed_so_far: u64, now: i64) -> Result<u64> {
+        let claimable = self.vested_amount(amount, now).saturating_sub(claimed_so_far);
+        require!(claimable > 0, AirdropError::NothingToClaim);
+        Ok(claimable)
+    }
+
+    /// Reject claims made outside the optional `[claim_start, claim_end)` window.
+    pub fn check_claim_window(&self, now: i64) -> Result<()> {
+        if let Some(start) = self.claim_start {
+            require!(now >= start, AirdropError::ClaimNotStarted);
+        }
+        if let Some(end) = self.claim_end {
+            require!(now < end, AirdropError::ClaimEnded);
+        }
+        Ok(())
+    }
+
+    /// Portion of a leaf's `amount` that has unlocked at `now`.
+    pub fn vested_amount(&self, amount: u64, now: i64) -> u64 {
+        match &self.vesting {
+            Some(schedule) => schedule.vested_amount(amount, now),
+            None => amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ClaimBitmap;
+
+    /// An airdrop with every field zeroed but its hashing scheme
+    fn merkle_root(hash_version: u8, leaf_encoding: u8) -> MerkleRoot {
+        let mut merkle_root = MerkleRoot::deserialize(&mut &[0u8; MerkleRoot::INIT_SPACE][..]).unwrap();
+        merkle_root.hash_version = hash_version;
+        merkle_root.leaf_encoding = leaf_encoding;
+        merkle_root
+    }
+
+    #[test]
+    fn test_keccak_hasher_matches_known_vector() {
+        assert_eq!(
+            keccak::hashv(&[]).to_bytes().to_vec(),
+            hex::decode("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_keccak_leaf_is_double_hashed_abi_encoding() {
+        let mut merkle_root = merkle_root(HASH_VERSION_KECCAK, LEAF_ENCODING_PUBKEY);
+        let claimant = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let amount_word = hex::decode(format!("{:064x}", 20u64)).unwrap();
+
+        let inner = keccak::hashv(&[claimant.as_ref(), &amount_word]);
+        assert_eq!(merkle_root.leaf_hash(&claimant, &mint, 20), keccak::hashv(&[inner.as_ref()]).to_bytes());
+
+        // Multi-mint leaves encode the mint as a second bytes32
+        merkle_root.multi_mint = true;
+        let inner = keccak::hashv(&[claimant.as_ref(), mint.as_ref(), &amount_word]);
+        assert_eq!(merkle_root.leaf_hash(&claimant, &mint, 20), keccak::hashv(&[inner.as_ref()]).to_bytes());
+    }
+
+    #[test]
+    fn test_keccak_nodes_hash_sorted_pairs() {
+        let merkle_root = merkle_root(HASH_VERSION_KECCAK, LEAF_ENCODING_PUBKEY);
+        let (low, high) = ([1u8; 32], [2u8; 32]);
+        let expected = keccak::hashv(&[&low, &high]).to_bytes();
+        assert_eq!(merkle_root.node_hash(&low, &high), expected);
+        assert_eq!(merkle_root.node_hash(&high, &low), expected);
+    }
+
+    #[test]
+    fn test_keccak_proofs_ignore_the_leaf_index() {
+        let mut merkle_root = merkle_root(HASH_VERSION_KECCAK, LEAF_ENCODING_PUBKEY);
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mint = Pubkey::default();
+        let leaves = [merkle_root.leaf_hash(&first, &mint, 5), merkle_root.leaf_hash(&second, &mint, 7)];
+        merkle_root.hash = merkle_root.node_hash(&leaves[0], &leaves[1]);
+
+        for leaf_index in [0, 1, 42] {
+            assert!(merkle_root.verify_proof(&first, &mint, &[leaves[1]], 5, leaf_index).is_ok());
+            assert!(merkle_root.verify_proof(&second, &mint, &[leaves[0]], 7, leaf_index).is_ok());
+        }
+        assert!(merkle_root.verify_proof(&second, &mint, &[leaves[0]], 5, 0).is_err());
+    }
+
+    #[test]
+    fn test_bitmap_accounts_split_at_claim_bitmap_bits() {
+        let mut merkle_root = merkle_root(HASH_VERSION_V2, LEAF_ENCODING_PUBKEY);
+        assert_eq!(merkle_root.bitmap_account_count(), 0);
+        assert_eq!(merkle_root.bitmap_len(0), 0);
+
+        // Exactly one full account
+        merkle_root.bitmap_leaf_count = Some(CLAIM_BITMAP_BITS);
+        assert_eq!(merkle_root.bitmap_account_count(), 1);
+        assert_eq!(merkle_root.bitmap_len(0), (CLAIM_BITMAP_BITS / 8) as usize);
+        assert_eq!(merkle_root.bitmap_len(1), 0);
+
+        // One leaf over spills a single byte into a second account
+        merkle_root.bitmap_leaf_count = Some(CLAIM_BITMAP_BITS + 1);
+        assert_eq!(merkle_root.bitmap_account_count(), 2);
+        assert_eq!(merkle_root.bitmap_len(0), (CLAIM_BITMAP_BITS / 8) as usize);
+        assert_eq!(merkle_root.bitmap_len(1), 1);
+        assert_eq!(merkle_root.bitmap_len(2), 0);
+
+        // Every leaf position lands on a bit its account was allocated
+        merkle_root.bitmap_leaf_count = Some(2 * CLAIM_BITMAP_BITS + 9);
+        assert_eq!(merkle_root.bitmap_account_count(), 3);
+        assert_eq!(merkle_root.bitmap_len(2), 2);
+        let (index, bit) = ClaimBitmap::locate(2 * CLAIM_BITMAP_BITS + 8);
+        assert!(((bit / 8) as usize) < merkle_root.bitmap_len(index));
+        assert_eq!(merkle_root.bitmap_len(u32::MAX), 0);
+    }
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_METADATA_NAME_LEN, MAX_METADATA_URI_LEN};
+
+/// Display details that wallets and apps show instead of the hex root, at
+/// `[METADATA_SEED, merkle_root]`.
+#[account]
+#[derive(InitSpace)]
+pub struct AirdropMetadata {
+    pub merkle_root: Pubkey,
+    /// Authority of the airdrop that wrote the metadata
+    pub creator: Pubkey,
+    #[max_len(MAX_METADATA_NAME_LEN)]
+    pub name: String,
+    /// Off-chain JSON or page describing the airdrop
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub description_uri: String,
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub image_uri: String,
+    /// Where recipients go to claim
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub website: String,
+    pub bump: u8,
+}
+
+/// Metadata fields as given to `update_metadata`; empty strings leave a field blank.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct MetadataParams {
+    pub name: String,
+    pub description_uri: String,
+    pub image_uri: String,
+    pub website: String,
+}
+
+impl MetadataParams {
+    pub fn is_valid(&self) -> bool {
+        self.name.len() <= MAX_METADATA_NAME_LEN
+            && [&self.description_uri, &self.image_uri, &self.website]
+                .iter()
+                .all(|uri| uri.len() <= MAX_METADATA_URI_LEN)
+    }
+}
@@ -1,4 +1,22 @@
 pub mod merkle_root;
+pub mod legacy_merkle_root;
 pub mod claim_receipt;
+pub mod claim_bitmap;
+pub mod vesting;
+pub mod claim_gate;
+pub mod voucher;
+pub mod config;
+pub mod metadata;
+pub mod closed_airdrop;
+pub mod vault_record;
 pub use merkle_root::*;
+pub use legacy_merkle_root::*;
 pub use claim_receipt::*;
+pub use claim_bitmap::*;
+pub use vesting::*;
+pub use claim_gate::*;
+pub use voucher::*;
+pub use config::*;
+pub use metadata::*;
+pub use closed_airdrop::*;
+pub use vault_record::*;
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+/// Kept at `[VAULT_RECORD_SEED, merkle_root, mint]` while a multi-mint airdrop has a vault for
+/// one of its additional mints, so the airdrop cannot be closed over tokens only it can sign for.
+#[account]
+#[derive(InitSpace)]
+pub struct VaultRecord {
+    /// Default until `fund_airdrop` first opens the vault
+    pub mint: Pubkey,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+/// Linear unlock between `start` and `end`, with nothing claimable before `cliff`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+pub struct VestingSchedule {
+    pub start: i64,
+    pub cliff: i64,
+    pub end: i64,
+}
+
+impl VestingSchedule {
+    pub fn is_valid(&self) -> bool {
+        self.start < self.end && self.start <= self.cliff && self.cliff <= self.end
+    }
+
+    /// Portion of `total` unlocked at `now`.
+    pub fn vested_amount(&self, total: u64, now: i64) -> u64 {
+        if now < self.cliff {
+            return 0;
+        }
+        if now >= self.end {
+            return total;
+        }
+
+        // Schedules may span more than i64::MAX seconds, so subtract in i128
+        let elapsed = (now as i128 - self.start as i128) as u128;
+        let duration = (self.end as i128 - self.start as i128) as u128;
+        (total as u128 * elapsed / duration) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEDULE: VestingSchedule = VestingSchedule { start: 1_000, cliff: 1_250, end: 2_000 };
+
+    #[test]
+    fn test_nothing_vests_before_the_cliff() {
+        assert_eq!(SCHEDULE.vested_amount(1_000, 0), 0);
+        assert_eq!(SCHEDULE.vested_amount(1_000, 1_249), 0);
+    }
+
+    #[test]
+    fn test_cliff_releases_the_time_since_start() {
+        assert_eq!(SCHEDULE.vested_amount(1_000, 1_250), 250);
+        assert_eq!(SCHEDULE.vested_amount(1_000, 1_500), 500);
+        // Rounded down
+        assert_eq!(SCHEDULE.vested_amount(3, 1_500), 1);
+    }
+
+    #[test]
+    fn test_everything_vests_at_the_end() {
+        assert_eq!(SCHEDULE.vested_amount(1_000, 1_999), 999);
+        assert_eq!(SCHEDULE.vested_amount(1_000, 2_000), 1_000);
+        assert_eq!(SCHEDULE.vested_amount(1_000, i64::MAX), 1_000);
+    }
+
+    #[test]
+    fn test_no_overflow_at_the_extremes() {
+        let schedule = VestingSchedule { start: i64::MIN, cliff: i64::MIN, end: i64::MAX };
+        assert!(schedule.is_valid());
+        assert_eq!(schedule.vested_amount(u64::MAX, i64::MIN), 0);
+        assert_eq!(schedule.vested_amount(u64::MAX, 0), 1 << 63);
+        assert_eq!(schedule.vested_amount(u64::MAX, i64::MAX - 1), u64::MAX - 1);
+    }
+}
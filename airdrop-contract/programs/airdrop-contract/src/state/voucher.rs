@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, sysvar::instructions::{load_current_index_checked, load_instruction_at_checked}};
+use crate::errors::AirdropError;
+
+const VOUCHER_DOMAIN: &[u8] = b"airdrop-voucher";
+
+// Layout of an Ed25519 program instruction carrying a single signature
+const ED25519_HEADER_LEN: usize = 16;
+const ED25519_PUBKEY_LEN: usize = 32;
+const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Off-chain authorization for `recipient` to claim `amount` from a voucher airdrop.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct Voucher {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    /// Unique per voucher; keys the claim receipt so each voucher is redeemed once
+    pub nonce: u64,
+    pub expires_at: i64,
+}
+
+impl Voucher {
+    /// Bytes the voucher signer signs: a domain tag, the airdrop account, then the voucher fields.
+    pub fn message(&self, airdrop: &Pubkey) -> Vec<u8> {
+        [
+            VOUCHER_DOMAIN,
+            airdrop.as_ref(),
+            self.recipient.as_ref(),
+            &self.amount.to_le_bytes(),
+            &self.nonce.to_le_bytes(),
+            &self.expires_at.to_le_bytes(),
+        ]
+        .concat()
+    }
+
+    /// Require the instruction just before the current one to be an Ed25519
+    /// program check of `signer`'s signature over this voucher.
+    pub fn verify_signature(&self, instructions_sysvar: &AccountInfo, signer: &Pubkey, airdrop: &Pubkey) -> Result<()> {
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        require!(current_index > 0, AirdropError::InvalidVoucherSignature);
+        let ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+        require!(ix.program_id == ed25519_program::ID, AirdropError::InvalidVoucherSignature);
+        require!(ix.accounts.is_empty(), AirdropError::InvalidVoucherSignature);
+
+        let message = self.message(airdrop);
+        let data = &ix.data;
+        require!(
+            data.len() == ED25519_HEADER_LEN + ED25519_PUBKEY_LEN + ED25519_SIGNATURE_LEN + message.len(),
+            AirdropError::InvalidVoucherSignature
+        );
+        // Exactly one signature, with every offset pointing into this instruction's own data
+        require!(data[0] == 1, AirdropError::InvalidVoucherSignature);
+        let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+        let signature_offset = read_u16(2) as usize;
+        let pubkey_offset = read_u16(6) as usize;
+        let message_offset = read_u16(10) as usize;
+        let message_size = read_u16(12) as usize;
+        for index_at in [4, 8, 14] {
+            require!(read_u16(index_at) == u16::MAX, AirdropError::InvalidVoucherSignature);
+        }
+        require!(
+            signature_offset + ED25519_SIGNATURE_LEN <= data.len()
+                && pubkey_offset + ED25519_PUBKEY_LEN <= data.len()
+                && message_offset + message_size <= data.len(),
+            AirdropError::InvalidVoucherSignature
+        );
+
+        require!(
+            &data[pubkey_offset..pubkey_offset + ED25519_PUBKEY_LEN] == signer.as_ref(),
+            AirdropError::InvalidVoucherSignature
+        );
+        require!(
+            data[message_offset..message_offset + message_size] == message[..],
+            AirdropError::InvalidVoucherSignature
+        );
+        Ok(())
+    }
+}
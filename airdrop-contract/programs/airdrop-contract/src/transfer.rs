@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeConfig},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+};
+use anchor_lang::system_program::Transfer;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked};
+use crate::errors::AirdropError;
+use crate::fees;
+use crate::state::MerkleRoot;
+
+/// `transfer_checked` for SPL Token and Token-2022 mints alike.
+///
+/// Unlike `anchor_spl::token_interface::transfer_checked`, the context's remaining accounts are
+/// forwarded, so a Token-2022 transfer hook can find its extra accounts among them.
+pub fn transfer_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    spl_token_2022::onchain::invoke_transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+        &ctx.remaining_accounts,
+        amount,
+        decimals,
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// The mint's transfer fee for the current epoch. Mints without a transfer fee charge a zero one.
+pub fn epoch_transfer_fee(mint: &InterfaceAccount<Mint>) -> Result<TransferFee> {
+    let info = mint.to_account_info();
+    let data = info.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let Ok(config) = state.get_extension::<TransferFeeConfig>() else {
+        return Ok(TransferFee::default());
+    };
+    Ok(*config.get_epoch_fee(Clock::get()?.epoch))
+}
+
+/// Amount to send so that `net` arrives after `fee`. Every installment of a vesting or cumulative
+/// claim is grossed up on its own, so each arrives in full.
+pub fn gross_up(fee: &TransferFee, net: u64) -> Result<u64> {
+    fee.calculate_pre_fee_amount(net)
+        .ok_or_else(|| AirdropError::InvalidAmount.into())
+}
+
+/// Amount that arrives when `gross` is sent, after `fee`.
+pub fn amount_received(fee: &TransferFee, gross: u64) -> Result<u64> {
+    fee.calculate_post_fee_amount(gross)
+        .ok_or_else(|| AirdropError::InvalidAmount.into())
+}
+
+/// How a claim reaches its recipient
+pub enum Payout<'a, 'info> {
+    /// Tokens out of the airdrop's vault, grossed up for any Token-2022 transfer fee. The hook
+    /// accounts are forwarded to the transfer for mints with a transfer hook.
+    Vault {
+        vault: &'a InterfaceAccount<'info, TokenAccount>,
+        destination: &'a InterfaceAccount<'info, TokenAccount>,
+        mint: &'a InterfaceAccount<'info, Mint>,
+        token_program: &'a Interface<'info, TokenInterface>,
+        hook_accounts: &'a [AccountInfo<'info>],
+    },
+    /// Lamports out of a SOL airdrop, whose merkle root account is its vault
+    Lamports { recipient: AccountInfo<'info> },
+    /// Tokens minted on the spot, the merkle root being the mint authority
+    MintTo {
+        destination: &'a InterfaceAccount<'info, TokenAccount>,
+        mint: &'a InterfaceAccount<'info, Mint>,
+        token_program: &'a Interface<'info, TokenInterface>,
+    },
+}
+
+/// Accounts that pay and receive a claim's protocol fee
+pub struct ClaimFee<'info> {
+    pub payer: AccountInfo<'info>,
+    pub config: AccountInfo<'info>,
+    pub fee_recipient: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Everything a claim does once its proof or voucher has been verified: check the gate held by
+/// `holder`, pay `net` to the recipient, charge the protocol claim fee and count the payout.
+pub fn pay_claim<'info>(
+    merkle_root: &mut Account<'info, MerkleRoot>,
+    holder: &Pubkey,
+    gate_token_account: Option<&TokenAccount>,
+    payout: Payout<'_, 'info>,
+    fee: ClaimFee<'info>,
+    net: u64,
+) -> Result<()> {
+    merkle_root.check_gate(holder, gate_token_account)?;
+
+    let seeds = merkle_root.signer_seeds();
+    let signer = &[&seeds[..]];
+    let paid = match payout {
+        Payout::Vault { vault, destination, mint, token_program, hook_accounts } => {
+            // Cover any Token-2022 transfer fee so the recipient receives the full amount
+            let gross = gross_up(&epoch_transfer_fee(mint)?, net)?;
+            let accounts = TransferChecked {
+                from: vault.to_account_info(),
+                to: destination.to_account_info(),
+                authority: merkle_root.to_account_info(),
+                mint: mint.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), accounts, signer)
+                .with_remaining_accounts(hook_accounts.to_vec());
+            transfer_checked(cpi_ctx, gross, mint.decimals)?;
+            gross
+        }
+        Payout::Lamports { recipient } => {
+            // The merkle root must stay rent-exempt
+            require!(
+                MerkleRoot::sol_balance(&merkle_root.to_account_info())? >= net,
+                AirdropError::InsufficientVaultBalance
+            );
+            // The runtime rejects leaving a new account with less than rent exemption; say why
+            let rent_exempt = Rent::get()?.minimum_balance(recipient.data_len());
+            require!(
+                recipient.lamports().saturating_add(net) >= rent_exempt,
+                AirdropError::RecipientNotRentExempt
+            );
+            merkle_root.sub_lamports(net)?;
+            recipient.add_lamports(net)?;
+            net
+        }
+        Payout::MintTo { destination, mint, token_program } => {
+            // Minting charges no transfer fee, so the recipient gets exactly `net`
+            let accounts = MintTo {
+                mint: mint.to_account_info(),
+                to: destination.to_account_info(),
+                authority: merkle_root.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), accounts, signer);
+            token_interface::mint_to(cpi_ctx, net)?;
+            net
+        }
+    };
+
+    // Protocol fee, if the program config charges one per claim
+    let accounts = Transfer {
+        from: fee.payer,
+        to: fee.fee_recipient,
+    };
+    let cpi_ctx = CpiContext::new(fee.system_program, accounts);
+    fees::charge_claim_fee(&fee.config, cpi_ctx, merkle_root.key())?;
+
+    // Caps the payout at what the airdrop was funded with, or for mint-on-claim declared
+    merkle_root.record_payout(paid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer_fee(basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: basis_points.into(),
+        }
+    }
+
+    #[test]
+    fn test_no_fee_sends_the_net_amount() {
+        let fee = TransferFee::default();
+        assert_eq!(gross_up(&fee, 1_000).unwrap(), 1_000);
+        assert_eq!(amount_received(&fee, 1_000).unwrap(), 1_000);
+        assert_eq!(gross_up(&fee, u64::MAX).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_gross_up_covers_the_fee() {
+        // 1%, uncapped
+        let fee = transfer_fee(100, u64::MAX);
+        assert_eq!(gross_up(&fee, 990).unwrap(), 1_000);
+        assert_eq!(amount_received(&fee, 1_000).unwrap(), 990);
+        for net in [1, 7, 99, 1_000, 123_456_789] {
+            assert_eq!(amount_received(&fee, gross_up(&fee, net).unwrap()).unwrap(), net);
+        }
+
+        // 5% capped at 10
+        let fee = transfer_fee(500, 10);
+        assert_eq!(gross_up(&fee, 1_000).unwrap(), 1_010);
+        assert_eq!(amount_received(&fee, 1_010).unwrap(), 1_000);
+        assert_eq!(amount_received(&fee, 100).unwrap(), 95);
+    }
+
+    #[test]
+    fn test_each_installment_arrives_in_full() {
+        for fee in [transfer_fee(100, u64::MAX), transfer_fee(333, u64::MAX), transfer_fee(500, 10)] {
+            for net in [333, 333, 334] {
+                assert_eq!(amount_received(&fee, gross_up(&fee, net).unwrap()).unwrap(), net);
+            }
+        }
+    }
+
+    #[test]
+    fn test_overflow_is_an_invalid_amount() {
+        let fee = transfer_fee(100, u64::MAX);
+        assert!(gross_up(&fee, u64::MAX).is_err());
+    }
+}
@@ -7,6 +7,11 @@ import { kp1, kp2, kp3, kp4 } from './keypairs';
 import { MERKLE_ROOT, PROOF1 } from "./constants";
 
 
+// The fixture tree hashes base58 addresses into v1 leaves
+const HASH_VERSION_V1 = 1;
+const LEAF_ENCODING_BASE58 = 0;
+const NONCE = new anchor.BN(0);
+
 describe("airdrop-contract", () => {
   // Configure the client to use the local cluster.
   anchor.setProvider(anchor.AnchorProvider.env());
@@ -16,16 +21,56 @@ describe("airdrop-contract", () => {
   const connection = anchor.getProvider().connection;
   let mint: anchor.web3.PublicKey, creatorTokenAccount: anchor.web3.PublicKey;
 
+  // Airdrop addresses are namespaced by creator and nonce
+  const merkleRoot = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("merkle_root"), authority.publicKey.toBuffer(), NONCE.toArrayLike(Buffer, "le", 8), Buffer.from(MERKLE_ROOT)],
+    program.programId
+  )[0];
+  const config = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("config")], program.programId)[0];
+
+  const claimAccounts = (claimant: anchor.web3.PublicKey) => ({
+    authority: claimant,
+    payer: claimant,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    mint,
+    merkleRoot,
+    claimReceipt: anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("receipt"), merkleRoot.toBuffer(), claimant.toBuffer()],
+      program.programId
+    )[0],
+    gateTokenAccount: null,
+    config,
+    // No claim fee is charged while the program config is uninitialized
+    feeRecipient: claimant,
+  });
+
   it("Create airdrop!", async () => {
     const airdropSignature = await connection.requestAirdrop(authority.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
     await connection.confirmTransaction(airdropSignature);
     ({ mint, creatorTokenAccount } = await createMintAndFundCreator(connection, authority));
 
     try {
-      const tx = await program.methods.createAirdrop(MERKLE_ROOT, new anchor.BN(10_000 * (10 ** 6))).accounts({
+      const tx = await program.methods.createAirdrop(MERKLE_ROOT, new anchor.BN(10_000 * (10 ** 6)), {
+        expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) + 24 * 60 * 60),
+        claimStart: null,
+        claimEnd: null,
+        vesting: null,
+        cumulative: false,
+        hashVersion: HASH_VERSION_V1,
+        leafEncoding: LEAF_ENCODING_BASE58,
+        voucherSigner: null,
+        bitmapLeafCount: null,
+        nonce: NONCE,
+        multiMint: false,
+        numLeaves: 4,
+        gate: null,
+      }).accountsPartial({
         authority: authority.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
-        mint
+        mint,
+        merkleRoot,
+        // No deposit fee is charged while the program config is uninitialized
+        feeRecipientTokenAccount: creatorTokenAccount,
       }).signers([authority]).rpc();
       console.log("Your transaction signature", tx);
     }
@@ -39,15 +84,9 @@ describe("airdrop-contract", () => {
     const airdropSignature = await connection.requestAirdrop(kp2.publicKey, anchor.web3.LAMPORTS_PER_SOL);
     await connection.confirmTransaction(airdropSignature);
 
-    const merkleRoot = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("merkle_root"), Buffer.from(MERKLE_ROOT)], program.programId)[0];
     console.log("Signer:", kp2.publicKey.toString());
 
-    const tx = await program.methods.claim(PROOF1, new anchor.BN(20), 5).accounts({
-      authority: kp2.publicKey,
-      tokenProgram: TOKEN_PROGRAM_ID,
-      mint,
-      merkleRoot,
-    }).signers([kp2]).rpc();
+    const tx = await program.methods.claim(PROOF1, new anchor.BN(20), 5).accountsPartial(claimAccounts(kp2.publicKey)).signers([kp2]).rpc();
     console.log("Your transaction signature", tx);
 
     const kp2TokenAccount = await getAssociatedTokenAddress(mint, kp2.publicKey, undefined, TOKEN_PROGRAM_ID, undefined);
@@ -59,16 +98,10 @@ describe("airdrop-contract", () => {
     const airdropSignature = await connection.requestAirdrop(kp2.publicKey, anchor.web3.LAMPORTS_PER_SOL);
     await connection.confirmTransaction(airdropSignature);
 
-    const merkleRoot = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("merkle_root"), Buffer.from(MERKLE_ROOT)], program.programId)[0];
     console.log("Signer:", kp2.publicKey.toString());
 
     try {
-      await program.methods.claim(PROOF1, new anchor.BN(20), 5).accounts({
-        authority: kp2.publicKey,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        mint,
-        merkleRoot,
-      }).signers([kp2]).rpc();
+      await program.methods.claim(PROOF1, new anchor.BN(20), 5).accountsPartial(claimAccounts(kp2.publicKey)).signers([kp2]).rpc();
     }
     catch (e) {
       console.log(e.message);
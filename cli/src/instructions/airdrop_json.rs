@@ -0,0 +1,366 @@
+use airdrop_contract::constants::{
+    CLOSED_AIRDROP_SEED, HASH_VERSION_V1, LEAF_ENCODING_BASE58, MERKLE_ROOT_SEED,
+    VAULT_RECORD_SEED,
+};
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs::File, path::Path, str::FromStr};
+
+#[derive(Deserialize, Serialize)]
+pub struct ClaimInfo {
+    pub amount: String,
+    pub leaf_index: u64,
+}
+
+/// The airdrop.json file written by `create-airdrop` and updated by `deploy-airdrop`.
+#[derive(Deserialize, Serialize)]
+pub struct AirdropJson {
+    pub merkle_root: String,
+    pub merkle_tree: Vec<String>,
+    pub claims: BTreeMap<String, ClaimInfo>,
+    /// Set for multi-mint airdrops: each recipient's claims keyed by mint. `claims` is then empty.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub mint_claims: BTreeMap<String, BTreeMap<String, ClaimInfo>>,
+    /// Mint funded at deployment; for a multi-mint airdrop, the first of its mints
+    #[serde(default)]
+    pub mint: Option<String>,
+    /// Set once deployed with `--sol`: claims pay lamports and there is no mint
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub sol: bool,
+    /// Set once deployed with `--mint-on-claim`: claims mint, so there is no vault to fund
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub mint_on_claim: bool,
+    /// Leaf and node hashing scheme; files written before versioning used v1
+    #[serde(default = "default_hash_version")]
+    pub hash_version: u8,
+    /// How leaves encode addresses; files written before the field existed hashed base58 text
+    #[serde(default = "default_leaf_encoding")]
+    pub leaf_encoding: u8,
+    /// Root of the first epoch of a cumulative airdrop, which the on-chain PDA is derived from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_merkle_root: Option<String>,
+    /// Deployer the on-chain PDA is namespaced under; absent until the airdrop is deployed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    /// Nonce in the PDA seeds alongside `creator`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+    /// Set for voucher airdrops: the ed25519 key that signs vouchers. `merkle_root` is then only an id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voucher_signer: Option<String>,
+    /// Amount deposited for a voucher airdrop, which has no claims to sum
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voucher_amount: Option<String>,
+}
+
+fn default_hash_version() -> u8 {
+    HASH_VERSION_V1
+}
+
+fn default_leaf_encoding() -> u8 {
+    LEAF_ENCODING_BASE58
+}
+
+impl AirdropJson {
+    /// Read and parse an airdrop.json file
+    pub fn read(path: &Path) -> Result<Self> {
+        let file = File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
+        serde_json::from_reader(file).with_context(|| {
+            format!(
+                "Failed to parse airdrop JSON from {:?}. Make sure you're using an airdrop.json file.",
+                path
+            )
+        })
+    }
+
+    /// Overwrite the airdrop.json file at `path`
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to open {:?} for writing", path))?;
+        serde_json::to_writer_pretty(file, self)
+            .with_context(|| "Failed to write updated airdrop JSON")
+    }
+
+    /// Decode the hex merkle root
+    pub fn merkle_root_hash(&self) -> Result<[u8; 32]> {
+        hex::decode(&self.merkle_root)
+            .with_context(|| "Invalid hex in merkle_root")?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("merkle_root must be 32 bytes"))
+    }
+
+    /// Whether the airdrop was deployed by the program's first release, which recorded the
+    /// mint but no creator, so its `LegacyMerkleRoot` lives at the root-only seeds
+    pub fn is_legacy(&self) -> bool {
+        self.creator.is_none() && self.mint.is_some()
+    }
+
+    /// Address of the on-chain MerkleRoot account for this airdrop, which only exists once
+    /// deploying has recorded its `creator`, or its `mint` for a legacy airdrop
+    pub fn merkle_root_pda(&self, program_id: &Pubkey) -> Result<Pubkey> {
+        let seed_root = self.initial_merkle_root.as_ref().unwrap_or(&self.merkle_root);
+        let seed_hash: [u8; 32] = hex::decode(seed_root)
+            .with_context(|| "Invalid hex in initial_merkle_root")?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("initial_merkle_root must be 32 bytes"))?;
+        if self.is_legacy() {
+            return Ok(legacy_merkle_root_pda(program_id, &seed_hash));
+        }
+        let creator = self
+            .creator
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Airdrop JSON has no creator; deploy the airdrop first"))?;
+        let creator = Pubkey::from_str(creator)
+            .map_err(|e| anyhow::anyhow!("Invalid creator in JSON: {}", e))?;
+        Ok(merkle_root_pda(program_id, &creator, self.nonce.unwrap_or(0), &seed_hash))
+    }
+
+    /// Sum of all claim amounts, or the deposit of a voucher airdrop
+    pub fn total_amount(&self) -> Result<u64> {
+        if let Some(amount) = &self.voucher_amount {
+            return amount
+                .parse()
+                .with_context(|| format!("invalid voucher_amount '{}'", amount));
+        }
+        self.claims.values().try_fold(0u64, |total, claim| {
+            let amount: u64 = claim
+                .amount
+                .parse()
+                .with_context(|| format!("invalid amount '{}'", claim.amount))?;
+            total
+                .checked_add(amount)
+                .ok_or_else(|| anyhow::anyhow!("Total airdrop amount overflows u64"))
+        })
+    }
+
+    /// Whether leaves commit to a mint, so that one root pays out several tokens
+    pub fn is_multi_mint(&self) -> bool {
+        !self.mint_claims.is_empty()
+    }
+
+    /// Every leaf as `(address, mint, claim)`; the mint is `None` outside multi-mint airdrops
+    pub fn allocations(&self) -> Vec<(&str, Option<&str>, &ClaimInfo)> {
+        let single = self
+            .claims
+            .iter()
+            .map(|(address, claim)| (address.as_str(), None, claim));
+        let multi = self.mint_claims.iter().flat_map(|(address, mints)| {
+            mints
+                .iter()
+                .map(move |(mint, claim)| (address.as_str(), Some(mint.as_str()), claim))
+        });
+        single.chain(multi).collect()
+    }
+
+    /// Total allocated per mint of a multi-mint airdrop, in mint order
+    pub fn mint_totals(&self) -> Result<BTreeMap<Pubkey, u64>> {
+        let mut totals = BTreeMap::new();
+        for (_, mint, claim) in self.allocations() {
+            let Some(mint) = mint else { continue };
+            let mint = Pubkey::from_str(mint)
+                .map_err(|e| anyhow::anyhow!("Invalid mint '{}' in JSON: {}", mint, e))?;
+            let amount: u64 = claim
+                .amount
+                .parse()
+                .with_context(|| format!("invalid amount '{}'", claim.amount))?;
+            let total: &mut u64 = totals.entry(mint).or_default();
+            *total = total
+                .checked_add(amount)
+                .ok_or_else(|| anyhow::anyhow!("Total amount of {} overflows u64", mint))?;
+        }
+        Ok(totals)
+    }
+
+    /// What the vault pays out for `mint`, one amount per leaf; a voucher deposit counts as one.
+    /// `mint` is only consulted for multi-mint airdrops.
+    pub fn payout_amounts(&self, mint: &Pubkey) -> Result<Vec<u64>> {
+        if self.voucher_amount.is_some() {
+            return Ok(vec![self.total_amount()?]);
+        }
+        let mint = mint.to_string();
+        self.allocations()
+            .into_iter()
+            .filter(|(_, leaf_mint, _)| leaf_mint.is_none() || *leaf_mint == Some(mint.as_str()))
+            .map(|(_, _, claim)| {
+                claim
+                    .amount
+                    .parse()
+                    .with_context(|| format!("invalid amount '{}'", claim.amount))
+            })
+            .collect()
+    }
+
+    /// Number of leaves the tree was built from, excluding padding
+    pub fn leaf_count(&self) -> Result<u32> {
+        let leaf_offset = (self.merkle_tree.len() / 2) as u64;
+        let last_leaf = self
+            .allocations()
+            .iter()
+            .map(|(_, _, claim)| claim.leaf_index)
+            .max()
+            .ok_or_else(|| anyhow::anyhow!("Airdrop has no claims"))?;
+        u32::try_from(last_leaf + 1 - leaf_offset).context("Too many leaves")
+    }
+
+    /// Voucher signer of a voucher airdrop, or `None` for a merkle airdrop
+    pub fn voucher_signer(&self) -> Result<Option<Pubkey>> {
+        self.voucher_signer
+            .as_deref()
+            .map(|signer| {
+                Pubkey::from_str(signer)
+                    .map_err(|e| anyhow::anyhow!("Invalid voucher_signer in JSON: {}", e))
+            })
+            .transpose()
+    }
+
+    /// Every mint the airdrop pays out: the leaves' mints, or the deployed mint (none for SOL)
+    pub fn mints(&self) -> Result<Vec<Pubkey>> {
+        if self.sol {
+            return Ok(Vec::new());
+        }
+        if self.is_multi_mint() {
+            return Ok(self.mint_totals()?.into_keys().collect());
+        }
+        Ok(vec![self.resolve_mint(None)?])
+    }
+
+    /// Resolve the airdrop mint, preferring an explicit override over the mint stored in the JSON
+    pub fn resolve_mint(&self, mint_override: Option<&str>) -> Result<Pubkey> {
+        match mint_override {
+            Some(mint_str) => Ok(Pubkey::from_str(mint_str)?),
+            None => {
+                let mint_str = self.mint.as_ref().ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "No mint address found. Either provide --mint or run deploy-airdrop first to populate the mint in the JSON."
+                    )
+                })?;
+                Pubkey::from_str(mint_str)
+                    .map_err(|e| anyhow::anyhow!("Invalid mint address in JSON: {}", e))
+            }
+        }
+    }
+
+    /// Record the program keeps of an open vault for `mint`, which only a multi-mint airdrop's
+    /// mints other than the one funded at deployment have
+    pub fn vault_record_pda(&self, program_id: &Pubkey, mint: &Pubkey) -> Result<Option<Pubkey>> {
+        if !self.is_multi_mint() || self.resolve_mint(None)? == *mint {
+            return Ok(None);
+        }
+        let merkle_root = self.merkle_root_pda(program_id)?;
+        let (pda, _) = Pubkey::find_program_address(
+            &[VAULT_RECORD_SEED, merkle_root.as_ref(), mint.as_ref()],
+            program_id,
+        );
+        Ok(Some(pda))
+    }
+}
+
+/// Address of the MerkleRoot account `creator` deploys for `seed_hash` under `nonce`
+pub fn merkle_root_pda(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    nonce: u64,
+    seed_hash: &[u8; 32],
+) -> Pubkey {
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[MERKLE_ROOT_SEED, creator.as_ref(), &nonce.to_le_bytes(), seed_hash],
+        program_id,
+    );
+    pda
+}
+
+/// Address of the `LegacyMerkleRoot` the program's first release deployed for `hash`
+pub fn legacy_merkle_root_pda(program_id: &Pubkey, hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[MERKLE_ROOT_SEED, hash], program_id).0
+}
+
+/// Tombstone the program leaves when the airdrop at `merkle_root` is closed
+pub fn closed_airdrop_pda(program_id: &Pubkey, merkle_root: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CLOSED_AIRDROP_SEED, merkle_root.as_ref()], program_id).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn airdrop_json(creator: Option<&Pubkey>, nonce: Option<u64>) -> AirdropJson {
+        AirdropJson {
+            merkle_root: hex::encode([7u8; 32]),
+            merkle_tree: Vec::new(),
+            claims: BTreeMap::new(),
+            mint_claims: BTreeMap::new(),
+            mint: None,
+            sol: false,
+            mint_on_claim: false,
+            hash_version: HASH_VERSION_V1,
+            leaf_encoding: LEAF_ENCODING_BASE58,
+            initial_merkle_root: None,
+            creator: creator.map(|c| c.to_string()),
+            nonce,
+            voucher_signer: None,
+            voucher_amount: None,
+        }
+    }
+
+    #[test]
+    fn test_multi_mint_totals_and_allocations() {
+        let (usdc, bonk) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let claim = |amount: u64, leaf_index: u64| ClaimInfo {
+            amount: amount.to_string(),
+            leaf_index,
+        };
+        let mut airdrop = airdrop_json(None, None);
+        airdrop.merkle_tree = vec![String::new(); 8];
+        airdrop.mint_claims.insert(
+            "alice".to_string(),
+            BTreeMap::from([(usdc.to_string(), claim(10, 4)), (bonk.to_string(), claim(5, 5))]),
+        );
+        airdrop
+            .mint_claims
+            .insert("bob".to_string(), BTreeMap::from([(usdc.to_string(), claim(7, 6))]));
+
+        assert!(airdrop.is_multi_mint());
+        assert_eq!(airdrop.allocations().len(), 3);
+        assert_eq!(airdrop.leaf_count().unwrap(), 3);
+        assert_eq!(airdrop.mint_totals().unwrap(), BTreeMap::from([(usdc, 17), (bonk, 5)]));
+        // No deployed mint is needed to list a multi-mint airdrop's mints
+        assert_eq!(airdrop.mints().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_undeployed_files_have_no_pda() {
+        let program_id = Pubkey::new_unique();
+        assert!(airdrop_json(None, None).merkle_root_pda(&program_id).is_err());
+    }
+
+    #[test]
+    fn test_legacy_files_use_root_only_seeds() {
+        let program_id = Pubkey::new_unique();
+        let mut airdrop = airdrop_json(None, None);
+        airdrop.mint = Some(Pubkey::new_unique().to_string());
+
+        assert!(airdrop.is_legacy());
+        assert_eq!(
+            airdrop.merkle_root_pda(&program_id).unwrap(),
+            legacy_merkle_root_pda(&program_id, &[7u8; 32])
+        );
+        // Current deployments record their creator alongside the mint
+        airdrop.creator = Some(Pubkey::new_unique().to_string());
+        assert!(!airdrop.is_legacy());
+    }
+
+    #[test]
+    fn test_pda_is_namespaced_by_creator_and_nonce() {
+        let program_id = Pubkey::new_unique();
+        let alice = Pubkey::new_unique();
+        let mallory = Pubkey::new_unique();
+
+        let ours = airdrop_json(Some(&alice), Some(0)).merkle_root_pda(&program_id).unwrap();
+        assert_eq!(ours, merkle_root_pda(&program_id, &alice, 0, &[7u8; 32]));
+        assert_ne!(ours, airdrop_json(Some(&mallory), Some(0)).merkle_root_pda(&program_id).unwrap());
+        assert_ne!(ours, airdrop_json(Some(&alice), Some(1)).merkle_root_pda(&program_id).unwrap());
+        // A missing nonce means the default of zero
+        assert_eq!(ours, airdrop_json(Some(&alice), None).merkle_root_pda(&program_id).unwrap());
+    }
+}
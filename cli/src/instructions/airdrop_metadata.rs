@@ -0,0 +1,379 @@
+use airdrop_contract::accounts::{CloseMetadata, UpdateMetadata};
+use airdrop_contract::constants::{MAX_METADATA_NAME_LEN, MAX_METADATA_URI_LEN, METADATA_SEED};
+use airdrop_contract::state::{AirdropMetadata, MerkleRoot, MetadataParams};
+use anchor_client::solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair, Signature};
+use anchor_client::{Client, Program};
+use anchor_lang::AccountDeserialize;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use solana_client::rpc_client::RpcClient;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    rc::Rc,
+    str::FromStr,
+};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::deploy_airdrop::Network;
+
+/// Maximum number of accounts `getMultipleAccounts` accepts per request
+const RPC_ACCOUNTS_CHUNK: usize = 100;
+/// Offset of `MerkleRoot::authority`: discriminator, hash, seed_hash, bump, mint
+const MERKLE_ROOT_AUTHORITY_OFFSET: usize = 8 + 32 + 32 + 1 + 32;
+
+/// Metadata fields from a TOML file or command-line flags; unset fields are left as they are
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct MetadataFields {
+    pub name: Option<String>,
+    pub description_uri: Option<String>,
+    pub image_uri: Option<String>,
+    pub website: Option<String>,
+}
+
+impl MetadataFields {
+    /// Read fields from a TOML file with `name`, `description_uri`, `image_uri` and `website` keys
+    pub fn read(path: &Path) -> Result<Self> {
+        let contents =
+            std::fs::read_to_string(path).with_context(|| format!("Failed to open {:?}", path))?;
+        Self::parse(&contents).with_context(|| format!("Failed to parse metadata from {:?}", path))
+    }
+
+    fn parse(contents: &str) -> Result<Self> {
+        Ok(toml::from_str(contents)?)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// These fields, with any that are set in `overrides` replaced
+    pub fn overridden_by(self, overrides: MetadataFields) -> Self {
+        Self {
+            name: overrides.name.or(self.name),
+            description_uri: overrides.description_uri.or(self.description_uri),
+            image_uri: overrides.image_uri.or(self.image_uri),
+            website: overrides.website.or(self.website),
+        }
+    }
+
+    /// Write the set fields over `params`, checking the result fits on-chain
+    pub fn apply(self, mut params: MetadataParams) -> Result<MetadataParams> {
+        if let Some(name) = self.name {
+            params.name = name;
+        }
+        if let Some(description_uri) = self.description_uri {
+            params.description_uri = description_uri;
+        }
+        if let Some(image_uri) = self.image_uri {
+            params.image_uri = image_uri;
+        }
+        if let Some(website) = self.website {
+            params.website = website;
+        }
+        if !params.is_valid() {
+            anyhow::bail!(
+                "Metadata name is limited to {} bytes and URIs to {} bytes",
+                MAX_METADATA_NAME_LEN,
+                MAX_METADATA_URI_LEN
+            );
+        }
+        Ok(params)
+    }
+}
+
+/// Metadata to publish at deploy time from `--metadata` and the individual flags, which take
+/// precedence over the file; `None` when neither was given
+pub fn metadata_from_flags(
+    file: Option<&Path>,
+    flags: MetadataFields,
+) -> Result<Option<MetadataParams>> {
+    let fields = match file {
+        Some(path) => MetadataFields::read(path)?.overridden_by(flags),
+        None => flags,
+    };
+    if fields.is_empty() {
+        return Ok(None);
+    }
+    fields.apply(MetadataParams::default()).map(Some)
+}
+
+#[derive(Debug)]
+pub struct UpdateMetadataArgs {
+    pub json_path: PathBuf,
+    pub metadata_path: Option<PathBuf>,
+    pub fields: MetadataFields,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+#[derive(Debug)]
+pub struct ListAirdropsArgs {
+    /// Only list airdrops created by this authority
+    pub authority: Option<String>,
+    pub network: Network,
+    pub program_id: String,
+}
+
+pub fn metadata_pda(program_id: &Pubkey, merkle_root: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[METADATA_SEED, merkle_root.as_ref()], program_id).0
+}
+
+/// The airdrop's metadata, or `None` if it never had any
+pub fn fetch_metadata(
+    rpc_client: &RpcClient,
+    program_id: &Pubkey,
+    merkle_root: &Pubkey,
+) -> Result<Option<AirdropMetadata>> {
+    let metadata = metadata_pda(program_id, merkle_root);
+    let account = rpc_client
+        .get_account_with_commitment(&metadata, CommitmentConfig::confirmed())?
+        .value;
+    match account {
+        Some(account) => Ok(Some(AirdropMetadata::try_deserialize(&mut account.data.as_slice())?)),
+        None => Ok(None),
+    }
+}
+
+/// Create or overwrite the airdrop's metadata; the program's payer must be its authority
+pub fn publish_metadata(
+    program: &Program<Rc<Keypair>>,
+    merkle_root: Pubkey,
+    params: &MetadataParams,
+) -> Result<Signature> {
+    let signature = program
+        .request()
+        .accounts(UpdateMetadata {
+            authority: program.payer(),
+            merkle_root,
+            metadata: metadata_pda(&program.id(), &merkle_root),
+            system_program: anchor_client::solana_sdk::system_program::ID,
+        })
+        .args(airdrop_contract::instruction::UpdateMetadata {
+            params: params.clone(),
+        })
+        .send()?;
+    Ok(signature)
+}
+
+/// Close the airdrop's metadata, returning its rent to the authority. Must run before the
+/// merkle root itself is closed.
+pub fn close_metadata(program: &Program<Rc<Keypair>>, merkle_root: Pubkey) -> Result<Signature> {
+    let signature = program
+        .request()
+        .accounts(CloseMetadata {
+            authority: program.payer(),
+            merkle_root,
+            metadata: metadata_pda(&program.id(), &merkle_root),
+        })
+        .args(airdrop_contract::instruction::CloseMetadata {})
+        .send()?;
+    Ok(signature)
+}
+
+fn print_metadata(metadata: &AirdropMetadata) {
+    println!("Creator: {}", metadata.creator);
+    for (label, value) in [
+        ("Description", &metadata.description_uri),
+        ("Image", &metadata.image_uri),
+        ("Website", &metadata.website),
+    ] {
+        if !value.is_empty() {
+            println!("{}: {}", label, value);
+        }
+    }
+}
+
+/// Set or change an airdrop's metadata (authority only). Fields that are not given keep their
+/// current on-chain value.
+pub fn update_metadata(args: UpdateMetadataArgs) -> Result<()> {
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let fields = match &args.metadata_path {
+        Some(path) => MetadataFields::read(path)?.overridden_by(args.fields),
+        None => args.fields,
+    };
+    if fields.is_empty() {
+        anyhow::bail!("Nothing to update: pass --metadata or a metadata flag");
+    }
+
+    // Load keypair
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+    let merkle_root: MerkleRoot = program.account(merkle_root_pda)?;
+    if merkle_root.authority != program.payer() {
+        anyhow::bail!(
+            "Keypair {} is not the airdrop authority ({})",
+            program.payer(),
+            merkle_root.authority
+        );
+    }
+    let current = fetch_metadata(&rpc_client, &program_id, &merkle_root_pda)?;
+    let params = fields.apply(match &current {
+        Some(metadata) => MetadataParams {
+            name: metadata.name.clone(),
+            description_uri: metadata.description_uri.clone(),
+            image_uri: metadata.image_uri.clone(),
+            website: metadata.website.clone(),
+        },
+        None => MetadataParams::default(),
+    })?;
+
+    println!("Merkle root: {}", airdrop_data.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Merkle root PDA: {}", merkle_root_pda);
+    println!("Metadata PDA: {}", metadata_pda(&program_id, &merkle_root_pda));
+    println!("Name: {}", params.name);
+
+    println!("\nSending update metadata transaction...");
+    let signature = publish_metadata(&program, merkle_root_pda, &params)?;
+
+    println!("\nMetadata updated successfully!");
+    println!("Signature: {}", signature);
+
+    Ok(())
+}
+
+/// List the program's airdrops with their metadata. No keypair is needed.
+pub fn list_airdrops(args: ListAirdropsArgs) -> Result<()> {
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let authority = args.authority.as_deref().map(Pubkey::from_str).transpose()?;
+
+    // Only used for its read-only account queries, so any keypair will do
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::new()),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+    let filters = authority
+        .map(|authority| {
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                MERKLE_ROOT_AUTHORITY_OFFSET,
+                authority.as_ref(),
+            ))
+        })
+        .into_iter()
+        .collect();
+    let mut airdrops = program.accounts::<MerkleRoot>(filters)?;
+
+    // Look up every airdrop's metadata PDA in batches
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let pdas: Vec<Pubkey> = airdrops
+        .iter()
+        .map(|(merkle_root, _)| metadata_pda(&program_id, merkle_root))
+        .collect();
+    let mut metadata = HashMap::new();
+    for chunk in pdas.chunks(RPC_ACCOUNTS_CHUNK) {
+        for account in rpc_client.get_multiple_accounts(chunk)?.into_iter().flatten() {
+            let entry = AirdropMetadata::try_deserialize(&mut account.data.as_slice())?;
+            metadata.insert(entry.merkle_root, entry);
+        }
+    }
+
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    if let Some(authority) = authority {
+        println!("Authority: {}", authority);
+    }
+    println!("Airdrops: {}", airdrops.len());
+
+    // Named airdrops first, alphabetically
+    airdrops.sort_by_cached_key(|(merkle_root_pda, _)| {
+        let name = metadata
+            .get(merkle_root_pda)
+            .map(|entry| entry.name.clone())
+            .filter(|name| !name.is_empty());
+        (name.is_none(), name, *merkle_root_pda)
+    });
+    for (merkle_root_pda, merkle_root) in &airdrops {
+        let entry = metadata.get(merkle_root_pda);
+        match entry {
+            Some(entry) if !entry.name.is_empty() => println!("\n{}", entry.name),
+            _ => println!("\n(unnamed)"),
+        }
+        println!("Merkle root PDA: {}", merkle_root_pda);
+        println!("Root: {}", hex::encode(merkle_root.hash));
+        if merkle_root.native_sol {
+            println!("Mint: SOL");
+        } else {
+            println!("Mint: {}", merkle_root.mint);
+        }
+        println!("Authority: {}", merkle_root.authority);
+        println!("Claimed: {} / {}", merkle_root.claimed_amount, merkle_root.total_amount);
+        println!("Expires at: {}", merkle_root.expires_at);
+        if let Some(entry) = entry {
+            print_metadata(entry);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_metadata_file() {
+        let fields = MetadataFields::parse(
+            "name = \"Community drop\"\n\
+            image_uri = \"https://example.com/logo.png\"\n",
+        )
+        .unwrap();
+        assert_eq!(fields.name.as_deref(), Some("Community drop"));
+        assert_eq!(fields.image_uri.as_deref(), Some("https://example.com/logo.png"));
+        assert_eq!(fields.description_uri, None);
+        assert!(MetadataFields::parse("title = \"typo\"\n").is_err());
+    }
+
+    #[test]
+    fn test_flags_override_file() {
+        let file = MetadataFields {
+            name: Some("From file".to_string()),
+            website: Some("https://example.com".to_string()),
+            ..Default::default()
+        };
+        let flags = MetadataFields {
+            name: Some("From flag".to_string()),
+            ..Default::default()
+        };
+        let params = file.overridden_by(flags).apply(MetadataParams::default()).unwrap();
+        assert_eq!(params.name, "From flag");
+        assert_eq!(params.website, "https://example.com");
+        assert_eq!(params.image_uri, "");
+    }
+
+    #[test]
+    fn test_metadata_length_limits() {
+        let fields = MetadataFields {
+            name: Some("x".repeat(MAX_METADATA_NAME_LEN + 1)),
+            ..Default::default()
+        };
+        assert!(fields.apply(MetadataParams::default()).is_err());
+        assert_eq!(metadata_from_flags(None, MetadataFields::default()).unwrap(), None);
+    }
+}
@@ -0,0 +1,103 @@
+use airdrop_contract::state::MerkleRoot;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_lang::AccountDeserialize;
+use anyhow::{Context, Result};
+use solana_client::rpc_client::RpcClient;
+use std::{path::PathBuf, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::deploy_airdrop::Network;
+use crate::instructions::token_mint::TokenMint;
+
+#[derive(Debug)]
+pub struct AirdropStatusArgs {
+    pub json_path: PathBuf,
+    pub network: Network,
+    pub program_id: String,
+}
+
+/// `part` as a percentage of `whole`, to two decimals
+fn percentage(part: u64, whole: u64) -> String {
+    if whole == 0 {
+        return "-".to_string();
+    }
+    format!("{:.2}%", part as f64 * 100.0 / whole as f64)
+}
+
+pub fn airdrop_status(args: AirdropStatusArgs) -> Result<()> {
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let account = rpc_client
+        .get_account(&merkle_root_pda)
+        .with_context(|| format!("Airdrop {} not found on-chain", merkle_root_pda))?;
+    let merkle_root = MerkleRoot::try_deserialize(&mut account.data.as_slice())?;
+
+    println!("Merkle root: {}", airdrop_data.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Merkle root PDA: {}", merkle_root_pda);
+    println!("Authority: {}", merkle_root.authority);
+    if merkle_root.cumulative {
+        println!("Epoch: {}", merkle_root.epoch);
+    }
+    println!("Expires at: {}", merkle_root.expires_at);
+    println!("Paused: {}", merkle_root.paused);
+    if let Some(gate) = &merkle_root.gate {
+        println!("Gated: at least {} of {}", gate.min_balance, gate.mint);
+    }
+
+    println!("\nLeaves: {}", merkle_root.num_leaves);
+    println!("Claims: {}", merkle_root.num_claims);
+    // Multi-mint airdrops keep no totals across mints; the vault balances below are per mint
+    if !merkle_root.multi_mint {
+        let remaining = merkle_root.total_amount.saturating_sub(merkle_root.claimed_amount);
+        // For mint-on-claim airdrops this is the cap on what claims may mint
+        println!("Funded: {}", merkle_root.total_amount);
+        println!(
+            "Claimed: {} ({})",
+            merkle_root.claimed_amount,
+            percentage(merkle_root.claimed_amount, merkle_root.total_amount)
+        );
+        println!("Remaining: {}", remaining);
+    }
+
+    // The vault may hold less than `Remaining` if tokens were clawed back, or more if
+    // someone sent tokens to it directly
+    if merkle_root.mint_on_claim {
+        println!("\nMint on claim: {} (no vault)", merkle_root.mint);
+        return Ok(());
+    }
+    if merkle_root.native_sol {
+        let rent = rpc_client.get_minimum_balance_for_rent_exemption(account.data.len())?;
+        println!("\nVault balance (lamports): {}", account.lamports.saturating_sub(rent));
+    }
+    for mint in airdrop_data.mints()? {
+        let token_mint = TokenMint::fetch(&rpc_client, &mint)?;
+        let vault = token_mint.associated_token_address(&merkle_root_pda);
+        match rpc_client.get_token_account_balance(&vault) {
+            Ok(balance) => println!("\nVault balance ({}): {}", mint, balance.amount),
+            Err(_) => println!("\nVault balance ({}): closed", mint),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentage() {
+        assert_eq!(percentage(1, 8), "12.50%");
+        assert_eq!(percentage(3, 3), "100.00%");
+        assert_eq!(percentage(0, 0), "-");
+    }
+}
@@ -1,32 +1,20 @@
-use airdrop_contract::accounts::Claim;
+use airdrop_contract::accounts::{Claim, ClaimLegacy, ClaimMint, ClaimSol, ClaimWithBitmap};
+use airdrop_contract::constants::{CLAIM_BITMAP_BITS, CLAIM_RECEIPT_SEED};
+use airdrop_contract::state::{ClaimBitmap, ClaimReceipt, MerkleRoot};
+use anchor_lang::AccountDeserialize;
 use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
 use anchor_client::solana_sdk::pubkey::Pubkey;
 use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair, Signer};
-use anchor_client::Client;
-use anchor_spl::associated_token::get_associated_token_address;
-use anchor_spl::token::spl_token;
+use anchor_client::{Client, Program};
 use anyhow::{Context, Result};
-use serde::Deserialize;
-use std::{fs::File, path::PathBuf, rc::Rc, str::FromStr};
+use solana_client::rpc_client::RpcClient;
+use std::{path::PathBuf, rc::Rc, str::FromStr};
 
-use crate::instructions::deploy_airdrop::Network;
-
-const CLAIM_RECEIPT_SEED: &[u8] = b"receipt";
-
-#[derive(Deserialize)]
-struct ClaimInfo {
-    amount: String,
-    leaf_index: u64,
-}
-
-#[derive(Deserialize)]
-struct AirdropJson {
-    merkle_root: String,
-    merkle_tree: Vec<String>,
-    claims: std::collections::BTreeMap<String, ClaimInfo>,
-    #[serde(default)]
-    mint: Option<String>,
-}
+use crate::instructions::airdrop_json::{AirdropJson, ClaimInfo};
+use crate::instructions::claim_status::{bitmap_position, claim_bitmap_pda};
+use crate::instructions::deploy_airdrop::{unix_timestamp_now, Network};
+use crate::instructions::program_config::claim_fee_accounts;
+use crate::instructions::token_mint::{current_epoch, TokenMint};
 
 #[derive(Debug)]
 pub struct ClaimAirdropArgs {
@@ -36,10 +24,12 @@ pub struct ClaimAirdropArgs {
     pub network: Network,
     pub program_id: String,
     pub keypair_path: PathBuf,
+    /// Sponsor that pays the fees and rent instead of the claimant
+    pub fee_payer_path: Option<PathBuf>,
 }
 
 /// Generate a merkle proof for the given leaf index
-fn generate_proof(merkle_tree: &[String], leaf_index: u64) -> Result<Vec<[u8; 32]>> {
+pub(crate) fn generate_proof(merkle_tree: &[String], leaf_index: u64) -> Result<Vec<[u8; 32]>> {
     let mut proof: Vec<[u8; 32]> = Vec::new();
     let mut index = leaf_index as usize;
 
@@ -59,37 +49,74 @@ fn generate_proof(merkle_tree: &[String], leaf_index: u64) -> Result<Vec<[u8; 32
     Ok(proof)
 }
 
+/// Amount already paid out against a claim receipt, or zero if it does not exist yet
+pub(crate) fn fetch_claimed_so_far(rpc_client: &RpcClient, claim_receipt: &Pubkey) -> Result<u64> {
+    let claimed_so_far = match rpc_client
+        .get_account_with_commitment(claim_receipt, CommitmentConfig::confirmed())?
+        .value
+    {
+        Some(account) => ClaimReceipt::try_deserialize(&mut account.data.as_slice())?.claimed_so_far,
+        None => 0,
+    };
+    Ok(claimed_so_far)
+}
+
+/// The token account `holder` must pass to claim from a gated airdrop, after checking it holds
+/// enough of the gate mint; `None` when the airdrop is not gated
+pub(crate) fn gate_token_account(
+    rpc_client: &RpcClient,
+    merkle_root: &MerkleRoot,
+    holder: &Pubkey,
+) -> Result<Option<Pubkey>> {
+    let Some(gate) = &merkle_root.gate else {
+        return Ok(None);
+    };
+    let token_mint = TokenMint::fetch(rpc_client, &gate.mint)?;
+    let account = token_mint.associated_token_address(holder);
+    // A missing account holds nothing
+    let balance: u64 = match rpc_client.get_token_account_balance(&account) {
+        Ok(balance) => balance.amount.parse()?,
+        Err(_) => 0,
+    };
+    if balance < gate.min_balance {
+        anyhow::bail!(
+            "Airdrop is gated: {} must hold at least {} of {} in {} (holds {})",
+            holder,
+            gate.min_balance,
+            gate.mint,
+            account,
+            balance
+        );
+    }
+    println!("Gate token account: {} (balance {})", account, balance);
+    Ok(Some(account))
+}
+
+/// Address of the receipt tracking `claimant`'s claims, keyed by mint in multi-mint airdrops
+pub(crate) fn claim_receipt_pda(
+    program_id: &Pubkey,
+    merkle_root: &Pubkey,
+    claimant: &Pubkey,
+    mint: Option<&Pubkey>,
+) -> Pubkey {
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[
+            CLAIM_RECEIPT_SEED,
+            merkle_root.as_ref(),
+            claimant.as_ref(),
+            ClaimReceipt::mint_seed(mint),
+        ],
+        program_id,
+    );
+    pda
+}
+
 pub fn claim_airdrop(args: ClaimAirdropArgs) -> Result<()> {
     // Read and parse the airdrop JSON
-    let file = File::open(&args.json_path)
-        .with_context(|| format!("Failed to open {:?}", args.json_path))?;
-    let airdrop_data: AirdropJson = serde_json::from_reader(file).with_context(|| {
-        format!(
-            "Failed to parse airdrop JSON from {:?}. Make sure you're using an airdrop.json file.",
-            args.json_path
-        )
-    })?;
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
 
-    let merkle_root_hash: [u8; 32] = hex::decode(&airdrop_data.merkle_root)
-        .with_context(|| "Invalid hex in merkle_root")?
-        .try_into()
-        .map_err(|_| anyhow::anyhow!("merkle_root must be 32 bytes"))?;
     let program_id = Pubkey::from_str(&args.program_id)?;
 
-    // Determine mint - either from args or from JSON
-    let mint = match &args.mint {
-        Some(mint_str) => Pubkey::from_str(mint_str)?,
-        None => {
-            let mint_str = airdrop_data.mint.as_ref().ok_or_else(|| {
-                anyhow::anyhow!(
-                    "No mint address found. Either provide --mint or run deploy-airdrop first to populate the mint in the JSON."
-                )
-            })?;
-            Pubkey::from_str(mint_str)
-                .map_err(|e| anyhow::anyhow!("Invalid mint address in JSON: {}", e))?
-        }
-    };
-
     // Load keypair
     let payer = read_keypair_file(&args.keypair_path)
         .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
@@ -103,97 +130,405 @@ pub fn claim_airdrop(args: ClaimAirdropArgs) -> Result<()> {
     println!("Merkle root: {}", airdrop_data.merkle_root);
     println!("Network: {}", args.network.name());
     println!("Program ID: {}", program_id);
-    println!("Mint: {}", mint);
     println!("Claiming address: {}", claiming_address);
 
-    // Look up the claim info for this address
-    let claim_info = airdrop_data
-        .claims
-        .get(&claiming_address.to_string())
-        .ok_or_else(|| {
-            anyhow::anyhow!(
-                "Address {} not found in airdrop. Available addresses:\n{}",
-                claiming_address,
-                airdrop_data
-                    .claims
-                    .keys()
-                    .map(|k| format!("  - {}", k))
-                    .collect::<Vec<_>>()
-                    .join("\n")
-            )
-        })?;
-
-    let amount: u64 = claim_info
-        .amount
-        .parse()
-        .with_context(|| "Failed to parse claim amount")?;
-    let leaf_index = claim_info.leaf_index;
-
-    println!("Claim amount: {}", amount);
-    println!("Leaf index: {}", leaf_index);
+    // Look up the claimant's leaves: one per mint in a multi-mint airdrop, where
+    // --mint narrows the claim down to a single token
+    let not_found = |addresses: Vec<&String>| {
+        anyhow::anyhow!(
+            "Address {} not found in airdrop. Available addresses:\n{}",
+            claiming_address,
+            addresses
+                .iter()
+                .map(|k| format!("  - {}", k))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    };
+    let leaves: Vec<(Pubkey, &ClaimInfo)> = if airdrop_data.is_multi_mint() {
+        let only_mint = args.mint.as_deref().map(Pubkey::from_str).transpose()?;
+        let mut leaves = Vec::new();
+        let allocations = airdrop_data
+            .mint_claims
+            .get(&claiming_address.to_string())
+            .ok_or_else(|| not_found(airdrop_data.mint_claims.keys().collect()))?;
+        for (mint, claim_info) in allocations {
+            let mint = Pubkey::from_str(mint)
+                .map_err(|e| anyhow::anyhow!("Invalid mint '{}' in JSON: {}", mint, e))?;
+            if only_mint.is_some_and(|only| only != mint) {
+                continue;
+            }
+            leaves.push((mint, claim_info));
+        }
+        if leaves.is_empty() {
+            anyhow::bail!("{} has no allocation of {}", claiming_address, args.mint.unwrap_or_default());
+        }
+        leaves
+    } else {
+        // Determine mint - either from args or from JSON; SOL airdrops have none
+        let mint = if airdrop_data.sol {
+            Pubkey::default()
+        } else {
+            airdrop_data.resolve_mint(args.mint.as_deref())?
+        };
+        let claim_info = airdrop_data
+            .claims
+            .get(&claiming_address.to_string())
+            .ok_or_else(|| not_found(airdrop_data.claims.keys().collect()))?;
+        vec![(mint, claim_info)]
+    };
 
-    // Generate the proof
-    let proof = generate_proof(&airdrop_data.merkle_tree, leaf_index)?;
-    println!("Proof length: {} nodes", proof.len());
+    // The fee payer signs as the client's payer; the claimant co-signs when sponsored
+    let fee_payer = match &args.fee_payer_path {
+        Some(path) => read_keypair_file(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read fee payer keypair from {:?}: {}", path, e))?,
+        None => Keypair::try_from(payer.to_bytes().as_ref())?,
+    };
+    println!("Fee payer: {}", fee_payer.pubkey());
 
     // Create Anchor client
     let client = Client::new_with_options(
         args.network.to_cluster(),
-        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        Rc::new(fee_payer),
         CommitmentConfig::confirmed(),
     );
     let program = client.program(program_id)?;
 
-    // Derive PDAs
-    let (merkle_root_pda, _bump) = Pubkey::find_program_address(
-        &[b"merkle_root", &merkle_root_hash],
-        &program_id,
-    );
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+    println!("Merkle root PDA: {}", merkle_root_pda);
 
-    let (claim_receipt_pda, _bump) = Pubkey::find_program_address(
-        &[CLAIM_RECEIPT_SEED, merkle_root_pda.as_ref(), claiming_address.as_ref()],
-        &program_id,
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
     );
 
-    let authority_token_account = get_associated_token_address(&claiming_address, &mint);
-    let merkle_root_token_account = get_associated_token_address(&merkle_root_pda, &mint);
+    // Airdrops from the program's first release have a single mint and no fee, gate or vesting
+    if airdrop_data.is_legacy() {
+        let (mint, claim_info) = leaves[0];
+        let claimant = args.fee_payer_path.is_some().then_some(&payer);
+        return claim_legacy(
+            &program,
+            &rpc_client,
+            LegacyLeaf { merkle_root_pda, claimant: claiming_address, mint, claim_info },
+            &airdrop_data.merkle_tree,
+            claimant,
+            args.network,
+        );
+    }
 
-    println!("\nMerkle root PDA: {}", merkle_root_pda);
-    println!("Claim receipt PDA: {}", claim_receipt_pda);
-    println!("Authority token account: {}", authority_token_account);
-    println!("Merkle root token account: {}", merkle_root_token_account);
+    let merkle_root: MerkleRoot = program.account(merkle_root_pda)?;
+    let (config, fee_recipient) = claim_fee_accounts(&rpc_client, &program_id, &program.payer())?;
+    let gate_token_account = gate_token_account(&rpc_client, &merkle_root, &claiming_address)?;
 
-    println!("\nSending claim transaction...");
+    let mut claimed = 0;
+    for (mint, claim_info) in &leaves {
+        let mint = *mint;
+        let amount: u64 = claim_info
+            .amount
+            .parse()
+            .with_context(|| "Failed to parse claim amount")?;
+        let leaf_index = claim_info.leaf_index;
+
+        if merkle_root.native_sol {
+            println!("\nClaim amount (lamports): {}", amount);
+        } else {
+            println!("\nMint: {}", mint);
+            println!("Claim amount: {}", amount);
+        }
+        println!("Leaf index: {}", leaf_index);
+
+        // Generate the proof
+        let proof = generate_proof(&airdrop_data.merkle_tree, leaf_index)?;
+        println!("Proof length: {} nodes", proof.len());
+
+        let mut request = program.request();
+        if args.fee_payer_path.is_some() {
+            request = request.signer(&payer);
+        }
+
+        // Bitmap airdrops pay the full allocation once and flip the leaf's bit
+        if let Some(leaf_count) = merkle_root.bitmap_leaf_count {
+            let position = bitmap_position(leaf_count, leaf_index)?;
+            let claim_bitmap =
+                claim_bitmap_pda(&program_id, &merkle_root_pda, position / CLAIM_BITMAP_BITS);
+            println!("Claim bitmap PDA: {}", claim_bitmap);
+            let (token_mint, authority_token_account, merkle_root_token_account) =
+                token_accounts(&rpc_client, &mint, &claiming_address, &merkle_root_pda)?;
 
-    // Build and send transaction
-    let signature = program
-        .request()
-        .accounts(Claim {
-            authority: claiming_address,
+            let data = rpc_client
+                .get_account_data(&claim_bitmap)
+                .with_context(|| format!("Claim bitmap {} has not been allocated", claim_bitmap))?;
+            let bitmap = ClaimBitmap::try_deserialize(&mut data.as_slice())?;
+            if bitmap.is_claimed(position % CLAIM_BITMAP_BITS) {
+                anyhow::bail!("Nothing to claim: {} has already claimed {}", claiming_address, amount);
+            }
+
+            println!("\nSending claim transaction...");
+            let signature = request
+                .accounts(ClaimWithBitmap {
+                    authority: claiming_address,
+                    payer: program.payer(),
+                    authority_token_account,
+                    merkle_root_token_account,
+                    mint,
+                    merkle_root: merkle_root_pda,
+                    claim_bitmap,
+                    config,
+                    gate_token_account,
+                    fee_recipient,
+                    system_program: anchor_client::solana_sdk::system_program::ID,
+                    token_program: token_mint.token_program,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                })
+                .accounts(token_mint.transfer_hook_accounts(
+                    &rpc_client,
+                    &merkle_root_token_account,
+                    &authority_token_account,
+                    &merkle_root_pda,
+                    token_mint.gross_up(amount, current_epoch(&rpc_client)?)?,
+                )?)
+                .args(airdrop_contract::instruction::ClaimWithBitmap {
+                    proof,
+                    amount,
+                    leaf_index: leaf_index as u32,
+                })
+                .send()?;
+            print_claimed(signature, args.network);
+            claimed += 1;
+            continue;
+        }
+
+        let claim_receipt_pda = claim_receipt_pda(
+            &program_id,
+            &merkle_root_pda,
+            &claiming_address,
+            merkle_root.receipt_mint(&mint),
+        );
+        println!("Claim receipt PDA: {}", claim_receipt_pda);
+
+        // Work out how much of the allocation is claimable right now
+        let claimed_so_far = fetch_claimed_so_far(&rpc_client, &claim_receipt_pda)?;
+        let vested = merkle_root.vested_amount(amount, unix_timestamp_now()?);
+        let claimable = vested.saturating_sub(claimed_so_far);
+        let epoch = current_epoch(&rpc_client)?;
+
+        println!("\nVested: {}", vested);
+        println!("Claimed: {}", claimed_so_far);
+        println!("Claimable: {}", claimable);
+
+        if claimable == 0 {
+            let reason = format!(
+                "Nothing to claim right now ({} of {} already claimed)",
+                claimed_so_far, amount
+            );
+            // A multi-mint claimant may still have other tokens to collect
+            if leaves.len() == 1 {
+                anyhow::bail!(reason);
+            }
+            println!("{}", reason);
+            continue;
+        }
+
+        println!("\nSending claim transaction...");
+
+        // SOL airdrops pay lamports straight out of the merkle root account
+        if merkle_root.native_sol {
+            let signature = request
+                .accounts(ClaimSol {
+                    authority: claiming_address,
+                    payer: program.payer(),
+                    merkle_root: merkle_root_pda,
+                    claim_receipt: claim_receipt_pda,
+                    config,
+                    gate_token_account,
+                    fee_recipient,
+                    system_program: anchor_client::solana_sdk::system_program::ID,
+                })
+                .args(airdrop_contract::instruction::ClaimSol {
+                    proof,
+                    amount,
+                    leaf_index: leaf_index as u32,
+                })
+                .send()?;
+            print_claimed(signature, args.network);
+            claimed += 1;
+            continue;
+        }
+
+        let (token_mint, authority_token_account, merkle_root_token_account) =
+            token_accounts(&rpc_client, &mint, &claiming_address, &merkle_root_pda)?;
+
+        // Mint-on-claim airdrops have no vault; the merkle root mints the claimable amount
+        if merkle_root.mint_on_claim {
+            let signature = request
+                .accounts(ClaimMint {
+                    authority: claiming_address,
+                    payer: program.payer(),
+                    authority_token_account,
+                    mint,
+                    merkle_root: merkle_root_pda,
+                    claim_receipt: claim_receipt_pda,
+                    config,
+                    gate_token_account,
+                    fee_recipient,
+                    system_program: anchor_client::solana_sdk::system_program::ID,
+                    token_program: token_mint.token_program,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                })
+                .args(airdrop_contract::instruction::ClaimMint {
+                    proof,
+                    amount,
+                    leaf_index: leaf_index as u32,
+                })
+                .send()?;
+            print_claimed(signature, args.network);
+            claimed += 1;
+            continue;
+        }
+
+        // Build and send transaction
+        let signature = request
+            .accounts(Claim {
+                authority: claiming_address,
+                payer: program.payer(),
+                authority_token_account,
+                merkle_root_token_account,
+                mint,
+                merkle_root: merkle_root_pda,
+                claim_receipt: claim_receipt_pda,
+                config,
+                gate_token_account,
+                fee_recipient,
+                system_program: anchor_client::solana_sdk::system_program::ID,
+                token_program: token_mint.token_program,
+                associated_token_program: anchor_spl::associated_token::ID,
+            })
+            .accounts(token_mint.transfer_hook_accounts(
+                &rpc_client,
+                &merkle_root_token_account,
+                &authority_token_account,
+                &merkle_root_pda,
+                token_mint.gross_up(claimable, epoch)?,
+            )?)
+            .args(airdrop_contract::instruction::Claim {
+                proof,
+                amount,
+                leaf_index: leaf_index as u32,
+            })
+            .send()?;
+        print_claimed(signature, args.network);
+        claimed += 1;
+    }
+
+    if claimed == 0 {
+        anyhow::bail!("Nothing to claim right now for {}", claiming_address);
+    }
+
+    Ok(())
+}
+
+/// A leaf of an airdrop deployed by the program's first release
+struct LegacyLeaf<'a> {
+    merkle_root_pda: Pubkey,
+    claimant: Pubkey,
+    mint: Pubkey,
+    claim_info: &'a ClaimInfo,
+}
+
+/// Claim a leaf from a `LegacyMerkleRoot`, which pays the whole allocation once. `claimant`
+/// co-signs when a sponsor pays.
+fn claim_legacy(
+    program: &Program<Rc<Keypair>>,
+    rpc_client: &RpcClient,
+    leaf: LegacyLeaf,
+    merkle_tree: &[String],
+    claimant: Option<&Keypair>,
+    network: Network,
+) -> Result<()> {
+    let amount: u64 = leaf
+        .claim_info
+        .amount
+        .parse()
+        .with_context(|| "Failed to parse claim amount")?;
+    let leaf_index = leaf.claim_info.leaf_index;
+    println!("\nMint: {}", leaf.mint);
+    println!("Claim amount: {}", amount);
+    println!("Leaf index: {}", leaf_index);
+
+    let claim_receipt =
+        claim_receipt_pda(&program.id(), &leaf.merkle_root_pda, &leaf.claimant, None);
+    println!("Claim receipt PDA: {}", claim_receipt);
+    let claimed = rpc_client
+        .get_account_with_commitment(&claim_receipt, CommitmentConfig::confirmed())?
+        .value
+        .is_some();
+    if claimed {
+        anyhow::bail!("Nothing to claim: {} has already claimed {}", leaf.claimant, amount);
+    }
+
+    let proof = generate_proof(merkle_tree, leaf_index)?;
+    println!("Proof length: {} nodes", proof.len());
+    let (token_mint, authority_token_account, merkle_root_token_account) =
+        token_accounts(rpc_client, &leaf.mint, &leaf.claimant, &leaf.merkle_root_pda)?;
+
+    let mut request = program.request();
+    if let Some(claimant) = claimant {
+        request = request.signer(claimant);
+    }
+
+    println!("\nSending claim transaction...");
+    let signature = request
+        .accounts(ClaimLegacy {
+            authority: leaf.claimant,
+            payer: program.payer(),
             authority_token_account,
             merkle_root_token_account,
-            mint,
-            merkle_root: merkle_root_pda,
-            claim_receipt: claim_receipt_pda,
+            mint: leaf.mint,
+            merkle_root: leaf.merkle_root_pda,
+            claim_receipt,
             system_program: anchor_client::solana_sdk::system_program::ID,
-            token_program: spl_token::ID,
+            token_program: token_mint.token_program,
             associated_token_program: anchor_spl::associated_token::ID,
         })
-        .args(airdrop_contract::instruction::Claim {
+        .accounts(token_mint.transfer_hook_accounts(
+            rpc_client,
+            &merkle_root_token_account,
+            &authority_token_account,
+            &leaf.merkle_root_pda,
+            amount,
+        )?)
+        .args(airdrop_contract::instruction::ClaimLegacy {
             proof,
             amount,
             leaf_index: leaf_index as u32,
         })
         .send()?;
+    print_claimed(signature, network);
+    Ok(())
+}
 
+/// Fetch the mint and derive the claimant's and the vault's token accounts for it
+fn token_accounts(
+    rpc_client: &RpcClient,
+    mint: &Pubkey,
+    claimant: &Pubkey,
+    merkle_root: &Pubkey,
+) -> Result<(TokenMint, Pubkey, Pubkey)> {
+    let token_mint = TokenMint::fetch(rpc_client, mint)?;
+    let authority_token_account = token_mint.associated_token_address(claimant);
+    let merkle_root_token_account = token_mint.associated_token_address(merkle_root);
+    println!("Authority token account: {}", authority_token_account);
+    println!("Merkle root token account: {}", merkle_root_token_account);
+    Ok((token_mint, authority_token_account, merkle_root_token_account))
+}
+
+fn print_claimed(signature: impl std::fmt::Display, network: Network) {
     println!("\nAirdrop claimed successfully!");
     println!("Signature: {}", signature);
     println!(
         "Explorer: https://explorer.solana.com/tx/{}?cluster={}",
         signature,
-        args.network.name()
+        network.name()
     );
-
-    Ok(())
 }
 
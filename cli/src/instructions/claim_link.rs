@@ -0,0 +1,217 @@
+use airdrop_contract::accounts::ClaimLink;
+use airdrop_contract::constants::CLAIM_RECEIPT_SEED;
+use airdrop_contract::state::MerkleRoot;
+use anchor_client::solana_sdk::bs58;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use anchor_client::Client;
+use anyhow::{Context, Result};
+use solana_client::rpc_client::RpcClient;
+use std::{path::PathBuf, rc::Rc, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::claim_airdrop::{fetch_claimed_so_far, gate_token_account, generate_proof};
+use crate::instructions::deploy_airdrop::{unix_timestamp_now, Network};
+use crate::instructions::program_config::claim_fee_accounts;
+use crate::instructions::token_mint::{current_epoch, TokenMint};
+
+#[derive(Debug)]
+pub struct ClaimLinkArgs {
+    pub json_path: PathBuf,
+    /// Claim link URL, or just the base58 secret key it carries
+    pub link: String,
+    pub destination: Option<String>,
+    pub mint: Option<String>,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+/// Build a claim link. The secret key goes in the URL fragment so it is never
+/// sent to the server hosting `base_url`.
+pub fn claim_link_url(base_url: &str, merkle_root: &str, keypair: &Keypair) -> String {
+    let separator = if base_url.contains('?') { '&' } else { '?' };
+    format!(
+        "{}{}root={}#{}",
+        base_url,
+        separator,
+        merkle_root,
+        keypair.to_base58_string()
+    )
+}
+
+/// Recover the ephemeral keypair from a claim link or a bare base58 secret key
+pub fn parse_claim_link(link: &str) -> Result<Keypair> {
+    let secret = link.rsplit_once('#').map_or(link, |(_, fragment)| fragment).trim();
+    let bytes = bs58::decode(secret)
+        .into_vec()
+        .map_err(|e| anyhow::anyhow!("Claim link does not contain a valid base58 secret key: {:?}", e))?;
+    Keypair::try_from(bytes.as_slice()).map_err(|e| anyhow::anyhow!("Invalid claim link key: {}", e))
+}
+
+pub fn claim_link(args: ClaimLinkArgs) -> Result<()> {
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    if airdrop_data.mint_on_claim {
+        anyhow::bail!("Claim links are not supported for mint-on-claim airdrops");
+    }
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let mint = airdrop_data.resolve_mint(args.mint.as_deref())?;
+
+    let link_keypair = parse_claim_link(&args.link)?;
+    let link_address = link_keypair.pubkey();
+
+    // The keypair pays fees and rent and, by default, receives the tokens
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+    let destination = match &args.destination {
+        Some(addr) => Pubkey::from_str(addr)?,
+        None => payer.pubkey(),
+    };
+
+    println!("Merkle root: {}", airdrop_data.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Mint: {}", mint);
+    println!("Link address: {}", link_address);
+    println!("Destination: {}", destination);
+
+    let claim_info = airdrop_data
+        .claims
+        .get(&link_address.to_string())
+        .ok_or_else(|| anyhow::anyhow!("Claim link {} is not part of this airdrop", link_address))?;
+    let amount: u64 = claim_info
+        .amount
+        .parse()
+        .with_context(|| "Failed to parse claim amount")?;
+    let leaf_index = claim_info.leaf_index;
+
+    println!("Claim amount: {}", amount);
+    println!("Leaf index: {}", leaf_index);
+
+    let proof = generate_proof(&airdrop_data.merkle_tree, leaf_index)?;
+    println!("Proof length: {} nodes", proof.len());
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(payer),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+
+    // Derive PDAs
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+    let (claim_receipt_pda, _bump) = Pubkey::find_program_address(
+        &[CLAIM_RECEIPT_SEED, merkle_root_pda.as_ref(), link_address.as_ref()],
+        &program_id,
+    );
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let token_mint = TokenMint::fetch(&rpc_client, &mint)?;
+    let destination_token_account = token_mint.associated_token_address(&destination);
+    let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+
+    println!("\nMerkle root PDA: {}", merkle_root_pda);
+    println!("Claim receipt PDA: {}", claim_receipt_pda);
+    println!("Destination token account: {}", destination_token_account);
+
+    let merkle_root: MerkleRoot = program.account(merkle_root_pda)?;
+    let claimed_so_far = fetch_claimed_so_far(&rpc_client, &claim_receipt_pda)?;
+    let vested = merkle_root.vested_amount(amount, unix_timestamp_now()?);
+    let claimable = vested.saturating_sub(claimed_so_far);
+
+    println!("\nVested: {}", vested);
+    println!("Claimed: {}", claimed_so_far);
+    println!("Claimable: {}", claimable);
+
+    if claimable == 0 {
+        anyhow::bail!("Nothing to claim right now ({} of {} already claimed)", claimed_so_far, amount);
+    }
+
+    let (config, fee_recipient) = claim_fee_accounts(&rpc_client, &program_id, &program.payer())?;
+    let gate_token_account = gate_token_account(&rpc_client, &merkle_root, &destination)?;
+
+    println!("\nSending claim transaction...");
+
+    let signature = program
+        .request()
+        .signer(&link_keypair)
+        .accounts(ClaimLink {
+            authority: link_address,
+            payer: program.payer(),
+            destination,
+            destination_token_account,
+            merkle_root_token_account,
+            mint,
+            merkle_root: merkle_root_pda,
+            claim_receipt: claim_receipt_pda,
+            config,
+            gate_token_account,
+            fee_recipient,
+            system_program: anchor_client::solana_sdk::system_program::ID,
+            token_program: token_mint.token_program,
+            associated_token_program: anchor_spl::associated_token::ID,
+        })
+        .accounts(token_mint.transfer_hook_accounts(
+            &rpc_client,
+            &merkle_root_token_account,
+            &destination_token_account,
+            &merkle_root_pda,
+            token_mint.gross_up(claimable, current_epoch(&rpc_client)?)?,
+        )?)
+        .args(airdrop_contract::instruction::ClaimLink {
+            proof,
+            amount,
+            leaf_index: leaf_index as u32,
+        })
+        .send()?;
+
+    println!("\nAirdrop claimed successfully!");
+    println!("Signature: {}", signature);
+    println!(
+        "Explorer: https://explorer.solana.com/tx/{}?cluster={}",
+        signature,
+        args.network.name()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_link_round_trip() {
+        let keypair = Keypair::new();
+        let url = claim_link_url("https://example.com/claim", "abcd", &keypair);
+
+        assert!(url.starts_with("https://example.com/claim?root=abcd#"));
+        assert_eq!(parse_claim_link(&url).unwrap().pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn test_claim_link_keeps_existing_query() {
+        let keypair = Keypair::new();
+        let url = claim_link_url("https://example.com/claim?event=demo", "abcd", &keypair);
+
+        assert!(url.starts_with("https://example.com/claim?event=demo&root=abcd#"));
+    }
+
+    #[test]
+    fn test_parse_bare_secret_key() {
+        let keypair = Keypair::new();
+        let parsed = parse_claim_link(&keypair.to_base58_string()).unwrap();
+
+        assert_eq!(parsed.pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn test_parse_invalid_claim_link() {
+        assert!(parse_claim_link("https://example.com/claim#not-base58!").is_err());
+        assert!(parse_claim_link("https://example.com/claim#abc").is_err());
+    }
+}
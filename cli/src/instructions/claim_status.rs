@@ -0,0 +1,168 @@
+use airdrop_contract::constants::{CLAIM_BITMAP_BITS, CLAIM_BITMAP_SEED};
+use airdrop_contract::state::{ClaimBitmap, MerkleRoot};
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_lang::AccountDeserialize;
+use anyhow::{Context, Result};
+use solana_client::rpc_client::RpcClient;
+use std::{path::PathBuf, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::deploy_airdrop::Network;
+
+/// Maximum number of accounts `getMultipleAccounts` accepts per request
+const RPC_ACCOUNTS_CHUNK: usize = 100;
+
+#[derive(Debug)]
+pub struct ClaimStatusArgs {
+    pub json_path: PathBuf,
+    pub address: Option<String>,
+    pub network: Network,
+    pub program_id: String,
+}
+
+/// Address of bitmap account `index` of a bitmap airdrop
+pub(crate) fn claim_bitmap_pda(program_id: &Pubkey, merkle_root: &Pubkey, index: u32) -> Pubkey {
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[CLAIM_BITMAP_SEED, merkle_root.as_ref(), &index.to_le_bytes()],
+        program_id,
+    );
+    pda
+}
+
+/// Position of a leaf within the leaf layer, mirroring the on-chain check:
+/// `leaf_index` must fall in `[2^depth, 2^depth + leaf_count)`
+pub(crate) fn bitmap_position(leaf_count: u32, leaf_index: u64) -> Result<u32> {
+    let depth = leaf_count.next_power_of_two().trailing_zeros();
+    let position = leaf_index
+        .checked_sub(1 << depth)
+        .filter(|position| *position < leaf_count as u64)
+        .ok_or_else(|| {
+            anyhow::anyhow!("Leaf index {} is outside a {}-leaf tree", leaf_index, leaf_count)
+        })?;
+    Ok(position as u32)
+}
+
+/// Fetch every bitmap account of an airdrop, in index order
+pub(crate) fn fetch_claim_bitmaps(
+    rpc_client: &RpcClient,
+    program_id: &Pubkey,
+    merkle_root_pda: &Pubkey,
+    merkle_root: &MerkleRoot,
+) -> Result<Vec<ClaimBitmap>> {
+    let pdas: Vec<Pubkey> = (0..merkle_root.bitmap_account_count())
+        .map(|index| claim_bitmap_pda(program_id, merkle_root_pda, index))
+        .collect();
+
+    let mut bitmaps = Vec::with_capacity(pdas.len());
+    for chunk in pdas.chunks(RPC_ACCOUNTS_CHUNK) {
+        for (pda, account) in chunk.iter().zip(rpc_client.get_multiple_accounts(chunk)?) {
+            let account = account.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Claim bitmap {} has not been allocated; re-run deploy-airdrop --claim-bitmap",
+                    pda
+                )
+            })?;
+            bitmaps.push(ClaimBitmap::try_deserialize(&mut account.data.as_slice())?);
+        }
+    }
+    Ok(bitmaps)
+}
+
+/// Whether the leaf at `position` is flagged as claimed
+pub(crate) fn is_claimed(bitmaps: &[ClaimBitmap], position: u32) -> bool {
+    bitmaps
+        .get((position / CLAIM_BITMAP_BITS) as usize)
+        .is_some_and(|bitmap| bitmap.is_claimed(position % CLAIM_BITMAP_BITS))
+}
+
+pub fn claim_status(args: ClaimStatusArgs) -> Result<()> {
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let data = rpc_client
+        .get_account_data(&merkle_root_pda)
+        .with_context(|| format!("Airdrop {} not found on-chain", merkle_root_pda))?;
+    let merkle_root = MerkleRoot::try_deserialize(&mut data.as_slice())?;
+    let leaf_count = merkle_root.bitmap_leaf_count.ok_or_else(|| {
+        anyhow::anyhow!("Airdrop tracks claims with per-claimer receipts, not a claim bitmap")
+    })?;
+
+    println!("Merkle root: {}", airdrop_data.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Merkle root PDA: {}", merkle_root_pda);
+
+    let bitmaps = fetch_claim_bitmaps(&rpc_client, &program_id, &merkle_root_pda, &merkle_root)?;
+
+    if let Some(address) = &args.address {
+        let claim = airdrop_data
+            .claims
+            .get(address)
+            .ok_or_else(|| anyhow::anyhow!("Address {} not found in airdrop", address))?;
+        let position = bitmap_position(leaf_count, claim.leaf_index)?;
+        let status = if is_claimed(&bitmaps, position) { "claimed" } else { "unclaimed" };
+        println!("\n{}: {} ({})", address, status, claim.amount);
+        return Ok(());
+    }
+
+    let mut claimed_count = 0usize;
+    let mut claimed_amount = 0u64;
+    for claim in airdrop_data.claims.values() {
+        if is_claimed(&bitmaps, bitmap_position(leaf_count, claim.leaf_index)?) {
+            claimed_count += 1;
+            claimed_amount += claim
+                .amount
+                .parse::<u64>()
+                .with_context(|| format!("invalid amount '{}'", claim.amount))?;
+        }
+    }
+    let total_amount = airdrop_data.total_amount()?;
+
+    println!("Bitmap accounts: {}", bitmaps.len());
+    println!("\nClaimed: {} / {}", claimed_count, airdrop_data.claims.len());
+    println!("Unclaimed: {}", airdrop_data.claims.len() - claimed_count);
+    println!("Claimed amount: {} / {}", claimed_amount, total_amount);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitmap_position_matches_tree_layout() {
+        // A 5-leaf tree is padded to 8 leaves stored at indices 8..16
+        assert_eq!(bitmap_position(5, 8).unwrap(), 0);
+        assert_eq!(bitmap_position(5, 12).unwrap(), 4);
+        assert!(bitmap_position(5, 13).is_err());
+        assert!(bitmap_position(5, 7).is_err());
+        // A single leaf sits at index 1
+        assert_eq!(bitmap_position(1, 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_is_claimed_spans_bitmap_accounts() {
+        let mut first = ClaimBitmap {
+            index: 0,
+            bump: 0,
+            bits: vec![0; (CLAIM_BITMAP_BITS / 8) as usize],
+        };
+        let mut second = ClaimBitmap { index: 1, bump: 0, bits: vec![0; 1] };
+        first.set_claimed(3).unwrap();
+        second.set_claimed(2).unwrap();
+        let bitmaps = [first, second];
+
+        assert!(is_claimed(&bitmaps, 3));
+        assert!(!is_claimed(&bitmaps, 2));
+        assert!(is_claimed(&bitmaps, CLAIM_BITMAP_BITS + 2));
+        assert!(!is_claimed(&bitmaps, CLAIM_BITMAP_BITS + 3));
+        assert!(!is_claimed(&bitmaps, 3 * CLAIM_BITMAP_BITS));
+    }
+}
@@ -0,0 +1,158 @@
+use airdrop_contract::accounts::ClaimVoucher;
+use airdrop_contract::constants::CLAIM_RECEIPT_SEED;
+use airdrop_contract::state::MerkleRoot;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::ed25519_instruction::new_ed25519_instruction_with_signature;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair};
+use anchor_client::solana_sdk::sysvar;
+use anchor_client::Client;
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use std::{path::PathBuf, rc::Rc, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::claim_airdrop::{fetch_claimed_so_far, gate_token_account};
+use crate::instructions::deploy_airdrop::{unix_timestamp_now, Network};
+use crate::instructions::program_config::claim_fee_accounts;
+use crate::instructions::issue_voucher::VoucherJson;
+use crate::instructions::token_mint::{current_epoch, TokenMint};
+
+#[derive(Debug)]
+pub struct ClaimVoucherArgs {
+    pub json_path: PathBuf,
+    pub voucher_path: PathBuf,
+    pub mint: Option<String>,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+pub fn claim_voucher(args: ClaimVoucherArgs) -> Result<()> {
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    let voucher_json = VoucherJson::read(&args.voucher_path)?;
+    let voucher = voucher_json.voucher()?;
+    let signature = voucher_json.signature_bytes()?;
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let mint = airdrop_data.resolve_mint(args.mint.as_deref())?;
+
+    let voucher_signer = airdrop_data
+        .voucher_signer()?
+        .ok_or_else(|| anyhow::anyhow!("{:?} is not a voucher airdrop", args.json_path))?;
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+    if voucher_json.airdrop != merkle_root_pda.to_string() {
+        anyhow::bail!(
+            "Voucher was issued for airdrop {}, not {}",
+            voucher_json.airdrop,
+            merkle_root_pda
+        );
+    }
+
+    // The keypair only pays fees and rent; tokens always go to the voucher's recipient
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    println!("Merkle root PDA: {}", merkle_root_pda);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Mint: {}", mint);
+    println!("Recipient: {}", voucher.recipient);
+    println!("Voucher amount: {}", voucher.amount);
+    println!("Voucher nonce: {}", voucher.nonce);
+
+    let now = unix_timestamp_now()?;
+    if now >= voucher.expires_at {
+        anyhow::bail!("Voucher expired at unix time {}", voucher.expires_at);
+    }
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+
+    let (claim_receipt_pda, _bump) = Pubkey::find_program_address(
+        &[CLAIM_RECEIPT_SEED, merkle_root_pda.as_ref(), &voucher.nonce.to_le_bytes()],
+        &program_id,
+    );
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let token_mint = TokenMint::fetch(&rpc_client, &mint)?;
+    let recipient_token_account = token_mint.associated_token_address(&voucher.recipient);
+    let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+
+    println!("\nClaim receipt PDA: {}", claim_receipt_pda);
+    println!("Recipient token account: {}", recipient_token_account);
+
+    let merkle_root: MerkleRoot = program.account(merkle_root_pda)?;
+    let claimed_so_far = fetch_claimed_so_far(&rpc_client, &claim_receipt_pda)?;
+    let vested = merkle_root.vested_amount(voucher.amount, now);
+    let claimable = vested.saturating_sub(claimed_so_far);
+
+    println!("\nVested: {}", vested);
+    println!("Claimed: {}", claimed_so_far);
+    println!("Claimable: {}", claimable);
+
+    if claimable == 0 {
+        anyhow::bail!(
+            "Nothing to claim right now ({} of {} already claimed)",
+            claimed_so_far,
+            voucher.amount
+        );
+    }
+
+    let (config, fee_recipient) = claim_fee_accounts(&rpc_client, &program_id, &program.payer())?;
+    let gate_token_account = gate_token_account(&rpc_client, &merkle_root, &voucher.recipient)?;
+
+    println!("\nSending claim transaction...");
+
+    // The Ed25519 program checks the signature; the claim instruction that follows
+    // checks that it covered this voucher and the airdrop's signer
+    let verify_ix = new_ed25519_instruction_with_signature(
+        &voucher.message(&merkle_root_pda),
+        &signature,
+        &voucher_signer.to_bytes(),
+    );
+    let signature = program
+        .request()
+        .instruction(verify_ix)
+        .accounts(ClaimVoucher {
+            recipient: voucher.recipient,
+            payer: program.payer(),
+            recipient_token_account,
+            merkle_root_token_account,
+            mint,
+            merkle_root: merkle_root_pda,
+            claim_receipt: claim_receipt_pda,
+            instructions_sysvar: sysvar::instructions::ID,
+            config,
+            gate_token_account,
+            fee_recipient,
+            system_program: anchor_client::solana_sdk::system_program::ID,
+            token_program: token_mint.token_program,
+            associated_token_program: anchor_spl::associated_token::ID,
+        })
+        .accounts(token_mint.transfer_hook_accounts(
+            &rpc_client,
+            &merkle_root_token_account,
+            &recipient_token_account,
+            &merkle_root_pda,
+            token_mint.gross_up(claimable, current_epoch(&rpc_client)?)?,
+        )?)
+        .args(airdrop_contract::instruction::ClaimVoucher { voucher })
+        .send()?;
+
+    println!("\nVoucher claimed successfully!");
+    println!("Signature: {}", signature);
+    println!(
+        "Explorer: https://explorer.solana.com/tx/{}?cluster={}",
+        signature,
+        args.network.name()
+    );
+
+    Ok(())
+}
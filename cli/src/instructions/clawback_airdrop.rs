@@ -0,0 +1,243 @@
+use airdrop_contract::accounts::{Clawback, ClawbackLegacy, ClawbackSol};
+use airdrop_contract::state::MerkleRoot;
+use anchor_client::solana_sdk::bpf_loader_upgradeable;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair, Signature};
+use anchor_client::{Client, Program};
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use std::{path::PathBuf, rc::Rc, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::deploy_airdrop::{unix_timestamp_now, Network};
+use crate::instructions::token_mint::TokenMint;
+
+#[derive(Debug)]
+pub struct ClawbackAirdropArgs {
+    pub json_path: PathBuf,
+    pub destination: Option<String>,
+    pub burn: bool,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+pub fn clawback_airdrop(args: ClawbackAirdropArgs) -> Result<()> {
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    if airdrop_data.mint_on_claim {
+        anyhow::bail!(
+            "Unclaimed tokens of a mint-on-claim airdrop were never minted; \
+            close-airdrop returns the mint authority instead"
+        );
+    }
+    let program_id = Pubkey::from_str(&args.program_id)?;
+
+    // Load keypair
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+    let authority = program.payer();
+
+    // Derive PDAs
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+
+    let destination = match &args.destination {
+        Some(addr) => Pubkey::from_str(addr)?,
+        None => authority,
+    };
+
+    // Legacy roots record no authority or expiry; the program's upgrade authority sweeps them
+    if airdrop_data.is_legacy() {
+        if args.burn {
+            anyhow::bail!("--burn is not supported for legacy airdrops");
+        }
+        println!("Merkle root: {}", airdrop_data.merkle_root);
+        println!("Network: {}", args.network.name());
+        println!("Program ID: {}", program_id);
+        println!("Merkle root PDA: {}", merkle_root_pda);
+
+        let signature = clawback_legacy(
+            &program,
+            &rpc_client,
+            &airdrop_data.resolve_mint(None)?,
+            merkle_root_pda,
+            &destination,
+        )?;
+        print_signatures(&[signature], &args.network);
+        return Ok(());
+    }
+
+    let merkle_root: MerkleRoot = program.account(merkle_root_pda)?;
+    if merkle_root.authority != authority {
+        anyhow::bail!(
+            "Keypair {} is not the airdrop authority ({})",
+            authority,
+            merkle_root.authority
+        );
+    }
+    let now = unix_timestamp_now()?;
+    if now < merkle_root.expires_at {
+        anyhow::bail!(
+            "Airdrop has not expired yet: clawback opens at unix time {} ({}s from now)",
+            merkle_root.expires_at,
+            merkle_root.expires_at - now
+        );
+    }
+
+    println!("Merkle root: {}", airdrop_data.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Merkle root PDA: {}", merkle_root_pda);
+
+    // SOL airdrops keep the lamports in the merkle root itself, above its rent
+    let mut signatures = Vec::new();
+    if merkle_root.native_sol {
+        if args.burn {
+            anyhow::bail!("--burn is not supported for SOL airdrops");
+        }
+        let account = rpc_client.get_account(&merkle_root_pda)?;
+        let rent = rpc_client.get_minimum_balance_for_rent_exemption(account.data.len())?;
+        println!("\nUnclaimed balance (lamports): {}", account.lamports.saturating_sub(rent));
+        println!("Returning unclaimed SOL to {}...", destination);
+
+        let signature = program
+            .request()
+            .accounts(ClawbackSol {
+                authority,
+                merkle_root: merkle_root_pda,
+                destination,
+            })
+            .args(airdrop_contract::instruction::ClawbackSol {})
+            .send()?;
+        signatures.push(signature);
+    }
+
+    // Multi-mint airdrops hold one vault per mint
+    for mint in airdrop_data.mints()? {
+        let token_mint = TokenMint::fetch(&rpc_client, &mint)?;
+        let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+        let remaining = rpc_client.get_token_account_balance(&merkle_root_token_account)?;
+
+        println!("\nMint: {}", mint);
+        println!("Unclaimed balance: {}", remaining.amount);
+
+        let mut request = program.request();
+        let mut hook_accounts = Vec::new();
+        let destination_token_account = if args.burn {
+            println!("Burning unclaimed tokens...");
+            None
+        } else {
+            let destination_token_account = token_mint.associated_token_address(&destination);
+            println!("Returning unclaimed tokens to {}...", destination_token_account);
+
+            request = request.instruction(create_associated_token_account_idempotent(
+                &authority,
+                &destination,
+                &mint,
+                &token_mint.token_program,
+            ));
+            hook_accounts = token_mint.transfer_hook_accounts(
+                &rpc_client,
+                &merkle_root_token_account,
+                &destination_token_account,
+                &merkle_root_pda,
+                remaining.amount.parse()?,
+            )?;
+            Some(destination_token_account)
+        };
+
+        let signature = request
+            .accounts(Clawback {
+                authority,
+                merkle_root: merkle_root_pda,
+                merkle_root_token_account,
+                destination_token_account,
+                mint,
+                token_program: token_mint.token_program,
+            })
+            .accounts(hook_accounts)
+            .args(airdrop_contract::instruction::Clawback { burn: args.burn })
+            .send()?;
+        signatures.push(signature);
+    }
+
+    print_signatures(&signatures, &args.network);
+
+    Ok(())
+}
+
+/// Sweep a first-release vault to `destination`'s token account, signed by the upgrade authority
+fn clawback_legacy(
+    program: &Program<Rc<Keypair>>,
+    rpc_client: &RpcClient,
+    mint: &Pubkey,
+    merkle_root_pda: Pubkey,
+    destination: &Pubkey,
+) -> Result<Signature> {
+    let token_mint = TokenMint::fetch(rpc_client, mint)?;
+    let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+    let remaining = rpc_client.get_token_account_balance(&merkle_root_token_account)?;
+    let destination_token_account = token_mint.associated_token_address(destination);
+    let authority = program.payer();
+    let program_id = program.id();
+
+    println!("\nMint: {}", mint);
+    println!("Unclaimed balance: {}", remaining.amount);
+    println!("Returning unclaimed tokens to {}...", destination_token_account);
+
+    let hook_accounts = token_mint.transfer_hook_accounts(
+        rpc_client,
+        &merkle_root_token_account,
+        &destination_token_account,
+        &merkle_root_pda,
+        remaining.amount.parse()?,
+    )?;
+    let signature = program
+        .request()
+        .instruction(create_associated_token_account_idempotent(
+            &authority,
+            destination,
+            mint,
+            &token_mint.token_program,
+        ))
+        .accounts(ClawbackLegacy {
+            authority,
+            program: program_id,
+            program_data: bpf_loader_upgradeable::get_program_data_address(&program_id),
+            merkle_root: merkle_root_pda,
+            merkle_root_token_account,
+            destination_token_account,
+            mint: *mint,
+            token_program: token_mint.token_program,
+        })
+        .accounts(hook_accounts)
+        .args(airdrop_contract::instruction::ClawbackLegacy {})
+        .send()?;
+    Ok(signature)
+}
+
+fn print_signatures(signatures: &[Signature], network: &Network) {
+    println!("\nAirdrop clawed back successfully!");
+    for signature in signatures {
+        println!("Signature: {}", signature);
+        println!(
+            "Explorer: https://explorer.solana.com/tx/{}?cluster={}",
+            signature,
+            network.name()
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use airdrop_contract::accounts::{
+    CloseAirdrop, CloseClaimBitmap, CloseClaimReceipt, CloseLegacyAirdrop, CloseMintAirdrop,
+    CloseSolAirdrop, CloseVault,
+};
+use airdrop_contract::constants::CLAIM_BITMAP_BITS;
+use airdrop_contract::state::{ClaimReceipt, MerkleRoot};
+use anchor_client::solana_sdk::bpf_loader_upgradeable;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair};
+use anchor_client::solana_sdk::system_program;
+use anchor_client::{Client, Program};
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anyhow::{Context, Result};
+use solana_client::rpc_client::RpcClient;
+use std::{path::PathBuf, rc::Rc, str::FromStr};
+
+use crate::instructions::airdrop_json::{closed_airdrop_pda, AirdropJson};
+use crate::instructions::airdrop_metadata::{close_metadata, fetch_metadata};
+use crate::instructions::claim_airdrop::claim_receipt_pda;
+use crate::instructions::claim_status::claim_bitmap_pda;
+use crate::instructions::deploy_airdrop::{unix_timestamp_now, Network};
+use crate::instructions::token_mint::TokenMint;
+
+/// Maximum number of accounts `getMultipleAccounts` accepts per request
+const RPC_ACCOUNTS_CHUNK: usize = 100;
+
+#[derive(Debug)]
+pub struct CloseAirdropArgs {
+    pub json_path: PathBuf,
+    pub batch_size: usize,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+pub fn close_airdrop(args: CloseAirdropArgs) -> Result<()> {
+    if args.batch_size == 0 {
+        anyhow::bail!("--batch-size must be at least 1");
+    }
+
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    let mints = airdrop_data.mints()?;
+    let program_id = Pubkey::from_str(&args.program_id)?;
+
+    // Load keypair
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+    let authority = program.payer();
+
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+
+    println!("Merkle root: {}", airdrop_data.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Merkle root PDA: {}", merkle_root_pda);
+
+    // Close the merkle root and its vault first; receipts can only be closed afterwards.
+    // Skipped when re-running after an interrupted close.
+    let merkle_root_exists = rpc_client
+        .get_account_with_commitment(&merkle_root_pda, CommitmentConfig::confirmed())?
+        .value
+        .is_some();
+    if merkle_root_exists && airdrop_data.is_legacy() {
+        close_legacy_root(&program, &rpc_client, &airdrop_data.resolve_mint(None)?, merkle_root_pda)?;
+    } else if merkle_root_exists {
+        let merkle_root: MerkleRoot = program.account(merkle_root_pda)?;
+        if merkle_root.authority != authority {
+            anyhow::bail!(
+                "Keypair {} is not the airdrop authority ({})",
+                authority,
+                merkle_root.authority
+            );
+        }
+        let now = unix_timestamp_now()?;
+        if now < merkle_root.expires_at {
+            anyhow::bail!(
+                "Airdrop has not expired yet: it can be closed at unix time {} ({}s from now)",
+                merkle_root.expires_at,
+                merkle_root.expires_at - now
+            );
+        }
+        // Left behind so the merkle root can never be deployed again and re-pay closed receipts
+        let closed_airdrop = closed_airdrop_pda(&program_id, &merkle_root_pda);
+        // Metadata is checked against the merkle root, so it has to go first
+        if fetch_metadata(&rpc_client, &program_id, &merkle_root_pda)?.is_some() {
+            println!("\nClosing metadata...");
+            let signature = close_metadata(&program, merkle_root_pda)?;
+            println!("Signature: {}", signature);
+        }
+        if merkle_root.native_sol {
+            // Any lamports left over go back to the authority along with the rent
+            println!("\nClosing merkle root...");
+            let signature = program
+                .request()
+                .accounts(CloseSolAirdrop {
+                    authority,
+                    merkle_root: merkle_root_pda,
+                    closed_airdrop,
+                    system_program: system_program::ID,
+                })
+                .args(airdrop_contract::instruction::CloseSolAirdrop {})
+                .send()?;
+            println!("Signature: {}", signature);
+        } else if merkle_root.mint_on_claim {
+            // Nothing was minted ahead of claims, so there is no vault; the mint authority
+            // goes back to the airdrop authority
+            let token_mint = TokenMint::fetch(&rpc_client, &merkle_root.mint)?;
+            println!("\nClosing merkle root...");
+            let signature = program
+                .request()
+                .accounts(CloseMintAirdrop {
+                    authority,
+                    merkle_root: merkle_root_pda,
+                    closed_airdrop,
+                    mint: token_mint.address,
+                    system_program: system_program::ID,
+                    token_program: token_mint.token_program,
+                })
+                .args(airdrop_contract::instruction::CloseMintAirdrop {})
+                .send()?;
+            println!("Signature: {}", signature);
+            println!("Mint authority returned to {}", authority);
+        } else {
+            let mint = TokenMint::fetch(&rpc_client, &airdrop_data.resolve_mint(None)?)?;
+            let merkle_root_token_account = mint.associated_token_address(&merkle_root_pda);
+            let mut open_vaults = Vec::new();
+            for vault_mint in &mints {
+                let token_mint = TokenMint::fetch(&rpc_client, vault_mint)?;
+                let vault = token_mint.associated_token_address(&merkle_root_pda);
+                let vault_exists = rpc_client
+                    .get_account_with_commitment(&vault, CommitmentConfig::confirmed())?
+                    .value
+                    .is_some();
+                if !vault_exists {
+                    // Closed by an earlier, interrupted run
+                    continue;
+                }
+                let remaining = rpc_client.get_token_account_balance(&vault)?;
+                if remaining.amount != "0" {
+                    anyhow::bail!(
+                        "Vault {} still holds {} tokens; run clawback-airdrop first",
+                        vault,
+                        remaining.amount
+                    );
+                }
+                open_vaults.push((token_mint, vault));
+            }
+
+            // A multi-mint airdrop's other vaults must go first, while the merkle root can sign
+            let extra_vaults =
+                open_vaults.iter().filter(|(vault_mint, _)| vault_mint.address != mint.address);
+            for (vault_mint, vault) in extra_vaults {
+                let signature = program
+                    .request()
+                    .accounts(CloseVault {
+                        authority,
+                        merkle_root: merkle_root_pda,
+                        vault_record: airdrop_data
+                            .vault_record_pda(&program_id, &vault_mint.address)?
+                            .ok_or_else(|| anyhow::anyhow!("{} has no vault record", vault_mint.address))?,
+                        merkle_root_token_account: *vault,
+                        mint: vault_mint.address,
+                        token_program: vault_mint.token_program,
+                    })
+                    .args(airdrop_contract::instruction::CloseVault {})
+                    .send()?;
+                println!("Closed vault {} ({})", vault, signature);
+            }
+
+            println!("\nClosing merkle root and vault...");
+            let signature = program
+                .request()
+                .accounts(CloseAirdrop {
+                    authority,
+                    merkle_root: merkle_root_pda,
+                    closed_airdrop,
+                    merkle_root_token_account,
+                    mint: mint.address,
+                    system_program: system_program::ID,
+                    token_program: mint.token_program,
+                })
+                .args(airdrop_contract::instruction::CloseAirdrop {})
+                .send()?;
+            println!("Signature: {}", signature);
+        }
+    } else {
+        println!("\nMerkle root already closed");
+    }
+
+    // Bitmap airdrops hold the authority's rent in their claim bitmaps instead of receipts
+    let open_bitmaps = open_claim_bitmaps(&rpc_client, &program_id, &merkle_root_pda, &airdrop_data)?;
+    if !open_bitmaps.is_empty() {
+        println!(
+            "\nClosing {} claim bitmap(s) in batches of {}...",
+            open_bitmaps.len(),
+            args.batch_size
+        );
+        let closed_airdrop = closed_airdrop_pda(&program_id, &merkle_root_pda);
+        let mut closed = 0;
+        for batch in open_bitmaps.chunks(args.batch_size) {
+            let mut request = program.request();
+            for claim_bitmap in batch {
+                request = request.instruction(Instruction {
+                    program_id,
+                    accounts: CloseClaimBitmap {
+                        authority,
+                        merkle_root: merkle_root_pda,
+                        closed_airdrop,
+                        claim_bitmap: *claim_bitmap,
+                    }
+                    .to_account_metas(None),
+                    data: airdrop_contract::instruction::CloseClaimBitmap {}.data(),
+                });
+            }
+            let signature = request.send()?;
+            closed += batch.len();
+            println!("Closed {}/{} bitmaps ({})", closed, open_bitmaps.len(), signature);
+        }
+    }
+
+    // Find the receipts that are still open; multi-mint airdrops have one per leaf
+    let leaves = airdrop_data
+        .allocations()
+        .into_iter()
+        .map(|(address, leaf_mint, _)| {
+            let claimant = Pubkey::from_str(address)
+                .with_context(|| format!("Invalid claimant address: {}", address))?;
+            let leaf_mint = leaf_mint
+                .map(|leaf_mint| {
+                    Pubkey::from_str(leaf_mint)
+                        .with_context(|| format!("Invalid mint address: {}", leaf_mint))
+                })
+                .transpose()?;
+            Ok((claimant, leaf_mint))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let mut open_receipts = Vec::new();
+    for chunk in leaves.chunks(RPC_ACCOUNTS_CHUNK) {
+        let receipts: Vec<Pubkey> = chunk
+            .iter()
+            .map(|(claimant, leaf_mint)| {
+                claim_receipt_pda(&program_id, &merkle_root_pda, claimant, leaf_mint.as_ref())
+            })
+            .collect();
+        let accounts = rpc_client.get_multiple_accounts(&receipts)?;
+        for ((leaf, receipt), account) in chunk.iter().zip(receipts).zip(accounts) {
+            if let Some(account) = account {
+                let payer = match ClaimReceipt::try_deserialize(&mut account.data.as_slice()) {
+                    Ok(claim_receipt) => claim_receipt.payer,
+                    // Receipts written by the first release record no payer to refund
+                    Err(_) if airdrop_data.is_legacy() => continue,
+                    Err(e) => return Err(e.into()),
+                };
+                open_receipts.push((*leaf, receipt, payer));
+            }
+        }
+    }
+
+    println!(
+        "\nClosing {} claim receipt(s) in batches of {}...",
+        open_receipts.len(),
+        args.batch_size
+    );
+
+    // Rent from each receipt goes back to whoever paid for the claim
+    let mut closed = 0;
+    for batch in open_receipts.chunks(args.batch_size) {
+        let mut request = program.request();
+        for ((claimant, leaf_mint), receipt, payer) in batch {
+            request = request.instruction(Instruction {
+                program_id,
+                accounts: CloseClaimReceipt {
+                    claimant: *claimant,
+                    payer: *payer,
+                    merkle_root: merkle_root_pda,
+                    mint: *leaf_mint,
+                    claim_receipt: *receipt,
+                }
+                .to_account_metas(None),
+                data: airdrop_contract::instruction::CloseClaimReceipt {}.data(),
+            });
+        }
+        let signature = request.send()?;
+        closed += batch.len();
+        println!("Closed {}/{} receipts ({})", closed, open_receipts.len(), signature);
+    }
+
+    println!("\nAirdrop closed successfully!");
+
+    Ok(())
+}
+
+/// Claim bitmaps of the airdrop that still exist. The merkle root may already be closed, so
+/// every index the tree could need is probed; airdrops deployed without bitmaps have none.
+fn open_claim_bitmaps(
+    rpc_client: &RpcClient,
+    program_id: &Pubkey,
+    merkle_root_pda: &Pubkey,
+    airdrop_data: &AirdropJson,
+) -> Result<Vec<Pubkey>> {
+    // Legacy and voucher airdrops never had bitmaps
+    if airdrop_data.is_legacy() || airdrop_data.allocations().is_empty() {
+        return Ok(Vec::new());
+    }
+    let bitmap_count = airdrop_data.leaf_count()?.div_ceil(CLAIM_BITMAP_BITS);
+    let pdas: Vec<Pubkey> = (0..bitmap_count)
+        .map(|index| claim_bitmap_pda(program_id, merkle_root_pda, index))
+        .collect();
+
+    let mut open = Vec::new();
+    for chunk in pdas.chunks(RPC_ACCOUNTS_CHUNK) {
+        let accounts = rpc_client.get_multiple_accounts(chunk)?;
+        for (pda, account) in chunk.iter().zip(accounts) {
+            if account.is_some() {
+                open.push(*pda);
+            }
+        }
+    }
+    Ok(open)
+}
+
+/// Close a `LegacyMerkleRoot` and its empty vault. The first release recorded no authority, so
+/// the program's upgrade authority signs and receives the rent.
+fn close_legacy_root(
+    program: &Program<Rc<Keypair>>,
+    rpc_client: &RpcClient,
+    mint: &Pubkey,
+    merkle_root_pda: Pubkey,
+) -> Result<()> {
+    let token_mint = TokenMint::fetch(rpc_client, mint)?;
+    let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+    let remaining = rpc_client.get_token_account_balance(&merkle_root_token_account)?;
+    if remaining.amount != "0" {
+        anyhow::bail!(
+            "Vault {} still holds {} tokens; run clawback-airdrop first",
+            merkle_root_token_account,
+            remaining.amount
+        );
+    }
+
+    let program_id = program.id();
+    println!("\nClosing legacy merkle root and vault...");
+    let signature = program
+        .request()
+        .accounts(CloseLegacyAirdrop {
+            authority: program.payer(),
+            program: program_id,
+            program_data: bpf_loader_upgradeable::get_program_data_address(&program_id),
+            merkle_root: merkle_root_pda,
+            merkle_root_token_account,
+            mint: token_mint.address,
+            token_program: token_mint.token_program,
+        })
+        .args(airdrop_contract::instruction::CloseLegacyAirdrop {})
+        .send()?;
+    println!("Signature: {}", signature);
+    Ok(())
+}
@@ -1,8 +1,26 @@
+use airdrop_contract::constants::{
+    HASH_VERSION_KECCAK, HASH_VERSION_V1, HASH_VERSION_V2, LEAF_ENCODING_BASE58, LEAF_ENCODING_PUBKEY, LEAF_PREFIX,
+    NODE_PREFIX,
+};
+use anchor_client::solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
 use anyhow::{Context, Result};
 use csv::StringRecord;
 use serde::Serialize;
 use sha2::{Digest, Sha256};
-use std::{collections::BTreeMap, fs::File, path::PathBuf};
+use sha3::Keccak256;
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs::File,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::instructions::claim_link::claim_link_url;
+
+const CLAIM_LINKS_PATH: &str = "claim_links.csv";
 
 #[derive(Serialize)]
 struct ClaimEntry {
@@ -15,15 +33,72 @@ struct AirdropData {
     merkle_root: String,
     merkle_tree: Vec<String>,
     claims: BTreeMap<String, ClaimEntry>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    mint_claims: BTreeMap<String, BTreeMap<String, ClaimEntry>>,
     mint: Option<String>,
+    hash_version: u8,
+    leaf_encoding: u8,
+}
+
+/// Parse a `--leaf-encoding` value into one of the `LEAF_ENCODING_*` constants
+pub fn parse_leaf_encoding(value: &str) -> Result<u8> {
+    match value {
+        "pubkey" => Ok(LEAF_ENCODING_PUBKEY),
+        "base58" => Ok(LEAF_ENCODING_BASE58),
+        _ => anyhow::bail!("Unsupported leaf encoding '{}' (expected pubkey or base58)", value),
+    }
 }
 
-pub fn create_airdrop(csv_path: &PathBuf) -> Result<()> {
-    let (leaves, addresses, amounts) = parse_airdrop_csv(csv_path)?;
-    let merkle_tree = construct_merkle_tree(leaves);
+/// Build airdrop.json from a CSV. With `link_base_url`, the first CSV column is
+/// only a label: each row gets a throwaway keypair that the tree is built over,
+/// and the secret claim links are written to claim_links.csv.
+pub fn create_airdrop(
+    csv_path: &PathBuf,
+    link_base_url: Option<&str>,
+    hash_version: u8,
+    leaf_encoding: u8,
+) -> Result<()> {
+    if !matches!(hash_version, HASH_VERSION_V1 | HASH_VERSION_V2 | HASH_VERSION_KECCAK) {
+        anyhow::bail!("Unsupported hash version {} (expected 1, 2 or 3)", hash_version);
+    }
+    if hash_version == HASH_VERSION_KECCAK && leaf_encoding != LEAF_ENCODING_PUBKEY {
+        anyhow::bail!("Keccak trees (hash version 3) require --leaf-encoding pubkey");
+    }
+    // In link mode the first column is a free-form label, not an address
+    let parse_addresses = link_base_url.is_none();
+    let (mut leaves, mut addresses, mints, amounts) =
+        parse_airdrop_csv(csv_path, hash_version, leaf_encoding, parse_addresses)?;
+    if link_base_url.is_some() && mints.iter().any(Option::is_some) {
+        anyhow::bail!("Claim links do not support a mint column");
+    }
+
+    let mut link_keypairs = Vec::new();
+    if link_base_url.is_some() {
+        link_keypairs = addresses.iter().map(|_| Keypair::new()).collect();
+        for (keypair, amount) in link_keypairs.iter().zip(&amounts) {
+            let address = keypair.pubkey();
+            leaves.push(leaf_hash(hash_version, leaf_encoding, &address, None, amount.parse()?));
+        }
+    }
+
+    let merkle_tree = construct_merkle_tree(leaves, hash_version);
     let leaf_offset = merkle_tree.len() / 2;
 
-    write_airdrop_json(&merkle_tree, &addresses, &amounts, leaf_offset)?;
+    if let Some(base_url) = link_base_url {
+        let labels = std::mem::take(&mut addresses);
+        addresses = link_keypairs.iter().map(|k| k.pubkey().to_string()).collect();
+        write_claim_links(base_url, &merkle_tree, &labels, &link_keypairs, &amounts)?;
+    }
+
+    write_airdrop_json(
+        &merkle_tree,
+        &addresses,
+        &mints,
+        &amounts,
+        leaf_offset,
+        hash_version,
+        leaf_encoding,
+    )?;
     Ok(())
 }
 
@@ -35,7 +110,62 @@ fn hash(data: &[u8]) -> [u8; 32] {
     out
 }
 
-/// Hash two 32-byte child nodes into one parent hash.
+/// Compute keccak256 hash of bytes
+fn keccak(data: &[u8]) -> [u8; 32] {
+    let digest = Keccak256::digest(data);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&digest);
+    out
+}
+
+/// Leaf committed to by the on-chain program: sha256(address || amount_le), where the
+/// address is the raw pubkey bytes or, for `LEAF_ENCODING_BASE58`, its base58 text;
+/// prefixed with `LEAF_PREFIX` under v2. Keccak leaves use the double-hashed encoding of
+/// OpenZeppelin's StandardMerkleTree: keccak256(keccak256(abi.encode(bytes32 address, uint256 amount))),
+/// though the tree around them is laid out differently, so its root is not StandardMerkleTree's.
+/// Leaves of multi-mint airdrops insert the raw mint bytes after the address.
+fn leaf_hash(
+    hash_version: u8,
+    leaf_encoding: u8,
+    address: &Pubkey,
+    mint: Option<&Pubkey>,
+    amount: u64,
+) -> [u8; 32] {
+    let mint: &[u8] = mint.map_or(&[], |mint| mint.as_ref());
+    if hash_version == HASH_VERSION_KECCAK {
+        let mut amount_word = [0u8; 32];
+        amount_word[24..].copy_from_slice(&amount.to_be_bytes());
+        return keccak(&keccak(&[address.as_ref(), mint, &amount_word].concat()));
+    }
+
+    let base58;
+    let address = match leaf_encoding {
+        LEAF_ENCODING_BASE58 => {
+            base58 = address.to_string();
+            base58.as_bytes()
+        }
+        _ => address.as_ref(),
+    };
+    match hash_version {
+        HASH_VERSION_V1 => hash(&[address, mint, &amount.to_le_bytes()].concat()),
+        _ => hash(&[LEAF_PREFIX, address, mint, &amount.to_le_bytes()].concat()),
+    }
+}
+
+/// Parent of two nodes under the given hash version. Keccak trees hash the
+/// sorted pair, which is what lets OpenZeppelin's `MerkleProof` skip leaf indices.
+fn node_hash(hash_version: u8, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    match hash_version {
+        HASH_VERSION_V1 => hash_children(left, right),
+        HASH_VERSION_KECCAK => {
+            let (low, high) = if left <= right { (left, right) } else { (right, left) };
+            keccak(&[&low[..], &high[..]].concat())
+        }
+        _ => hash(&[NODE_PREFIX, left, right].concat()),
+    }
+}
+
+/// Hash two 32-byte child nodes into one parent hash (v1).
 fn hash_children(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
     let mut hasher = Sha256::new();
     hasher.update(left);
@@ -59,37 +189,78 @@ fn min_power_of_2(num_leaves: usize) -> usize {
     power
 }
 
-/// Parse airdrop CSV leaves
-fn parse_airdrop_csv(csv_path: &PathBuf) -> Result<(Vec<[u8; 32]>, Vec<String>, Vec<String>)> {
+/// Parse airdrop CSV leaves. Leaves are only built when `parse_addresses` is set,
+/// since otherwise the first column is a label rather than a wallet address.
+/// A column headed `mint` after address and amount makes this a multi-mint airdrop.
+fn parse_airdrop_csv(
+    csv_path: &PathBuf,
+    hash_version: u8,
+    leaf_encoding: u8,
+    parse_addresses: bool,
+) -> Result<(Vec<[u8; 32]>, Vec<String>, Vec<Option<Pubkey>>, Vec<String>)> {
     let file = File::open(csv_path).with_context(|| format!("Failed to open {:?}", csv_path))?;
     let mut rdr = csv::Reader::from_reader(file);
 
-    // Expect header address,amount
+    // Expect header address,amount[,mint]
+    let mint_column = rdr
+        .headers()?
+        .iter()
+        .position(|header| header.trim().eq_ignore_ascii_case("mint"));
+    if mint_column.is_some_and(|column| column < 2) {
+        anyhow::bail!("The mint column must come after the address and amount columns");
+    }
     let mut leaves = Vec::new();
     let mut addresses = Vec::new();
+    let mut mints = Vec::new();
     let mut amounts = Vec::new();
+    let mut allocations = HashSet::new();
 
-    for result in rdr.records() {
+    for (row, result) in rdr.records().enumerate() {
         let record: StringRecord = result?;
         let address = record.get(0).context("missing address field")?;
         let amount = record.get(1).context("missing amount field")?;
         // Parse amount as u64 and encode in little-endian like the on-chain program
         let amount_u64: u64 = amount.parse().with_context(|| format!("invalid amount '{}'", amount))?;
-        let leaf_bytes = [address.as_bytes(), &amount_u64.to_le_bytes()].concat();
-        leaves.push(hash(&leaf_bytes));
+        let mint = match mint_column {
+            Some(column) => {
+                let mint = record.get(column).context("missing mint field")?;
+                let mint = Pubkey::from_str(mint).map_err(|e| {
+                    anyhow::anyhow!("invalid mint '{}' on row {}: {}", mint, row + 2, e)
+                })?;
+                Some(mint)
+            }
+            None => None,
+        };
+        // Receipts are keyed by address (and mint), so a second leaf could never be claimed.
+        // Link-mode labels may repeat, since every row gets its own throwaway key.
+        if parse_addresses && !allocations.insert((address.to_string(), mint)) {
+            match mint {
+                Some(mint) => {
+                    anyhow::bail!("{} is allocated {} twice (row {})", address, mint, row + 2)
+                }
+                None => anyhow::bail!("{} appears twice (row {})", address, row + 2),
+            }
+        }
+        if parse_addresses {
+            let pubkey = Pubkey::from_str(address).map_err(|e| {
+                anyhow::anyhow!("invalid address '{}' on row {}: {}", address, row + 2, e)
+            })?;
+            leaves.push(leaf_hash(hash_version, leaf_encoding, &pubkey, mint.as_ref(), amount_u64));
+        }
         addresses.push(address.to_string());
+        mints.push(mint);
         amounts.push(amount.to_string());
     }
 
-    if leaves.is_empty() {
+    if addresses.is_empty() {
         anyhow::bail!("CSV contains no rows");
     }
 
-    Ok((leaves, addresses, amounts))
+    Ok((leaves, addresses, mints, amounts))
 }
 
 /// Construct the merkle tree
-fn construct_merkle_tree(mut leaves: Vec<[u8; 32]>) -> Vec<[u8; 32]> {
+fn construct_merkle_tree(mut leaves: Vec<[u8; 32]>, hash_version: u8) -> Vec<[u8; 32]> {
     let power = min_power_of_2(leaves.len());
     let tree_levels = power + 1;
     let tree_leaf_nodes = usize::pow(2, power as u32);
@@ -104,7 +275,7 @@ fn construct_merkle_tree(mut leaves: Vec<[u8; 32]>) -> Vec<[u8; 32]> {
     }
 
     for i in (1..tree_leaf_nodes).rev() {
-        tree[i] = hash_children(&tree[i * 2], &tree[i * 2 + 1]);
+        tree[i] = node_hash(hash_version, &tree[i * 2], &tree[i * 2 + 1]);
     }
 
     tree
@@ -114,35 +285,76 @@ fn construct_merkle_tree(mut leaves: Vec<[u8; 32]>) -> Vec<[u8; 32]> {
 fn write_airdrop_json(
     tree: &[[u8; 32]],
     addresses: &[String],
+    mints: &[Option<Pubkey>],
     amounts: &[String],
     leaf_offset: usize,
+    hash_version: u8,
+    leaf_encoding: u8,
 ) -> Result<()> {
     let file = File::create("airdrop.json").with_context(|| "Failed to create airdrop.json")?;
     let merkle_root = tree[1];
 
     let mut claims = BTreeMap::new();
+    let mut mint_claims: BTreeMap<String, BTreeMap<String, ClaimEntry>> = BTreeMap::new();
 
-    for (i, (addr, amount)) in addresses.iter().zip(amounts).enumerate() {
-        let leaf_index = i + leaf_offset;
-        claims.insert(
-            addr.clone(),
-            ClaimEntry {
-                amount: amount.to_string(),
-                leaf_index,
-            },
-        );
+    for (i, ((addr, mint), amount)) in addresses.iter().zip(mints).zip(amounts).enumerate() {
+        let entry = ClaimEntry {
+            amount: amount.to_string(),
+            leaf_index: i + leaf_offset,
+        };
+        match mint {
+            Some(mint) => {
+                mint_claims.entry(addr.clone()).or_default().insert(mint.to_string(), entry);
+            }
+            None => {
+                claims.insert(addr.clone(), entry);
+            }
+        }
     }
 
     let data = AirdropData {
         merkle_root: hex::encode(merkle_root),
         claims,
+        mint_claims,
         merkle_tree: tree.iter().map(|h| hex::encode(h)).collect(),
         mint: None,
+        hash_version,
+        leaf_encoding,
     };
     serde_json::to_writer_pretty(file, &data).with_context(|| "Failed to write Airdrop JSON")?;
     Ok(())
 }
 
+/// Write one secret claim link per recipient. Anyone holding a link can claim its allocation.
+fn write_claim_links(
+    base_url: &str,
+    tree: &[[u8; 32]],
+    labels: &[String],
+    keypairs: &[Keypair],
+    amounts: &[String],
+) -> Result<()> {
+    let merkle_root = hex::encode(tree[1]);
+    let mut writer = csv::Writer::from_path(CLAIM_LINKS_PATH)
+        .with_context(|| format!("Failed to create {}", CLAIM_LINKS_PATH))?;
+    writer.write_record(["label", "address", "amount", "link"])?;
+    for ((label, keypair), amount) in labels.iter().zip(keypairs).zip(amounts) {
+        writer.write_record([
+            label.as_str(),
+            &keypair.pubkey().to_string(),
+            amount.as_str(),
+            &claim_link_url(base_url, &merkle_root, keypair),
+        ])?;
+    }
+    writer.flush()?;
+
+    println!(
+        "Wrote {} claim links to {} - these contain secret keys, share each one only with its recipient",
+        keypairs.len(),
+        CLAIM_LINKS_PATH
+    );
+    Ok(())
+}
+
 #[allow(dead_code)]
 fn create_proof(tree: &[[u8; 32]], leaf_index: usize) -> Vec<[u8; 32]> {
     let mut proof = Vec::new();
@@ -185,7 +397,7 @@ mod tests {
         ];
 
         let leaves = vec![leaf1, leaf2, leaf3, leaf4];
-        let merkle_tree = construct_merkle_tree(leaves.clone());
+        let merkle_tree = construct_merkle_tree(leaves.clone(), HASH_VERSION_V1);
 
         return MerkleTreeTestData {
             merkle_tree,
@@ -213,7 +425,7 @@ mod tests {
             hash(&leaf3_bytes),
             hash(&leaf4_bytes),
         ];
-        let merkle_tree = construct_merkle_tree(leaves.clone());
+        let merkle_tree = construct_merkle_tree(leaves.clone(), HASH_VERSION_V1);
 
         return MerkleTreeTestData {
             merkle_tree,
@@ -346,7 +558,7 @@ mod tests {
             hash(&leaf3_bytes),
         ];
 
-        let merkle_tree = construct_merkle_tree(leaves.clone());
+        let merkle_tree = construct_merkle_tree(leaves.clone(), HASH_VERSION_V1);
 
         // 3 leaves -> padded to 4 -> tree size is 8
         assert_eq!(merkle_tree.len(), 8);
@@ -379,7 +591,7 @@ mod tests {
         let leaf = hash(&leaf_bytes);
         let leaves = vec![leaf];
 
-        let merkle_tree = construct_merkle_tree(leaves.clone());
+        let merkle_tree = construct_merkle_tree(leaves.clone(), HASH_VERSION_V1);
 
         // 1 leaf -> min_power_of_2(1)=0 -> tree_levels=1 -> tree size is 2^1 = 2
         assert_eq!(merkle_tree.len(), 2);
@@ -403,7 +615,7 @@ mod tests {
         let leaf2 = hash(&leaf2_bytes);
         let leaves = vec![leaf1, leaf2];
 
-        let merkle_tree = construct_merkle_tree(leaves.clone());
+        let merkle_tree = construct_merkle_tree(leaves.clone(), HASH_VERSION_V1);
 
         // 2 leaves -> min_power_of_2(2)=1 -> tree_levels=2 -> tree size is 2^2 = 4
         assert_eq!(merkle_tree.len(), 4);
@@ -469,7 +681,7 @@ mod tests {
             })
             .collect();
 
-        let merkle_tree = construct_merkle_tree(leaves.clone());
+        let merkle_tree = construct_merkle_tree(leaves.clone(), HASH_VERSION_V1);
 
         // 5 leaves -> padded to 8 -> tree size is 16
         assert_eq!(merkle_tree.len(), 16);
@@ -517,7 +729,7 @@ mod tests {
 
         // Build tree with correct leaf
         let leaves = vec![correct_leaf];
-        let merkle_tree = construct_merkle_tree(leaves);
+        let merkle_tree = construct_merkle_tree(leaves, HASH_VERSION_V1);
         let root = merkle_tree[1];
         let leaf_offset = merkle_tree.len() / 2;
         let proof = create_proof(&merkle_tree, leaf_offset);
@@ -560,4 +772,227 @@ mod tests {
         assert_eq!(min_power_of_2(9), 4); // 2^4 = 16
         assert_eq!(min_power_of_2(100), 7); // 2^7 = 128
     }
-}   
+
+    #[test]
+    fn test_v2_merkle_tree_uses_prefixed_hashes() {
+        let leaves = vec![
+            leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_BASE58, &pubkey("31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS"), None, 20),
+            leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_BASE58, &pubkey("4wcdH4iueQSMGV4JeJGbfM7wD8ZvVfMCQC3RgautKMG1"), None, 40),
+        ];
+        let merkle_tree = construct_merkle_tree(leaves.clone(), HASH_VERSION_V2);
+
+        let mut expected_leaf = vec![0x00];
+        expected_leaf.extend_from_slice(b"31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS");
+        expected_leaf.extend_from_slice(&20u64.to_le_bytes());
+        assert_eq!(leaves[0], hash(&expected_leaf));
+
+        let expected_root = hash(&[&[0x01][..], &leaves[0], &leaves[1]].concat());
+        assert_eq!(merkle_tree[1], expected_root);
+        assert_ne!(merkle_tree[1], hash_children(&leaves[0], &leaves[1]));
+    }
+
+    #[test]
+    fn test_v2_leaf_differs_from_v1() {
+        let address = "31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS";
+        assert_ne!(
+            leaf_hash(HASH_VERSION_V1, LEAF_ENCODING_BASE58, &pubkey(address), None, 20),
+            leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_BASE58, &pubkey(address), None, 20)
+        );
+        assert_eq!(
+            leaf_hash(HASH_VERSION_V1, LEAF_ENCODING_BASE58, &pubkey(address), None, 20),
+            hash(&[address.as_bytes(), &20u64.to_le_bytes()].concat())
+        );
+    }
+
+    #[test]
+    fn test_pubkey_leaf_hashes_raw_bytes() {
+        let address = pubkey("31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS");
+        let expected = hash(&[&[0x00][..], address.as_ref(), &20u64.to_le_bytes()].concat());
+        assert_eq!(leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, &address, None, 20), expected);
+        assert_ne!(
+            leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, &address, None, 20),
+            leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_BASE58, &address, None, 20)
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_invalid_address() {
+        let dir = std::env::temp_dir().join(format!("airdrop-csv-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let csv_path = dir.join("bad.csv");
+        std::fs::write(
+            &csv_path,
+            "address,amount\n31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS,10\nnot-a-pubkey,20\n",
+        )
+        .unwrap();
+
+        let err = parse_airdrop_csv(&csv_path, HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, true)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("invalid address 'not-a-pubkey' on row 3"));
+
+        // Link mode treats the column as a label, so anything goes
+        let (leaves, labels, _, _) =
+            parse_airdrop_csv(&csv_path, HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, false).unwrap();
+        assert!(leaves.is_empty());
+        assert_eq!(labels[1], "not-a-pubkey");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_duplicate_address() {
+        let dir = std::env::temp_dir().join(format!("airdrop-dup-csv-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let alice = "31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS";
+        let csv_path = dir.join("dup.csv");
+        std::fs::write(&csv_path, format!("address,amount\n{alice},10\n{alice},20\n")).unwrap();
+
+        let err = parse_airdrop_csv(&csv_path, HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, true)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("appears twice (row 3)"));
+
+        // Each link gets its own key, so labels may repeat
+        let (_, labels, _, _) =
+            parse_airdrop_csv(&csv_path, HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, false).unwrap();
+        assert_eq!(labels, [alice, alice]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_multi_mint_leaf_commits_to_mint() {
+        let address = pubkey("31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS");
+        let mint = pubkey("4wcdH4iueQSMGV4JeJGbfM7wD8ZvVfMCQC3RgautKMG1");
+        let expected =
+            hash(&[&[0x00][..], address.as_ref(), mint.as_ref(), &20u64.to_le_bytes()].concat());
+        assert_eq!(
+            leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, &address, Some(&mint), 20),
+            expected
+        );
+        assert_ne!(
+            leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, &address, Some(&mint), 20),
+            leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, &address, Some(&address), 20)
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_mint_column() {
+        let dir = std::env::temp_dir().join(format!("airdrop-mint-csv-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let alice = "31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS";
+        let usdc = "4wcdH4iueQSMGV4JeJGbfM7wD8ZvVfMCQC3RgautKMG1";
+        let bonk = "7N3h2Zp4i9DzRbRGjtJHnRXnUbjKxLpsCnxmz7RLS1qZ";
+
+        let csv_path = dir.join("mints.csv");
+        std::fs::write(
+            &csv_path,
+            format!("address,amount,mint\n{alice},10,{usdc}\n{alice},20,{bonk}\n"),
+        )
+        .unwrap();
+        let (leaves, addresses, mints, _) =
+            parse_airdrop_csv(&csv_path, HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, true).unwrap();
+        assert_eq!(addresses, [alice, alice]);
+        assert_eq!(mints, [Some(pubkey(usdc)), Some(pubkey(bonk))]);
+        assert_eq!(
+            leaves[1],
+            leaf_hash(HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, &pubkey(alice), Some(&pubkey(bonk)), 20)
+        );
+
+        // One leaf per (recipient, mint), since receipts are keyed that way
+        std::fs::write(
+            &csv_path,
+            format!("address,amount,mint\n{alice},10,{usdc}\n{alice},20,{usdc}\n"),
+        )
+        .unwrap();
+        let err = parse_airdrop_csv(&csv_path, HASH_VERSION_V2, LEAF_ENCODING_PUBKEY, true)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("twice (row 3)"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_leaf_encoding() {
+        assert_eq!(parse_leaf_encoding("pubkey").unwrap(), LEAF_ENCODING_PUBKEY);
+        assert_eq!(parse_leaf_encoding("base58").unwrap(), LEAF_ENCODING_BASE58);
+        assert!(parse_leaf_encoding("hex").is_err());
+    }
+
+    #[test]
+    fn test_keccak_matches_known_vector() {
+        assert_eq!(
+            hex::encode(keccak(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+
+    #[test]
+    fn test_keccak_leaf_is_double_hashed_abi_encoding() {
+        let address = pubkey("31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS");
+        let mut encoded = address.to_bytes().to_vec();
+        encoded.extend_from_slice(&[0u8; 24]);
+        encoded.extend_from_slice(&20u64.to_be_bytes());
+        assert_eq!(
+            leaf_hash(HASH_VERSION_KECCAK, LEAF_ENCODING_PUBKEY, &address, None, 20),
+            keccak(&keccak(&encoded))
+        );
+    }
+
+    #[test]
+    fn test_keccak_proofs_verify_without_leaf_index() {
+        let addresses = [
+            "31HrWnNNM3QvZYNqN2F1CqWE2iiYfCV1pvLvTeZwyHBS",
+            "4wcdH4iueQSMGV4JeJGbfM7wD8ZvVfMCQC3RgautKMG1",
+            "7N3h2Zp4i9DzRbRGjtJHnRXnUbjKxLpsCnxmz7RLS1qZ",
+        ];
+        let leaves: Vec<[u8; 32]> = addresses
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                leaf_hash(HASH_VERSION_KECCAK, LEAF_ENCODING_PUBKEY, &pubkey(a), None, i as u64 + 1)
+            })
+            .collect();
+        let tree = construct_merkle_tree(leaves.clone(), HASH_VERSION_KECCAK);
+        let leaf_offset = tree.len() / 2;
+
+        // MerkleProof.verify: fold the proof with commutative hashing, no index needed
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = create_proof(&tree, leaf_offset + i);
+            let root = proof
+                .iter()
+                .fold(*leaf, |hash, sibling| node_hash(HASH_VERSION_KECCAK, &hash, sibling));
+            assert_eq!(root, tree[1]);
+        }
+        assert_eq!(
+            node_hash(HASH_VERSION_KECCAK, &leaves[0], &leaves[1]),
+            node_hash(HASH_VERSION_KECCAK, &leaves[1], &leaves[0])
+        );
+    }
+
+    #[test]
+    fn test_keccak_tree_matches_openzeppelin_root() {
+        // abi.encode pads an EVM address to a 32-byte word, so these keys hash like the two
+        // rows of the example in OpenZeppelin's merkle-tree README
+        let evm_address = |byte: u8| {
+            let mut key = [0u8; 32];
+            key[12..].fill(byte);
+            Pubkey::new_from_array(key)
+        };
+        let rows = [(0x11, 5_000_000_000_000_000_000), (0x22, 2_500_000_000_000_000_000)];
+        let leaves = rows
+            .iter()
+            .map(|(byte, amount)| {
+                leaf_hash(HASH_VERSION_KECCAK, LEAF_ENCODING_PUBKEY, &evm_address(*byte), None, *amount)
+            })
+            .collect();
+        let tree = construct_merkle_tree(leaves, HASH_VERSION_KECCAK);
+        assert_eq!(
+            hex::encode(tree[1]),
+            "d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77"
+        );
+    }
+
+    fn pubkey(address: &str) -> Pubkey {
+        Pubkey::from_str(address).unwrap()
+    }
+}
@@ -0,0 +1,41 @@
+use airdrop_contract::constants::{HASH_VERSION_V2, LEAF_ENCODING_PUBKEY};
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anyhow::Result;
+use std::{collections::BTreeMap, path::Path, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+
+/// Write an airdrop.json for a voucher airdrop. There is no tree: a random id
+/// stands in for the merkle root, and claims are authorized by vouchers signed by `signer`.
+pub fn create_voucher_airdrop(signer: &str, amount: u64) -> Result<()> {
+    let signer = Pubkey::from_str(signer)
+        .map_err(|e| anyhow::anyhow!("Invalid voucher signer {}: {}", signer, e))?;
+    if amount == 0 {
+        anyhow::bail!("Voucher airdrop amount must be greater than zero");
+    }
+
+    let airdrop_id: [u8; 32] = rand::random();
+    let airdrop_data = AirdropJson {
+        merkle_root: hex::encode(airdrop_id),
+        merkle_tree: Vec::new(),
+        claims: BTreeMap::new(),
+        mint_claims: BTreeMap::new(),
+        mint: None,
+        sol: false,
+        mint_on_claim: false,
+        hash_version: HASH_VERSION_V2,
+        leaf_encoding: LEAF_ENCODING_PUBKEY,
+        initial_merkle_root: None,
+        creator: None,
+        nonce: None,
+        voucher_signer: Some(signer.to_string()),
+        voucher_amount: Some(amount.to_string()),
+    };
+    airdrop_data.write(Path::new("airdrop.json"))?;
+
+    println!("Voucher airdrop id: {}", airdrop_data.merkle_root);
+    println!("Voucher signer: {}", signer);
+    println!("Amount: {}", amount);
+    println!("Wrote airdrop.json - deploy it with deploy-airdrop, then hand out vouchers with issue-voucher");
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use airdrop_contract::events::{
+    AirdropClosed, AirdropCreated, AirdropFunded, ClaimBitmapClosed, ClaimBitmapInitialized,
+    ClaimReceiptClosed, Claimed, ClawedBack, ConfigUpdated, MetadataClosed, MetadataUpdated,
+    PauseSet, ProtocolFeePaid, RootUpdated, VaultClosed, VoucherClaimed,
+};
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_request::RpcRequest;
+use std::str::FromStr;
+
+use crate::instructions::deploy_airdrop::Network;
+
+#[derive(Debug)]
+pub struct DecodeEventsArgs {
+    pub signature: String,
+    pub network: Network,
+    pub program_id: String,
+}
+
+/// How an event field is written out: addresses in base58, hashes in hex
+trait EventField {
+    fn to_json(&self) -> Value;
+}
+
+impl EventField for Pubkey {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl EventField for [u8; 32] {
+    fn to_json(&self) -> Value {
+        Value::String(hex::encode(self))
+    }
+}
+
+impl<T: EventField> EventField for Option<T> {
+    fn to_json(&self) -> Value {
+        self.as_ref().map_or(Value::Null, EventField::to_json)
+    }
+}
+
+macro_rules! impl_event_field {
+    ($($ty:ty),*) => {
+        $(impl EventField for $ty {
+            fn to_json(&self) -> Value {
+                json!(self)
+            }
+        })*
+    };
+}
+
+impl_event_field!(bool, u16, u32, u64, i64, String);
+
+macro_rules! airdrop_events {
+    ($($event:ident { $($field:ident),* $(,)? }),* $(,)?) => {
+        /// An event emitted by the airdrop program
+        #[derive(Debug)]
+        pub enum AirdropEvent {
+            $($event($event),)*
+        }
+
+        impl AirdropEvent {
+            /// Decode the payload of a `Program data:` log line; `None` for anything that is
+            /// not one of the program's events
+            pub fn decode(data: &[u8]) -> Option<Self> {
+                $(if let Some(mut fields) = data.strip_prefix($event::DISCRIMINATOR) {
+                    return $event::deserialize(&mut fields).ok().map(Self::$event);
+                })*
+                None
+            }
+
+            pub fn to_json(&self) -> Value {
+                match self {
+                    $(Self::$event(event) => json!({
+                        "event": stringify!($event),
+                        $(stringify!($field): event.$field.to_json(),)*
+                    }),)*
+                }
+            }
+        }
+    };
+}
+
+airdrop_events! {
+    AirdropCreated { merkle_root, root, mint, authority, amount },
+    Claimed { merkle_root, root, claimant, mint, amount, leaf_index, timestamp },
+    VoucherClaimed { merkle_root, recipient, mint, amount, nonce, timestamp },
+    ClaimBitmapInitialized { merkle_root, index },
+    AirdropFunded { merkle_root, mint, funder, amount },
+    RootUpdated { merkle_root, root, epoch, amount },
+    PauseSet { merkle_root, paused },
+    ClawedBack { merkle_root, mint, amount, destination },
+    VaultClosed { merkle_root, mint },
+    AirdropClosed { merkle_root, authority },
+    ClaimReceiptClosed { merkle_root, claimant, payer },
+    ClaimBitmapClosed { merkle_root, index },
+    ConfigUpdated { config, admin, fee_recipient, funded_fee_bps, claim_fee_lamports },
+    ProtocolFeePaid { merkle_root, payer, fee_recipient, mint, amount },
+    MetadataUpdated { merkle_root, metadata, name },
+    MetadataClosed { merkle_root, metadata },
+}
+
+/// Events `program_id` emitted, in order, given a transaction's log messages.
+///
+/// `emit!` logs each event as `Program data: <base64>`. Only lines logged while `program_id`
+/// is the innermost running program count, so data logged by programs it calls, or by other
+/// programs in the transaction, is skipped.
+pub fn parse_events(logs: &[String], program_id: &Pubkey) -> Vec<AirdropEvent> {
+    let program_id = program_id.to_string();
+    let mut call_stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs {
+        let Some(rest) = line.strip_prefix("Program ") else {
+            continue;
+        };
+        if let Some(data) = rest.strip_prefix("data: ") {
+            if call_stack.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+            let event = STANDARD.decode(data).ok().and_then(|data| AirdropEvent::decode(&data));
+            events.extend(event);
+        } else if let Some((program, status)) = rest.split_once(' ') {
+            if status.starts_with("invoke [") {
+                call_stack.push(program);
+            } else if status == "success" || status.starts_with("failed") {
+                call_stack.pop();
+            }
+        }
+    }
+
+    events
+}
+
+/// Log messages of a confirmed transaction, and whether it failed
+fn fetch_transaction_logs(rpc_client: &RpcClient, signature: &str) -> Result<(Vec<String>, bool)> {
+    let transaction: Value = rpc_client.send(
+        RpcRequest::GetTransaction,
+        json!([
+            signature,
+            {
+                "encoding": "json",
+                "commitment": "confirmed",
+                "maxSupportedTransactionVersion": 0,
+            }
+        ]),
+    )?;
+    if transaction.is_null() {
+        anyhow::bail!("Transaction {} not found", signature);
+    }
+
+    let meta = &transaction["meta"];
+    let logs = meta["logMessages"]
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("Transaction {} has no log messages", signature))?
+        .iter()
+        .filter_map(|line| line.as_str().map(String::from))
+        .collect();
+    Ok((logs, !meta["err"].is_null()))
+}
+
+pub fn decode_events(args: DecodeEventsArgs) -> Result<()> {
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+
+    let (logs, failed) = fetch_transaction_logs(&rpc_client, &args.signature)?;
+    if failed {
+        // A failed transaction's state changes were rolled back, whatever it logged
+        eprintln!("Transaction {} failed; its events did not take effect", args.signature);
+    }
+
+    let events = parse_events(&logs, &program_id);
+    if events.is_empty() {
+        eprintln!("No airdrop events in transaction {}", args.signature);
+    }
+    // One JSON object per line, for piping into other tools
+    for event in events {
+        println!("{}", event.to_json());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Event;
+
+    fn data_line(event: &impl Event) -> String {
+        format!("Program data: {}", STANDARD.encode(event.data()))
+    }
+
+    fn claimed() -> Claimed {
+        Claimed {
+            merkle_root: Pubkey::new_unique(),
+            root: [7; 32],
+            claimant: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 1_000,
+            leaf_index: 5,
+            timestamp: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_events_are_attributed_to_the_running_program() {
+        let program_id = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let event = claimed();
+        let logs = vec![
+            format!("Program {} invoke [1]", other),
+            data_line(&event),
+            format!("Program {} success", other),
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: Claim".to_string(),
+            format!("Program {} invoke [2]", other),
+            data_line(&event),
+            format!("Program {} success", other),
+            data_line(&event),
+            format!("Program {} consumed 30000 of 200000 compute units", program_id),
+            format!("Program {} success", program_id),
+        ];
+
+        let events = parse_events(&logs, &program_id);
+        assert_eq!(events.len(), 1);
+        let AirdropEvent::Claimed(decoded) = &events[0] else {
+            panic!("Expected a Claimed event, got {:?}", events[0]);
+        };
+        assert_eq!(decoded.claimant, event.claimant);
+        assert_eq!(decoded.leaf_index, 5);
+    }
+
+    #[test]
+    fn test_unknown_data_is_skipped() {
+        let program_id = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            "Program data: AAAAAAAAAAA=".to_string(),
+            "Program data: not base64!".to_string(),
+            format!("Program {} failed: custom program error: 0x1", program_id),
+        ];
+        assert!(parse_events(&logs, &program_id).is_empty());
+    }
+
+    #[test]
+    fn test_event_json() {
+        let event = claimed();
+        let value = AirdropEvent::Claimed(event).to_json();
+        assert_eq!(value["event"], "Claimed");
+        assert_eq!(value["root"], hex::encode([7u8; 32]));
+        assert_eq!(value["amount"], 1_000);
+
+        let clawback = AirdropEvent::ClawedBack(ClawedBack {
+            merkle_root: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 0,
+            destination: None,
+        });
+        assert!(clawback.to_json()["destination"].is_null());
+    }
+}
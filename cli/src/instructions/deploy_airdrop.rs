@@ -1,41 +1,46 @@
-use airdrop_contract::accounts::CreateAirdrop;
+use airdrop_contract::accounts::{
+    CreateAirdrop, CreateMintAirdrop, CreateSolAirdrop, FundAirdrop, InitClaimBitmap,
+};
+use airdrop_contract::constants::{CLAIM_BITMAP_BITS, HASH_VERSION_KECCAK};
+use airdrop_contract::instructions::CreateAirdropParams;
+use airdrop_contract::state::{ClaimGate, MerkleRoot, MetadataParams, VestingSchedule};
 use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
 use anchor_client::solana_sdk::pubkey::Pubkey;
-use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
 use anchor_client::solana_sdk::transaction::Transaction;
 #[allow(deprecated)]
 use solana_sdk::system_program;
-use anchor_client::Client;
-use anchor_client::Cluster;
-use anchor_spl::associated_token::get_associated_token_address;
+use anchor_client::{Client, Cluster, Program};
+use anchor_lang::AccountDeserialize;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use spl_token_2022::instruction::AuthorityType;
 use anyhow::{Context, Result};
-use serde::{Deserialize, Serialize};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::program_pack::Pack;
 #[allow(deprecated)]
 use solana_sdk::system_instruction;
 use spl_token::state::Mint;
-use std::{fs::File, path::PathBuf, rc::Rc, str::FromStr};
+use std::{
+    fs::File,
+    path::PathBuf,
+    rc::Rc,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::instructions::airdrop_json::{closed_airdrop_pda, AirdropJson};
+use crate::instructions::airdrop_metadata::publish_metadata;
+use crate::instructions::claim_status::claim_bitmap_pda;
+use crate::instructions::program_config::{config_pda, fetch_config, FundingFee};
+use crate::instructions::token_mint::{current_epoch, max_installments, TokenMint};
 
 // Default program ID from Anchor.toml
 const DEFAULT_PROGRAM_ID: &str = "F6fHBUyYyaW14CxjSnJjLck8vMmWew3PbCnt5TMqRdZX";
 const MINT_DECIMALS: u8 = 6;
-
-#[derive(Deserialize, Serialize)]
-struct ClaimInfo {
-    amount: String,
-    leaf_index: u64,
-}
-
-#[derive(Deserialize, Serialize)]
-struct AirdropJson {
-    merkle_root: String,
-    merkle_tree: Vec<String>,
-    claims: std::collections::BTreeMap<String, ClaimInfo>,
-    #[serde(default)]
-    mint: Option<String>,
-}
+// Airdrops expire 30 days after deployment unless --expires-at is given
+const DEFAULT_EXPIRY_SECS: i64 = 30 * 24 * 60 * 60;
 
 #[derive(Clone, Copy, Debug)]
 pub enum Network {
@@ -77,7 +82,7 @@ impl Network {
         }
     }
 
-    fn rpc_url(&self) -> &'static str {
+    pub fn rpc_url(&self) -> &'static str {
         match self {
             Network::Devnet => "https://api.devnet.solana.com",
             Network::Testnet => "https://api.testnet.solana.com",
@@ -94,6 +99,29 @@ pub struct DeployAirdropArgs {
     pub network: Network,
     pub program_id: String,
     pub keypair_path: PathBuf,
+    pub expires_at: Option<i64>,
+    pub claim_start: Option<i64>,
+    pub claim_end: Option<i64>,
+    pub vesting_start: Option<i64>,
+    pub vesting_cliff: Option<i64>,
+    pub vesting_end: Option<i64>,
+    pub cumulative: bool,
+    /// Track claims in bitmap accounts allocated here instead of per-claimer receipts
+    pub claim_bitmap: bool,
+    /// Lets one creator deploy the same root more than once
+    pub nonce: u64,
+    /// Airdrop native SOL: amounts are lamports held by the merkle root account
+    pub sol: bool,
+    /// Hand the mint authority to the merkle root and mint each claim instead of funding a vault
+    pub mint_on_claim: bool,
+    /// Create the new mint under Token-2022 rather than SPL Token
+    pub token_2022: bool,
+    /// Name, image and links to publish for wallets and apps
+    pub metadata: Option<MetadataParams>,
+    /// Only pay claimants holding this mint when they claim
+    pub gate_mint: Option<String>,
+    /// Base units of the gate mint a claimant must hold (defaults to 1)
+    pub gate_min_balance: Option<u64>,
 }
 
 pub fn deploy_airdrop(args: DeployAirdropArgs) -> Result<()> {
@@ -121,24 +149,142 @@ pub fn deploy_airdrop(args: DeployAirdropArgs) -> Result<()> {
         )
     })?;
 
+    if airdrop_data.initial_merkle_root.is_some() {
+        anyhow::bail!(
+            "{:?} is a later epoch of a cumulative airdrop. Publish it with update-root instead.",
+            args.json_path
+        );
+    }
+
+    if airdrop_data.is_legacy() {
+        anyhow::bail!(
+            "{:?} was deployed by the program's first release; redeploying it would pay every \
+            recipient again. Claim, claw back and close it as it is.",
+            args.json_path
+        );
+    }
+
+    // Multi-mint airdrops are created with their first mint and fund the rest afterwards
+    let mint_totals = airdrop_data.mint_totals()?;
+    if !mint_totals.is_empty() {
+        if args.mint.is_some() {
+            anyhow::bail!("Multi-mint airdrops take their mints from the CSV; drop --mint");
+        }
+        if args.cumulative {
+            anyhow::bail!("--cumulative is not supported for multi-mint airdrops");
+        }
+    }
+
     // Calculate total amount from claims
-    let total_amount: u64 = airdrop_data
-        .claims
-        .values()
-        .map(|claim| claim.amount.parse::<u64>().unwrap_or(0))
-        .sum();
-
-    let merkle_root_hash: [u8; 32] = hex::decode(&airdrop_data.merkle_root)
-        .with_context(|| "Invalid hex in merkle_root")?
-        .try_into()
-        .map_err(|_| anyhow::anyhow!("merkle_root must be 32 bytes"))?;
+    let total_amount = match mint_totals.values().next() {
+        Some(total) => *total,
+        None => airdrop_data.total_amount()?,
+    };
+
+    let merkle_root_hash = airdrop_data.merkle_root_hash()?;
+    let voucher_signer = airdrop_data.voucher_signer()?;
     let program_id = Pubkey::from_str(&args.program_id)?;
+    let vesting = vesting_schedule(&args)?;
+    // Voucher airdrops have no tree to count
+    let num_leaves = match voucher_signer {
+        Some(_) => 0,
+        None => airdrop_data.leaf_count()?,
+    };
+    let bitmap_leaf_count = if args.claim_bitmap {
+        if args.cumulative || vesting.is_some() || voucher_signer.is_some() || !mint_totals.is_empty() {
+            anyhow::bail!(
+                "--claim-bitmap only supports one-shot, single-mint merkle airdrops without vesting"
+            );
+        }
+        if airdrop_data.hash_version == HASH_VERSION_KECCAK {
+            anyhow::bail!(
+                "--claim-bitmap needs leaf indices bound by the proof, which keccak trees do not provide"
+            );
+        }
+        Some(num_leaves)
+    } else {
+        None
+    };
+    if args.sol
+        && (!mint_totals.is_empty() || args.cumulative || args.claim_bitmap || voucher_signer.is_some())
+    {
+        anyhow::bail!("--sol airdrops cannot be multi-mint, cumulative, voucher or bitmap airdrops");
+    }
+    if args.mint_on_claim
+        && (!mint_totals.is_empty() || args.cumulative || args.claim_bitmap || voucher_signer.is_some())
+    {
+        anyhow::bail!(
+            "--mint-on-claim airdrops cannot be multi-mint, cumulative, voucher or bitmap airdrops"
+        );
+    }
+    // Default expiry never cuts a claim window short
+    let expires_at = match args.expires_at {
+        Some(expires_at) => expires_at,
+        None => (unix_timestamp_now()? + DEFAULT_EXPIRY_SECS).max(args.claim_end.unwrap_or(0)),
+    };
 
     println!("Merkle root: {}", airdrop_data.merkle_root);
     println!("Network: {}", args.network.name());
     println!("Program ID: {}", program_id);
-    println!("Total amount (from claims): {}", total_amount);
-    println!("Number of recipients: {}", airdrop_data.claims.len());
+    match voucher_signer {
+        Some(signer) => {
+            println!("Total amount (voucher deposit): {}", total_amount);
+            println!("Voucher signer: {}", signer);
+        }
+        None if !mint_totals.is_empty() => {
+            for (mint, total) in &mint_totals {
+                println!("Total amount of {} (from claims): {}", mint, total);
+            }
+            println!("Number of recipients: {}", airdrop_data.mint_claims.len());
+        }
+        None if args.sol => {
+            println!("Total amount (lamports, from claims): {}", total_amount);
+            println!("Number of recipients: {}", airdrop_data.claims.len());
+        }
+        None if args.mint_on_claim => {
+            println!("Total amount (minted on claim, from claims): {}", total_amount);
+            println!("Number of recipients: {}", airdrop_data.claims.len());
+        }
+        None => {
+            println!("Total amount (from claims): {}", total_amount);
+            println!("Number of recipients: {}", airdrop_data.claims.len());
+        }
+    }
+    println!("Expires at (unix): {}", expires_at);
+    if args.cumulative {
+        println!("Cumulative: new roots can be published with update-root");
+    }
+    if let Some(leaf_count) = bitmap_leaf_count {
+        println!("Claim bitmap: {} leaves", leaf_count);
+    }
+    if let Some(start) = args.claim_start {
+        println!("Claims open at (unix): {}", start);
+    }
+    if let Some(end) = args.claim_end {
+        println!("Claims close at (unix): {}", end);
+    }
+    if let Some(vesting) = &vesting {
+        println!(
+            "Vesting (unix): start {}, cliff {}, end {}",
+            vesting.start, vesting.cliff, vesting.end
+        );
+    }
+    let gate = args
+        .gate_mint
+        .as_deref()
+        .map(|mint| -> Result<ClaimGate> {
+            Ok(ClaimGate {
+                mint: Pubkey::from_str(mint)?,
+                min_balance: args.gate_min_balance.unwrap_or(1),
+            })
+        })
+        .transpose()?;
+    if let Some(gate) = &gate {
+        if gate.min_balance == 0 {
+            anyhow::bail!("--gate-min-balance must be at least 1");
+        }
+        println!("Gated: claimants must hold at least {} of {}", gate.min_balance, gate.mint);
+    }
 
     // Load keypair
     let payer = read_keypair_file(&args.keypair_path)
@@ -152,14 +298,68 @@ pub fn deploy_airdrop(args: DeployAirdropArgs) -> Result<()> {
         CommitmentConfig::confirmed(),
     );
 
+    let params = CreateAirdropParams {
+        expires_at,
+        claim_start: args.claim_start,
+        claim_end: args.claim_end,
+        vesting,
+        cumulative: args.cumulative,
+        hash_version: airdrop_data.hash_version,
+        leaf_encoding: airdrop_data.leaf_encoding,
+        voucher_signer,
+        bitmap_leaf_count,
+        nonce: args.nonce,
+        multi_mint: !mint_totals.is_empty(),
+        num_leaves,
+        gate,
+    };
+    if args.sol {
+        return deploy_sol_airdrop(&args, &payer, airdrop_data, total_amount, params);
+    }
+    if args.mint_on_claim {
+        return deploy_mint_airdrop(&args, &payer, &rpc_client, airdrop_data, total_amount, params);
+    }
+
+    let (program, merkle_root_pda) = deploy_target(&args, &payer, &mut airdrop_data)?;
+    let authority = program.payer();
+    println!("Merkle root PDA: {} (nonce {})", merkle_root_pda, args.nonce);
+
+    // A deploy interrupted after its create transaction leaves the merkle root behind; re-running
+    // it carries on with the vaults it did not get to fund instead of creating it again
+    let created = fetch_merkle_root(&rpc_client, &merkle_root_pda)?;
+    if let Some(merkle_root) = &created {
+        if merkle_root.native_sol || merkle_root.mint_on_claim {
+            anyhow::bail!("{} already holds a SOL or mint-on-claim airdrop", merkle_root_pda);
+        }
+        if let Some(mint) = &args.mint {
+            if Pubkey::from_str(mint)? != merkle_root.mint {
+                anyhow::bail!(
+                    "{} was created with mint {}, not {}",
+                    merkle_root_pda,
+                    merkle_root.mint,
+                    mint
+                );
+            }
+        }
+        println!("\nMerkle root already created; resuming the deploy");
+    }
+
     // Determine mint - either use provided or create new one
-    let (mint_pubkey, mint_keypair) = match &args.mint {
-        Some(mint_str) => {
+    let (mint_pubkey, mint_keypair) = match (&created, &args.mint, mint_totals.keys().next()) {
+        (Some(merkle_root), _, _) => {
+            println!("Using the airdrop's mint: {}", merkle_root.mint);
+            (merkle_root.mint, None)
+        }
+        (None, _, Some(mint)) => {
+            println!("Using first airdrop mint: {}", mint);
+            (*mint, None)
+        }
+        (None, Some(mint_str), None) => {
             let mint = Pubkey::from_str(mint_str)?;
             println!("Using existing mint: {}", mint);
             (mint, None)
         }
-        None => {
+        (None, None, None) => {
             println!("\nNo mint provided, creating new token mint...");
             let mint_kp = Keypair::new();
             println!("New mint address: {}", mint_kp.pubkey());
@@ -167,32 +367,20 @@ pub fn deploy_airdrop(args: DeployAirdropArgs) -> Result<()> {
         }
     };
 
-    // Create Anchor client
-    let client = Client::new_with_options(
-        args.network.to_cluster(),
-        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
-        CommitmentConfig::confirmed(),
-    );
-    let program = client.program(program_id)?;
-
-    // Derive PDAs
-    let (merkle_root_pda, _bump) = Pubkey::find_program_address(
-        &[b"merkle_root", &merkle_root_hash],
-        &program_id,
-    );
-
-    let authority = program.payer();
-    let authority_token_account = get_associated_token_address(&authority, &mint_pubkey);
-    let merkle_root_token_account = get_associated_token_address(&merkle_root_pda, &mint_pubkey);
-
-    println!("Merkle root PDA: {}", merkle_root_pda);
-    println!("Authority token account: {}", authority_token_account);
-    println!("Merkle root token account: {}", merkle_root_token_account);
+    airdrop_data.mint = Some(mint_pubkey.to_string());
 
     // If we need to create a mint, do it in a separate transaction first
     if let Some(ref mint_kp) = mint_keypair {
         println!("\nCreating mint and minting tokens...");
-
+        // Mint enough for the protocol fee on the deposit as well
+        let fee = fetch_config(&rpc_client, &program_id)?
+            .map_or(0, |config| config.fee.funding_fee(total_amount));
+
+        let token_program = if args.token_2022 {
+            spl_token_2022::ID
+        } else {
+            spl_token::ID
+        };
         let rent = rpc_client.get_minimum_balance_for_rent_exemption(Mint::LEN)?;
 
         // Create mint account
@@ -201,12 +389,12 @@ pub fn deploy_airdrop(args: DeployAirdropArgs) -> Result<()> {
             &mint_kp.pubkey(),
             rent,
             Mint::LEN as u64,
-            &spl_token::ID,
+            &token_program,
         );
 
         // Initialize mint
-        let init_mint_ix = spl_token::instruction::initialize_mint(
-            &spl_token::ID,
+        let init_mint_ix = spl_token_2022::instruction::initialize_mint(
+            &token_program,
             &mint_kp.pubkey(),
             &payer.pubkey(),
             Some(&payer.pubkey()),
@@ -218,17 +406,21 @@ pub fn deploy_airdrop(args: DeployAirdropArgs) -> Result<()> {
             &payer.pubkey(),
             &payer.pubkey(),
             &mint_kp.pubkey(),
-            &spl_token::ID,
+            &token_program,
         );
 
         // Mint tokens to authority
-        let mint_to_ix = spl_token::instruction::mint_to(
-            &spl_token::ID,
+        let mint_to_ix = spl_token_2022::instruction::mint_to(
+            &token_program,
             &mint_kp.pubkey(),
-            &authority_token_account,
+            &get_associated_token_address_with_program_id(
+                &payer.pubkey(),
+                &mint_kp.pubkey(),
+                &token_program,
+            ),
             &payer.pubkey(),
             &[],
-            total_amount,
+            total_amount + fee,
         )?;
 
         let recent_blockhash = rpc_client.get_latest_blockhash()?;
@@ -243,45 +435,432 @@ pub fn deploy_airdrop(args: DeployAirdropArgs) -> Result<()> {
         println!("Mint created: {}", sig);
     }
 
-    println!("\nSending create airdrop transaction...");
+    // Token-2022 transfer fees are charged on the deposit and again on every claim, so the
+    // deposit is grossed up until each recipient still receives their full allocation
+    let epoch = current_epoch(&rpc_client)?;
+    let mut deposits = Vec::new();
+    let mint_addresses: Vec<Pubkey> = if mint_totals.is_empty() {
+        vec![mint_pubkey]
+    } else {
+        mint_totals.keys().copied().collect()
+    };
+    for mint in &mint_addresses {
+        let token_mint = TokenMint::fetch(&rpc_client, mint)?;
+        let vault = token_mint.associated_token_address(&merkle_root_pda);
+        if created.is_some() && account_exists(&rpc_client, &vault)? {
+            println!("Vault {} for {} already funded", vault, mint);
+            continue;
+        }
+        let deposit = token_mint.funding_amount(
+            airdrop_data.payout_amounts(mint)?,
+            max_installments(vesting.as_ref()),
+            epoch,
+        )?;
+        if token_mint.token_program == spl_token_2022::ID {
+            println!("Token-2022 mint {}: depositing {} to cover transfer fees", mint, deposit);
+        }
+
+        // Every mint must be fully funded, protocol fee included, before anything is created
+        let token_account = token_mint.associated_token_address(&authority);
+        let funding_fee =
+            FundingFee::fetch(&rpc_client, &program_id, &token_mint, &token_account, deposit)?;
+        if funding_fee.fee > 0 {
+            println!("Protocol fee on {}: {}", mint, funding_fee.fee);
+        }
+        let balance: u64 = rpc_client
+            .get_token_account_balance(&token_account)
+            .with_context(|| format!("No token account {} for mint {}", token_account, mint))?
+            .amount
+            .parse()?;
+        if balance < deposit + funding_fee.fee {
+            anyhow::bail!(
+                "Authority token account {} holds {} of {} but {} is needed",
+                token_account,
+                balance,
+                mint,
+                deposit + funding_fee.fee
+            );
+        }
+        deposits.push((token_mint, deposit, funding_fee));
+    }
+
+    // Create the airdrop with its first mint, unless an earlier run already did
+    let mut deposits = deposits.iter();
+    let signature = match &created {
+        Some(_) => None,
+        None => {
+            let (token_mint, deposit, funding_fee) =
+                deposits.next().context("No mint to create the airdrop with")?;
+            let authority_token_account = token_mint.associated_token_address(&authority);
+            let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+            println!("Authority token account: {}", authority_token_account);
+            println!("Merkle root token account: {}", merkle_root_token_account);
+
+            println!("\nSending create airdrop transaction...");
+
+            // Build and send transaction using the program's instruction
+            let mut request = program.request();
+            if let Some(ix) = funding_fee.create_account_instruction(&authority, token_mint) {
+                request = request.instruction(ix);
+            }
+            let signature = request
+                .accounts(CreateAirdrop {
+                    authority,
+                    authority_token_account,
+                    merkle_root_token_account,
+                    mint: mint_pubkey,
+                    merkle_root: merkle_root_pda,
+                    closed_airdrop: closed_airdrop_pda(&program_id, &merkle_root_pda),
+                    config: funding_fee.config,
+                    fee_recipient_token_account: funding_fee.fee_recipient_token_account,
+                    system_program: system_program::ID,
+                    token_program: token_mint.token_program,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                })
+                .accounts(token_mint.transfer_hook_accounts(
+                    &rpc_client,
+                    &authority_token_account,
+                    &merkle_root_token_account,
+                    &authority,
+                    *deposit,
+                )?)
+                .args(airdrop_contract::instruction::CreateAirdrop {
+                    merkle_root_hash,
+                    amount: *deposit,
+                    params,
+                })
+                .send()?;
+
+            // The vaults and bitmaps below are separate transactions, so record the creator and
+            // nonce now; re-running the deploy needs them to find the airdrop again
+            airdrop_data.write(&args.json_path)?;
+            Some(signature)
+        }
+    };
 
-    // Build and send transaction using the program's instruction
+    // The other mints' vaults are opened by funding them
+    let unfunded: Vec<_> = deposits.collect();
+    if !unfunded.is_empty() {
+        println!("\nFunding {} additional mint vault(s)...", unfunded.len());
+        for (token_mint, deposit, funding_fee) in unfunded {
+            let funder_token_account = token_mint.associated_token_address(&authority);
+            let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+            let mut request = program.request();
+            if let Some(ix) = funding_fee.create_account_instruction(&authority, token_mint) {
+                request = request.instruction(ix);
+            }
+            let sig = request
+                .accounts(FundAirdrop {
+                    funder: authority,
+                    funder_token_account,
+                    merkle_root_token_account,
+                    mint: token_mint.address,
+                    merkle_root: merkle_root_pda,
+                    vault_record: airdrop_data.vault_record_pda(&program_id, &token_mint.address)?,
+                    config: funding_fee.config,
+                    fee_recipient_token_account: funding_fee.fee_recipient_token_account,
+                    system_program: system_program::ID,
+                    token_program: token_mint.token_program,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                })
+                .accounts(token_mint.transfer_hook_accounts(
+                    &rpc_client,
+                    &funder_token_account,
+                    &merkle_root_token_account,
+                    &authority,
+                    *deposit,
+                )?)
+                .args(airdrop_contract::instruction::FundAirdrop { amount: *deposit })
+                .send()?;
+            println!("Funded {} with {} ({})", token_mint.address, deposit, sig);
+        }
+    }
+
+    // Allocate the bitmap accounts up front so claimants never pay rent
+    if let Some(leaf_count) = bitmap_leaf_count {
+        let bitmap_count = leaf_count.div_ceil(CLAIM_BITMAP_BITS);
+        println!("\nAllocating {} claim bitmap account(s)...", bitmap_count);
+        for index in 0..bitmap_count {
+            let claim_bitmap = claim_bitmap_pda(&program_id, &merkle_root_pda, index);
+            if created.is_some() && account_exists(&rpc_client, &claim_bitmap)? {
+                println!("Claim bitmap {}: {} (already allocated)", index, claim_bitmap);
+                continue;
+            }
+            let sig = program
+                .request()
+                .accounts(InitClaimBitmap {
+                    authority,
+                    merkle_root: merkle_root_pda,
+                    claim_bitmap,
+                    system_program: system_program::ID,
+                })
+                .args(airdrop_contract::instruction::InitClaimBitmap { index })
+                .send()?;
+            println!("Claim bitmap {}: {} ({})", index, claim_bitmap, sig);
+        }
+    }
+
+    finish_deploy(
+        &args,
+        &program,
+        merkle_root_pda,
+        signature,
+        Some(mint_pubkey),
+        &airdrop_data,
+        "mint address and creator",
+    )
+}
+
+/// Anchor client for the deploying keypair, and the airdrop's merkle root PDA. The PDA is
+/// namespaced by the deploying authority and nonce, which are recorded in `airdrop_data`.
+fn deploy_target(
+    args: &DeployAirdropArgs,
+    payer: &Keypair,
+    airdrop_data: &mut AirdropJson,
+) -> Result<(Program<Rc<Keypair>>, Pubkey)> {
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+
+    airdrop_data.creator = Some(program.payer().to_string());
+    airdrop_data.nonce = Some(args.nonce);
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+    Ok((program, merkle_root_pda))
+}
+
+/// The merkle root already at `address`, if an earlier deploy created it
+fn fetch_merkle_root(rpc_client: &RpcClient, address: &Pubkey) -> Result<Option<MerkleRoot>> {
+    rpc_client
+        .get_account_with_commitment(address, CommitmentConfig::confirmed())?
+        .value
+        .map(|account| Ok(MerkleRoot::try_deserialize(&mut account.data.as_slice())?))
+        .transpose()
+}
+
+fn account_exists(rpc_client: &RpcClient, address: &Pubkey) -> Result<bool> {
+    Ok(rpc_client
+        .get_account_with_commitment(address, CommitmentConfig::confirmed())?
+        .value
+        .is_some())
+}
+
+/// Report a successful create, write what was deployed back to the JSON file and publish any
+/// metadata. The file is written first so a failed metadata publish cannot lose the creator and
+/// nonce every later command needs; `update-metadata` can publish it again. `signature` is
+/// `None` when a resumed deploy found the airdrop already created.
+fn finish_deploy(
+    args: &DeployAirdropArgs,
+    program: &Program<Rc<Keypair>>,
+    merkle_root_pda: Pubkey,
+    signature: Option<Signature>,
+    mint: Option<Pubkey>,
+    airdrop_data: &AirdropJson,
+    recorded: &str,
+) -> Result<()> {
+    println!("\nAirdrop deployed successfully!");
+    if let Some(signature) = signature {
+        println!("Signature: {}", signature);
+    }
+    if let Some(mint) = mint {
+        println!("Mint: {}", mint);
+    }
+    if let Some(signature) = signature {
+        println!(
+            "Explorer: https://explorer.solana.com/tx/{}?cluster={}",
+            signature,
+            args.network.name()
+        );
+    }
+
+    airdrop_data.write(&args.json_path)?;
+    println!("\nUpdated {:?} with {}", args.json_path, recorded);
+
+    if let Some(metadata) = &args.metadata {
+        let signature = publish_metadata(program, merkle_root_pda, metadata)?;
+        println!("Metadata published: {} ({})", metadata.name, signature);
+    }
+
+    Ok(())
+}
+
+/// Create a SOL airdrop, whose merkle root account holds the lamports itself
+fn deploy_sol_airdrop(
+    args: &DeployAirdropArgs,
+    payer: &Keypair,
+    mut airdrop_data: AirdropJson,
+    total_amount: u64,
+    params: CreateAirdropParams,
+) -> Result<()> {
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let (program, merkle_root_pda) = deploy_target(args, payer, &mut airdrop_data)?;
+    let authority = program.payer();
+    println!("Merkle root PDA (vault): {} (nonce {})", merkle_root_pda, args.nonce);
+
+    // The protocol fee on SOL deposits is paid in lamports straight to the fee recipient
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let config = fetch_config(&rpc_client, &program_id)?;
+    let fee_recipient = config.as_ref().map_or(authority, |config| config.fee_recipient);
+    let fee = config.map_or(0, |config| config.fee.funding_fee(total_amount));
+    if fee > 0 {
+        println!("Protocol fee (lamports): {}", fee);
+    }
+
+    println!("\nSending create SOL airdrop transaction...");
     let signature = program
         .request()
-        .accounts(CreateAirdrop {
+        .accounts(CreateSolAirdrop {
             authority,
-            authority_token_account,
-            merkle_root_token_account,
-            mint: mint_pubkey,
             merkle_root: merkle_root_pda,
+            closed_airdrop: closed_airdrop_pda(&program_id, &merkle_root_pda),
+            config: config_pda(&program_id),
+            fee_recipient,
             system_program: system_program::ID,
-            token_program: spl_token::ID,
-            associated_token_program: anchor_spl::associated_token::ID,
         })
-        .args(airdrop_contract::instruction::CreateAirdrop {
-            merkle_root_hash,
+        .args(airdrop_contract::instruction::CreateSolAirdrop {
+            merkle_root_hash: airdrop_data.merkle_root_hash()?,
             amount: total_amount,
+            params,
         })
         .send()?;
 
-    println!("\nAirdrop deployed successfully!");
-    println!("Signature: {}", signature);
-    println!("Mint: {}", mint_pubkey);
-    println!(
-        "Explorer: https://explorer.solana.com/tx/{}?cluster={}",
-        signature,
-        args.network.name()
-    );
+    airdrop_data.sol = true;
+    finish_deploy(
+        args,
+        &program,
+        merkle_root_pda,
+        Some(signature),
+        None,
+        &airdrop_data,
+        "SOL flag and creator",
+    )
+}
 
-    // Update the JSON file with the mint address
-    airdrop_data.mint = Some(mint_pubkey.to_string());
-    let file = File::create(&args.json_path)
-        .with_context(|| format!("Failed to open {:?} for writing", args.json_path))?;
-    serde_json::to_writer_pretty(file, &airdrop_data)
-        .with_context(|| "Failed to write updated airdrop JSON")?;
-    println!("\nUpdated {:?} with mint address", args.json_path);
+/// Create a mint-on-claim airdrop. A new mint is created with the merkle root as its mint
+/// authority and no supply; an existing mint has its authority handed over in the same
+/// transaction, so the keypair must currently hold it.
+fn deploy_mint_airdrop(
+    args: &DeployAirdropArgs,
+    payer: &Keypair,
+    rpc_client: &RpcClient,
+    mut airdrop_data: AirdropJson,
+    total_amount: u64,
+    params: CreateAirdropParams,
+) -> Result<()> {
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let (program, merkle_root_pda) = deploy_target(args, payer, &mut airdrop_data)?;
+    let authority = program.payer();
+    println!("Merkle root PDA (mint authority): {} (nonce {})", merkle_root_pda, args.nonce);
 
-    Ok(())
+    let mut request = program.request();
+    let (mint, token_program) = match &args.mint {
+        Some(mint_str) => {
+            let token_mint = TokenMint::fetch(rpc_client, &Pubkey::from_str(mint_str)?)?;
+            println!("Using existing mint: {}", token_mint.address);
+            request = request.instruction(spl_token_2022::instruction::set_authority(
+                &token_mint.token_program,
+                &token_mint.address,
+                Some(&merkle_root_pda),
+                AuthorityType::MintTokens,
+                &authority,
+                &[],
+            )?);
+            (token_mint.address, token_mint.token_program)
+        }
+        None => {
+            println!("\nNo mint provided, creating new token mint...");
+            let mint_kp = Keypair::new();
+            let token_program = if args.token_2022 {
+                spl_token_2022::ID
+            } else {
+                spl_token::ID
+            };
+            let rent = rpc_client.get_minimum_balance_for_rent_exemption(Mint::LEN)?;
+            let create_mint_ix = system_instruction::create_account(
+                &payer.pubkey(),
+                &mint_kp.pubkey(),
+                rent,
+                Mint::LEN as u64,
+                &token_program,
+            );
+            let init_mint_ix = spl_token_2022::instruction::initialize_mint(
+                &token_program,
+                &mint_kp.pubkey(),
+                &merkle_root_pda,
+                Some(&payer.pubkey()),
+                MINT_DECIMALS,
+            )?;
+            let recent_blockhash = rpc_client.get_latest_blockhash()?;
+            let tx = Transaction::new_signed_with_payer(
+                &[create_mint_ix, init_mint_ix],
+                Some(&payer.pubkey()),
+                &[payer, &mint_kp],
+                recent_blockhash,
+            );
+            let sig = rpc_client.send_and_confirm_transaction(&tx)?;
+            println!("Mint created: {} ({})", mint_kp.pubkey(), sig);
+            (mint_kp.pubkey(), token_program)
+        }
+    };
+    if token_program == spl_token_2022::ID {
+        println!("Token-2022 mint: minting charges no transfer fee");
+    }
+
+    println!("\nSending create mint-on-claim airdrop transaction...");
+    let signature = request
+        .accounts(CreateMintAirdrop {
+            authority,
+            merkle_root: merkle_root_pda,
+            closed_airdrop: closed_airdrop_pda(&program_id, &merkle_root_pda),
+            mint,
+            system_program: system_program::ID,
+        })
+        .args(airdrop_contract::instruction::CreateMintAirdrop {
+            merkle_root_hash: airdrop_data.merkle_root_hash()?,
+            amount: total_amount,
+            params,
+        })
+        .send()?;
+
+    airdrop_data.mint = Some(mint.to_string());
+    airdrop_data.mint_on_claim = true;
+    finish_deploy(
+        args,
+        &program,
+        merkle_root_pda,
+        Some(signature),
+        Some(mint),
+        &airdrop_data,
+        "mint address, mint-on-claim flag and creator",
+    )
+}
+
+/// Build the optional linear vesting schedule from the deploy flags
+fn vesting_schedule(args: &DeployAirdropArgs) -> Result<Option<VestingSchedule>> {
+    let end = match args.vesting_end {
+        Some(end) => end,
+        None if args.vesting_start.is_some() || args.vesting_cliff.is_some() => {
+            anyhow::bail!("--vesting-end is required when --vesting-start or --vesting-cliff is set")
+        }
+        None => return Ok(None),
+    };
+    let start = match args.vesting_start.or(args.claim_start) {
+        Some(start) => start,
+        None => unix_timestamp_now()?,
+    };
+    let cliff = args.vesting_cliff.unwrap_or(start);
+
+    let schedule = VestingSchedule { start, cliff, end };
+    if !schedule.is_valid() {
+        anyhow::bail!("Vesting schedule must satisfy start <= cliff <= end and start < end");
+    }
+    Ok(Some(schedule))
 }
 
 pub fn get_default_program_id() -> String {
@@ -292,3 +871,48 @@ pub fn get_default_keypair_path() -> PathBuf {
     let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
     PathBuf::from(home).join(".config/solana/id.json")
 }
+
+/// Current unix timestamp from the local clock
+pub fn unix_timestamp_now() -> Result<i64> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .with_context(|| "System clock is before the unix epoch")?;
+    Ok(now.as_secs() as i64)
+}
+
+/// Parse a timestamp given either as unix seconds or as an RFC3339 date-time
+pub fn parse_timestamp(value: &str) -> Result<i64> {
+    if let Ok(unix) = value.parse::<i64>() {
+        return Ok(unix);
+    }
+    let datetime = chrono::DateTime::parse_from_rfc3339(value).with_context(|| {
+        format!(
+            "Invalid timestamp '{}': expected unix seconds or RFC3339 (e.g. 2025-01-31T12:00:00Z)",
+            value
+        )
+    })?;
+    Ok(datetime.timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp_unix() {
+        assert_eq!(parse_timestamp("1700000000").unwrap(), 1_700_000_000);
+    }
+
+    #[test]
+    fn test_parse_timestamp_rfc3339() {
+        assert_eq!(parse_timestamp("2023-11-14T22:13:20Z").unwrap(), 1_700_000_000);
+        // Offsets are normalized to UTC
+        assert_eq!(parse_timestamp("2023-11-15T00:13:20+02:00").unwrap(), 1_700_000_000);
+    }
+
+    #[test]
+    fn test_parse_timestamp_invalid() {
+        assert!(parse_timestamp("next tuesday").is_err());
+        assert!(parse_timestamp("2023-11-14").is_err());
+    }
+}
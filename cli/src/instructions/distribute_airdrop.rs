@@ -0,0 +1,353 @@
+use airdrop_contract::accounts::Distribute;
+use airdrop_contract::state::{ClaimReceipt, MerkleRoot};
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair};
+use anchor_client::Client;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022::{self, extension::StateWithExtensions};
+use anyhow::{Context, Result};
+use solana_client::rpc_client::RpcClient;
+use std::collections::hash_map::{Entry, HashMap};
+use std::{path::PathBuf, rc::Rc, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::claim_airdrop::{claim_receipt_pda, generate_proof};
+use crate::instructions::deploy_airdrop::{unix_timestamp_now, Network};
+use crate::instructions::program_config::claim_fee_accounts;
+use crate::instructions::token_mint::{current_epoch, TokenMint};
+
+/// Maximum number of accounts `getMultipleAccounts` accepts per request
+const RPC_ACCOUNTS_CHUNK: usize = 100;
+
+#[derive(Debug)]
+pub struct DistributeAirdropArgs {
+    pub json_path: PathBuf,
+    pub batch_size: usize,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+/// One leaf of the tree, as `distribute` takes it
+#[derive(Debug, PartialEq)]
+struct Leaf {
+    recipient: Pubkey,
+    mint: Pubkey,
+    /// Whether the claim receipt is keyed by mint, as in multi-mint airdrops
+    multi_mint: bool,
+    amount: u64,
+    leaf_index: u64,
+}
+
+/// Every leaf of `airdrop_data`, paying `mint` unless the leaf names its own
+fn leaves(airdrop_data: &AirdropJson, mint: &Pubkey) -> Result<Vec<Leaf>> {
+    airdrop_data
+        .allocations()
+        .into_iter()
+        .map(|(address, leaf_mint, claim)| {
+            let recipient = Pubkey::from_str(address)
+                .with_context(|| format!("Invalid recipient address: {}", address))?;
+            let leaf_mint = leaf_mint
+                .map(|leaf_mint| {
+                    Pubkey::from_str(leaf_mint)
+                        .with_context(|| format!("Invalid mint address: {}", leaf_mint))
+                })
+                .transpose()?;
+            let amount = claim
+                .amount
+                .parse()
+                .with_context(|| format!("invalid amount '{}' for {}", claim.amount, address))?;
+            Ok(Leaf {
+                recipient,
+                mint: leaf_mint.unwrap_or(*mint),
+                multi_mint: leaf_mint.is_some(),
+                amount,
+                leaf_index: claim.leaf_index,
+            })
+        })
+        .collect()
+}
+
+/// Base units held by each of `accounts`, zero for those that do not exist
+fn token_balances(rpc_client: &RpcClient, accounts: &[Pubkey]) -> Result<Vec<u64>> {
+    let mut balances = Vec::with_capacity(accounts.len());
+    for chunk in accounts.chunks(RPC_ACCOUNTS_CHUNK) {
+        for account in rpc_client.get_multiple_accounts(chunk)? {
+            let balance = match account {
+                Some(account) => {
+                    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+                        .map_err(|e| anyhow::anyhow!("Invalid token account: {}", e))?
+                        .base
+                        .amount
+                }
+                None => 0,
+            };
+            balances.push(balance);
+        }
+    }
+    Ok(balances)
+}
+
+/// Push every leaf's claimable amount to its recipient with the authority-only `distribute`
+/// instruction. What has been paid is read back from the claim receipts, so re-running picks
+/// up where an interrupted or partly failed run stopped.
+pub fn distribute_airdrop(args: DistributeAirdropArgs) -> Result<()> {
+    if args.batch_size == 0 {
+        anyhow::bail!("--batch-size must be at least 1");
+    }
+
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    let program_id = Pubkey::from_str(&args.program_id)?;
+
+    // Load keypair
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+    let authority = program.payer();
+
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+    let merkle_root: MerkleRoot = program.account(merkle_root_pda)?;
+    if merkle_root.authority != authority {
+        anyhow::bail!(
+            "Keypair {} is not the airdrop authority ({})",
+            authority,
+            merkle_root.authority
+        );
+    }
+    if merkle_root.native_sol
+        || merkle_root.mint_on_claim
+        || merkle_root.voucher_signer.is_some()
+        || merkle_root.bitmap_leaf_count.is_some()
+    {
+        anyhow::bail!(
+            "distribute-airdrop only supports token airdrops paid from a vault with claim receipts"
+        );
+    }
+
+    println!("Merkle root: {}", airdrop_data.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Merkle root PDA: {}", merkle_root_pda);
+
+    // Work out what each leaf is still owed from its claim receipt
+    let all_leaves = leaves(&airdrop_data, &merkle_root.mint)?;
+    let now = unix_timestamp_now()?;
+    let mut pending = Vec::new();
+    for chunk in all_leaves.chunks(RPC_ACCOUNTS_CHUNK) {
+        let receipts: Vec<Pubkey> = chunk
+            .iter()
+            .map(|leaf| {
+                let receipt_mint = leaf.multi_mint.then_some(&leaf.mint);
+                claim_receipt_pda(&program_id, &merkle_root_pda, &leaf.recipient, receipt_mint)
+            })
+            .collect();
+        let accounts = rpc_client.get_multiple_accounts(&receipts)?;
+        for ((leaf, receipt), account) in chunk.iter().zip(receipts).zip(accounts) {
+            let claimed_so_far = match account {
+                Some(account) => {
+                    ClaimReceipt::try_deserialize(&mut account.data.as_slice())?.claimed_so_far
+                }
+                None => 0,
+            };
+            let claimable =
+                merkle_root.vested_amount(leaf.amount, now).saturating_sub(claimed_so_far);
+            if claimable > 0 {
+                pending.push((leaf, receipt, claimable));
+            }
+        }
+    }
+    println!("\nLeaves: {}", all_leaves.len());
+    println!("Nothing owed yet: {}", all_leaves.len() - pending.len());
+
+    // Gated airdrops only pay recipients who hold enough of the gate mint right now
+    let mut gate_accounts = HashMap::new();
+    if let Some(gate) = &merkle_root.gate {
+        let gate_mint = TokenMint::fetch(&rpc_client, &gate.mint)?;
+        let accounts: Vec<Pubkey> = pending
+            .iter()
+            .map(|(leaf, ..)| gate_mint.associated_token_address(&leaf.recipient))
+            .collect();
+        let balances = token_balances(&rpc_client, &accounts)?;
+        let before = pending.len();
+        let mut balances = balances.into_iter();
+        let mut accounts = accounts.into_iter();
+        pending.retain(|(leaf, ..)| {
+            let (account, balance) = (accounts.next().unwrap(), balances.next().unwrap());
+            gate_accounts.insert(leaf.recipient, account);
+            balance >= gate.min_balance
+        });
+        println!(
+            "Skipped for holding less than {} of gate mint {}: {}",
+            gate.min_balance,
+            gate.mint,
+            before - pending.len()
+        );
+    }
+    println!("To distribute: {}", pending.len());
+    if pending.is_empty() {
+        println!("\nNothing left to distribute");
+        return Ok(());
+    }
+
+    let (config, fee_recipient) = claim_fee_accounts(&rpc_client, &program_id, &authority)?;
+    let mut token_mints: HashMap<Pubkey, TokenMint> = HashMap::new();
+    let epoch = current_epoch(&rpc_client)?;
+
+    println!("\nDistributing in batches of {}...", args.batch_size);
+    let mut distributed = 0;
+    let mut failed = 0;
+    for batch in pending.chunks(args.batch_size) {
+        let mut request = program.request();
+        for (leaf, claim_receipt, claimable) in batch {
+            let token_mint = match token_mints.entry(leaf.mint) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(TokenMint::fetch(&rpc_client, &leaf.mint)?),
+            };
+            let recipient_token_account = token_mint.associated_token_address(&leaf.recipient);
+            let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+            let mut accounts = Distribute {
+                authority,
+                recipient: leaf.recipient,
+                recipient_token_account,
+                merkle_root_token_account,
+                mint: leaf.mint,
+                merkle_root: merkle_root_pda,
+                claim_receipt: *claim_receipt,
+                gate_token_account: gate_accounts.get(&leaf.recipient).copied(),
+                config,
+                fee_recipient,
+                system_program: anchor_client::solana_sdk::system_program::ID,
+                token_program: token_mint.token_program,
+                associated_token_program: anchor_spl::associated_token::ID,
+            }
+            .to_account_metas(None);
+            // The hook sees what the program sends: the vested part, grossed up for any fee
+            accounts.extend(token_mint.transfer_hook_accounts(
+                &rpc_client,
+                &merkle_root_token_account,
+                &recipient_token_account,
+                &merkle_root_pda,
+                token_mint.gross_up(*claimable, epoch)?,
+            )?);
+            request = request.instruction(Instruction {
+                program_id,
+                accounts,
+                data: airdrop_contract::instruction::Distribute {
+                    proof: generate_proof(&airdrop_data.merkle_tree, leaf.leaf_index)?,
+                    amount: leaf.amount,
+                    leaf_index: leaf.leaf_index as u32,
+                }
+                .data(),
+            });
+        }
+
+        // Keep going past a failed batch; the next run retries it
+        match request.send() {
+            Ok(signature) => {
+                distributed += batch.len();
+                println!(
+                    "Distributed {}/{} ({})",
+                    distributed + failed,
+                    pending.len(),
+                    signature
+                );
+            }
+            Err(e) => {
+                failed += batch.len();
+                let recipients: Vec<String> =
+                    batch.iter().map(|(leaf, ..)| leaf.recipient.to_string()).collect();
+                println!(
+                    "Failed {}/{} ({}): {}",
+                    distributed + failed,
+                    pending.len(),
+                    recipients.join(", "),
+                    e
+                );
+            }
+        }
+    }
+
+    println!("\nDistributed to {} leaves", distributed);
+    if failed > 0 {
+        anyhow::bail!(
+            "{} distribution(s) failed; run distribute-airdrop again to retry them",
+            failed
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::airdrop_json::ClaimInfo;
+    use airdrop_contract::constants::{HASH_VERSION_V2, LEAF_ENCODING_PUBKEY};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_leaves_take_the_airdrop_mint_unless_multi_mint() {
+        let recipient = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let other_mint = Pubkey::new_unique();
+        let claim = |amount: u64, leaf_index: u64| ClaimInfo {
+            amount: amount.to_string(),
+            leaf_index,
+        };
+        let mut airdrop_data = AirdropJson {
+            merkle_root: hex::encode([0u8; 32]),
+            merkle_tree: Vec::new(),
+            claims: BTreeMap::from([(recipient.to_string(), claim(100, 2))]),
+            mint_claims: BTreeMap::new(),
+            mint: None,
+            sol: false,
+            mint_on_claim: false,
+            hash_version: HASH_VERSION_V2,
+            leaf_encoding: LEAF_ENCODING_PUBKEY,
+            initial_merkle_root: None,
+            creator: None,
+            nonce: None,
+            voucher_signer: None,
+            voucher_amount: None,
+        };
+        assert_eq!(
+            leaves(&airdrop_data, &mint).unwrap(),
+            [Leaf {
+                recipient,
+                mint,
+                multi_mint: false,
+                amount: 100,
+                leaf_index: 2,
+            }]
+        );
+
+        airdrop_data.claims.clear();
+        airdrop_data.mint_claims = BTreeMap::from([(
+            recipient.to_string(),
+            BTreeMap::from([(other_mint.to_string(), claim(50, 3))]),
+        )]);
+        assert_eq!(
+            leaves(&airdrop_data, &mint).unwrap(),
+            [Leaf {
+                recipient,
+                mint: other_mint,
+                multi_mint: true,
+                amount: 50,
+                leaf_index: 3,
+            }]
+        );
+    }
+}
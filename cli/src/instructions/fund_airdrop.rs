@@ -0,0 +1,136 @@
+use airdrop_contract::accounts::FundAirdrop;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair};
+use anchor_client::Client;
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use std::{path::PathBuf, rc::Rc, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::deploy_airdrop::Network;
+use crate::instructions::program_config::FundingFee;
+use crate::instructions::token_mint::TokenMint;
+
+#[derive(Debug)]
+pub struct FundAirdropArgs {
+    pub json_path: PathBuf,
+    pub amount: u64,
+    /// One of a multi-mint airdrop's mints; defaults to the mint in the JSON
+    pub mint: Option<String>,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+/// Top up an airdrop's vault from the funder's token account (anyone may fund)
+pub fn fund_airdrop(args: FundAirdropArgs) -> Result<()> {
+    if args.amount == 0 {
+        anyhow::bail!("--amount must be greater than zero");
+    }
+
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    if airdrop_data.sol {
+        anyhow::bail!("SOL airdrops are funded in full at deployment");
+    }
+    if airdrop_data.mint_on_claim {
+        anyhow::bail!("Mint-on-claim airdrops have no vault; claims mint the tokens");
+    }
+    let mint = airdrop_data.resolve_mint(args.mint.as_deref())?;
+    if !airdrop_data.mints()?.contains(&mint) {
+        anyhow::bail!("{} is not one of this airdrop's mints", mint);
+    }
+    let program_id = Pubkey::from_str(&args.program_id)?;
+
+    // Load keypair
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+    let funder = program.payer();
+
+    // Derive PDAs
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let token_mint = TokenMint::fetch(&rpc_client, &mint)?;
+    let funder_token_account = token_mint.associated_token_address(&funder);
+    let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+
+    println!("Merkle root: {}", airdrop_data.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Mint: {}", mint);
+    println!("Merkle root PDA: {}", merkle_root_pda);
+    println!("Funder token account: {}", funder_token_account);
+    println!("Merkle root token account: {}", merkle_root_token_account);
+
+    let funding_fee =
+        FundingFee::fetch(&rpc_client, &program_id, &token_mint, &funder_token_account, args.amount)?;
+    let balance: u64 = rpc_client
+        .get_token_account_balance(&funder_token_account)?
+        .amount
+        .parse()?;
+    if balance < args.amount + funding_fee.fee {
+        anyhow::bail!(
+            "Insufficient balance: {} has {} but {} is needed",
+            funder_token_account,
+            balance,
+            args.amount + funding_fee.fee
+        );
+    }
+    println!("Funding amount: {}", args.amount);
+    if funding_fee.fee > 0 {
+        println!("Protocol fee: {}", funding_fee.fee);
+    }
+
+    println!("\nSending fund transaction...");
+
+    let mut request = program.request();
+    if let Some(ix) = funding_fee.create_account_instruction(&funder, &token_mint) {
+        request = request.instruction(ix);
+    }
+    let signature = request
+        .accounts(FundAirdrop {
+            funder,
+            funder_token_account,
+            merkle_root_token_account,
+            mint,
+            merkle_root: merkle_root_pda,
+            vault_record: airdrop_data.vault_record_pda(&program_id, &mint)?,
+            config: funding_fee.config,
+            fee_recipient_token_account: funding_fee.fee_recipient_token_account,
+            system_program: anchor_client::solana_sdk::system_program::ID,
+            token_program: token_mint.token_program,
+            associated_token_program: anchor_spl::associated_token::ID,
+        })
+        .accounts(token_mint.transfer_hook_accounts(
+            &rpc_client,
+            &funder_token_account,
+            &merkle_root_token_account,
+            &funder,
+            args.amount,
+        )?)
+        .args(airdrop_contract::instruction::FundAirdrop {
+            amount: args.amount,
+        })
+        .send()?;
+
+    println!("\nAirdrop funded successfully!");
+    println!("Signature: {}", signature);
+    println!(
+        "Explorer: https://explorer.solana.com/tx/{}?cluster={}",
+        signature,
+        args.network.name()
+    );
+
+    Ok(())
+}
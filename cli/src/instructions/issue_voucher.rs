@@ -0,0 +1,172 @@
+use airdrop_contract::state::Voucher;
+use anchor_client::solana_sdk::bs58;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Signer};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{fs::File, path::{Path, PathBuf}, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::deploy_airdrop::unix_timestamp_now;
+
+/// Vouchers are valid for a week unless --expires-at is given
+const DEFAULT_VOUCHER_TTL_SECS: i64 = 7 * 24 * 60 * 60;
+
+#[derive(Debug)]
+pub struct IssueVoucherArgs {
+    pub json_path: PathBuf,
+    pub recipient: String,
+    pub amount: u64,
+    pub nonce: Option<u64>,
+    pub expires_at: Option<i64>,
+    pub output_path: PathBuf,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+/// A signed voucher as handed to its recipient
+#[derive(Deserialize, Serialize)]
+pub struct VoucherJson {
+    /// Merkle root account of the voucher airdrop, which the signature is bound to
+    pub airdrop: String,
+    pub recipient: String,
+    pub amount: String,
+    pub nonce: u64,
+    pub expires_at: i64,
+    pub signer: String,
+    /// Base58 ed25519 signature over `Voucher::message`
+    pub signature: String,
+}
+
+impl VoucherJson {
+    /// Read and parse a voucher file
+    pub fn read(path: &Path) -> Result<Self> {
+        let file = File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
+        serde_json::from_reader(file).with_context(|| format!("Failed to parse voucher from {:?}", path))
+    }
+
+    /// The on-chain voucher this file describes
+    pub fn voucher(&self) -> Result<Voucher> {
+        Ok(Voucher {
+            recipient: Pubkey::from_str(&self.recipient)?,
+            amount: self
+                .amount
+                .parse()
+                .with_context(|| format!("invalid amount '{}'", self.amount))?,
+            nonce: self.nonce,
+            expires_at: self.expires_at,
+        })
+    }
+
+    /// Decode the 64-byte signature
+    pub fn signature_bytes(&self) -> Result<[u8; 64]> {
+        bs58::decode(&self.signature)
+            .into_vec()
+            .map_err(|e| anyhow::anyhow!("Invalid voucher signature: {:?}", e))?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Voucher signature must be 64 bytes"))
+    }
+}
+
+/// Sign a voucher offline with the airdrop's voucher signer keypair
+pub fn issue_voucher(args: IssueVoucherArgs) -> Result<()> {
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    let voucher_signer = airdrop_data
+        .voucher_signer()?
+        .ok_or_else(|| anyhow::anyhow!("{:?} is not a voucher airdrop", args.json_path))?;
+    let program_id = Pubkey::from_str(&args.program_id)?;
+
+    let signer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+    if signer.pubkey() != voucher_signer {
+        anyhow::bail!(
+            "Keypair {} is not the voucher signer ({})",
+            signer.pubkey(),
+            voucher_signer
+        );
+    }
+    if args.amount == 0 {
+        anyhow::bail!("Voucher amount must be greater than zero");
+    }
+
+    let airdrop = airdrop_data.merkle_root_pda(&program_id)?;
+    let voucher = Voucher {
+        recipient: Pubkey::from_str(&args.recipient)?,
+        amount: args.amount,
+        nonce: args.nonce.unwrap_or_else(rand::random),
+        expires_at: match args.expires_at {
+            Some(expires_at) => expires_at,
+            None => unix_timestamp_now()? + DEFAULT_VOUCHER_TTL_SECS,
+        },
+    };
+    let signature = signer.sign_message(&voucher.message(&airdrop));
+
+    let voucher_json = VoucherJson {
+        airdrop: airdrop.to_string(),
+        recipient: voucher.recipient.to_string(),
+        amount: voucher.amount.to_string(),
+        nonce: voucher.nonce,
+        expires_at: voucher.expires_at,
+        signer: voucher_signer.to_string(),
+        signature: signature.to_string(),
+    };
+    let file = File::create(&args.output_path)
+        .with_context(|| format!("Failed to open {:?} for writing", args.output_path))?;
+    serde_json::to_writer_pretty(file, &voucher_json).with_context(|| "Failed to write voucher")?;
+
+    println!("Airdrop: {}", airdrop);
+    println!("Recipient: {}", voucher.recipient);
+    println!("Amount: {}", voucher.amount);
+    println!("Nonce: {}", voucher.nonce);
+    println!("Expires at (unix): {}", voucher.expires_at);
+    println!("\nWrote voucher to {:?}", args.output_path);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::solana_sdk::signature::{Keypair, Signature};
+
+    #[test]
+    fn test_voucher_json_round_trip() {
+        let signer = Keypair::new();
+        let airdrop = Pubkey::new_unique();
+        let voucher = Voucher {
+            recipient: Pubkey::new_unique(),
+            amount: 250,
+            nonce: 7,
+            expires_at: 1_700_000_000,
+        };
+        let signature = signer.sign_message(&voucher.message(&airdrop));
+        let voucher_json = VoucherJson {
+            airdrop: airdrop.to_string(),
+            recipient: voucher.recipient.to_string(),
+            amount: voucher.amount.to_string(),
+            nonce: voucher.nonce,
+            expires_at: voucher.expires_at,
+            signer: signer.pubkey().to_string(),
+            signature: signature.to_string(),
+        };
+
+        let parsed = voucher_json.voucher().unwrap();
+        assert_eq!(parsed, voucher);
+        let bytes = voucher_json.signature_bytes().unwrap();
+        assert!(Signature::from(bytes).verify(signer.pubkey().as_ref(), &parsed.message(&airdrop)));
+    }
+
+    #[test]
+    fn test_voucher_message_binds_airdrop() {
+        let voucher = Voucher {
+            recipient: Pubkey::new_unique(),
+            amount: 1,
+            nonce: 0,
+            expires_at: 0,
+        };
+        assert_ne!(
+            voucher.message(&Pubkey::new_unique()),
+            voucher.message(&Pubkey::new_unique())
+        );
+    }
+}
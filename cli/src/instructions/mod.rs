@@ -1,7 +1,39 @@
+pub mod airdrop_json;
+pub mod airdrop_metadata;
+pub mod airdrop_status;
 pub mod claim_airdrop;
+pub mod claim_link;
+pub mod claim_status;
+pub mod claim_voucher;
+pub mod clawback_airdrop;
+pub mod close_airdrop;
 pub mod create_airdrop;
+pub mod create_voucher_airdrop;
+pub mod decode_events;
 pub mod deploy_airdrop;
+pub mod distribute_airdrop;
+pub mod fund_airdrop;
+pub mod issue_voucher;
+pub mod pause_airdrop;
+pub mod program_config;
+pub mod token_mint;
+pub mod update_root;
 
+pub use airdrop_metadata::*;
+pub use airdrop_status::*;
 pub use claim_airdrop::*;
+pub use claim_link::*;
+pub use claim_status::*;
+pub use claim_voucher::*;
+pub use clawback_airdrop::*;
+pub use close_airdrop::*;
 pub use create_airdrop::*;
+pub use create_voucher_airdrop::*;
+pub use decode_events::*;
 pub use deploy_airdrop::*;
+pub use distribute_airdrop::*;
+pub use fund_airdrop::*;
+pub use issue_voucher::*;
+pub use pause_airdrop::*;
+pub use program_config::*;
+pub use update_root::*;
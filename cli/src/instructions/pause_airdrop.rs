@@ -0,0 +1,77 @@
+use airdrop_contract::accounts::SetPaused;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair};
+use anchor_client::Client;
+use anyhow::Result;
+use std::{path::PathBuf, rc::Rc, str::FromStr};
+
+use crate::instructions::airdrop_json::AirdropJson;
+use crate::instructions::deploy_airdrop::Network;
+
+#[derive(Debug)]
+pub struct PauseAirdropArgs {
+    pub json_path: PathBuf,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+/// Halt claims on an airdrop (authority only)
+pub fn pause_airdrop(args: PauseAirdropArgs) -> Result<()> {
+    set_paused(args, true)
+}
+
+/// Resume claims on a paused airdrop (authority only)
+pub fn unpause_airdrop(args: PauseAirdropArgs) -> Result<()> {
+    set_paused(args, false)
+}
+
+fn set_paused(args: PauseAirdropArgs, paused: bool) -> Result<()> {
+    let airdrop_data = AirdropJson::read(&args.json_path)?;
+    let program_id = Pubkey::from_str(&args.program_id)?;
+
+    // Load keypair
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+
+    let merkle_root_pda = airdrop_data.merkle_root_pda(&program_id)?;
+
+    println!("Merkle root: {}", airdrop_data.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Merkle root PDA: {}", merkle_root_pda);
+
+    let request = program.request().accounts(SetPaused {
+        authority: program.payer(),
+        merkle_root: merkle_root_pda,
+    });
+    let signature = if paused {
+        println!("\nSending pause transaction...");
+        request.args(airdrop_contract::instruction::Pause {}).send()?
+    } else {
+        println!("\nSending unpause transaction...");
+        request.args(airdrop_contract::instruction::Unpause {}).send()?
+    };
+
+    println!(
+        "\nAirdrop {} successfully!",
+        if paused { "paused" } else { "unpaused" }
+    );
+    println!("Signature: {}", signature);
+    println!(
+        "Explorer: https://explorer.solana.com/tx/{}?cluster={}",
+        signature,
+        args.network.name()
+    );
+
+    Ok(())
+}
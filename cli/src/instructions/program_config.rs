@@ -0,0 +1,331 @@
+use airdrop_contract::accounts::{InitializeConfig, UpdateConfig};
+use airdrop_contract::constants::{CONFIG_SEED, MAX_FEE_BPS};
+use airdrop_contract::state::{Config, ProtocolFee};
+use anchor_client::solana_sdk::bpf_loader_upgradeable;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair};
+use anchor_client::Client;
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use std::{path::PathBuf, rc::Rc, str::FromStr};
+
+use crate::instructions::deploy_airdrop::Network;
+use crate::instructions::token_mint::TokenMint;
+
+#[derive(Debug)]
+pub struct InitConfigArgs {
+    /// Defaults to the keypair
+    pub admin: Option<String>,
+    /// Defaults to the admin
+    pub fee_recipient: Option<String>,
+    pub funded_fee_bps: Option<u16>,
+    pub claim_fee_lamports: Option<u64>,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+#[derive(Debug)]
+pub struct UpdateConfigArgs {
+    pub admin: Option<String>,
+    pub fee_recipient: Option<String>,
+    pub funded_fee_bps: Option<u16>,
+    pub claim_fee_lamports: Option<u64>,
+    /// Stop charging any fee
+    pub no_fee: bool,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+#[derive(Debug)]
+pub struct ShowConfigArgs {
+    pub network: Network,
+    pub program_id: String,
+}
+
+pub fn config_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED], program_id).0
+}
+
+/// The program's config, or `None` while it has not been initialized
+pub fn fetch_config(rpc_client: &RpcClient, program_id: &Pubkey) -> Result<Option<Config>> {
+    let account = rpc_client
+        .get_account_with_commitment(&config_pda(program_id), CommitmentConfig::confirmed())?
+        .value;
+    match account {
+        Some(account) => Ok(Some(Config::try_deserialize(&mut account.data.as_slice())?)),
+        None => Ok(None),
+    }
+}
+
+/// The config PDA and the fee recipient every claim passes. The recipient is only paid when a
+/// claim fee is configured; until the config exists `payer` stands in for it.
+pub fn claim_fee_accounts(
+    rpc_client: &RpcClient,
+    program_id: &Pubkey,
+    payer: &Pubkey,
+) -> Result<(Pubkey, Pubkey)> {
+    let fee_recipient = match fetch_config(rpc_client, program_id)? {
+        Some(config) => config.fee_recipient,
+        None => *payer,
+    };
+    Ok((config_pda(program_id), fee_recipient))
+}
+
+/// Protocol fee on a token deposit, and the accounts the depositing instruction passes for it
+pub struct FundingFee {
+    pub config: Pubkey,
+    /// The fee recipient's token account, or the depositor's own when no fee is due
+    pub fee_recipient_token_account: Pubkey,
+    pub fee: u64,
+    fee_recipient: Option<Pubkey>,
+}
+
+impl FundingFee {
+    pub fn fetch(
+        rpc_client: &RpcClient,
+        program_id: &Pubkey,
+        token_mint: &TokenMint,
+        depositor_token_account: &Pubkey,
+        amount: u64,
+    ) -> Result<Self> {
+        let config = fetch_config(rpc_client, program_id)?;
+        let fee = config.as_ref().map_or(0, |config| config.fee.funding_fee(amount));
+        let fee_recipient = config.filter(|_| fee > 0).map(|config| config.fee_recipient);
+        let fee_recipient_token_account = match &fee_recipient {
+            Some(recipient) => token_mint.associated_token_address(recipient),
+            None => *depositor_token_account,
+        };
+        Ok(Self {
+            config: config_pda(program_id),
+            fee_recipient_token_account,
+            fee,
+            fee_recipient,
+        })
+    }
+
+    /// Opens the fee recipient's token account, at `payer`'s expense, if a fee is due
+    pub fn create_account_instruction(&self, payer: &Pubkey, token_mint: &TokenMint) -> Option<Instruction> {
+        self.fee_recipient.map(|recipient| {
+            create_associated_token_account_idempotent(
+                payer,
+                &recipient,
+                &token_mint.address,
+                &token_mint.token_program,
+            )
+        })
+    }
+}
+
+/// Build the fee setting from the command-line flags; `None` when none were given
+fn fee_from_flags(
+    funded_fee_bps: Option<u16>,
+    claim_fee_lamports: Option<u64>,
+    no_fee: bool,
+) -> Result<Option<ProtocolFee>> {
+    let fee = match (funded_fee_bps, claim_fee_lamports, no_fee) {
+        (None, None, false) => return Ok(None),
+        (Some(bps), None, false) => ProtocolFee::FundedBps(bps),
+        (None, Some(lamports), false) => ProtocolFee::ClaimLamports(lamports),
+        (None, None, true) => ProtocolFee::None,
+        _ => anyhow::bail!(
+            "Pick one of --funded-fee-bps, --claim-fee-lamports and --no-fee"
+        ),
+    };
+    if !fee.is_valid() {
+        anyhow::bail!("--funded-fee-bps cannot exceed {}", MAX_FEE_BPS);
+    }
+    Ok(Some(fee))
+}
+
+fn describe_fee(fee: &ProtocolFee) -> String {
+    match fee {
+        ProtocolFee::None => "none".to_string(),
+        ProtocolFee::FundedBps(bps) => {
+            format!("{} bps ({:.2}%) of every deposit", bps, *bps as f64 / 100.0)
+        }
+        ProtocolFee::ClaimLamports(lamports) => format!("{} lamports per claim", lamports),
+    }
+}
+
+fn print_config(config_pda: &Pubkey, config: &Config) {
+    println!("Config PDA: {}", config_pda);
+    println!("Admin: {}", config.admin);
+    println!("Fee recipient: {}", config.fee_recipient);
+    println!("Fee: {}", describe_fee(&config.fee));
+}
+
+fn parse_pubkey(value: Option<&str>) -> Result<Option<Pubkey>> {
+    value.map(Pubkey::from_str).transpose().map_err(Into::into)
+}
+
+/// Create the program's singleton config. Must be signed by the program's upgrade authority.
+pub fn init_config(args: InitConfigArgs) -> Result<()> {
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let fee = fee_from_flags(args.funded_fee_bps, args.claim_fee_lamports, false)?
+        .unwrap_or(ProtocolFee::None);
+
+    // Load keypair
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+
+    let admin = parse_pubkey(args.admin.as_deref())?.unwrap_or(program.payer());
+    let fee_recipient = parse_pubkey(args.fee_recipient.as_deref())?.unwrap_or(admin);
+    let config = config_pda(&program_id);
+
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Config PDA: {}", config);
+    println!("Admin: {}", admin);
+    println!("Fee recipient: {}", fee_recipient);
+    println!("Fee: {}", describe_fee(&fee));
+
+    println!("\nSending initialize config transaction...");
+    let signature = program
+        .request()
+        .accounts(InitializeConfig {
+            payer: program.payer(),
+            program: program_id,
+            program_data: bpf_loader_upgradeable::get_program_data_address(&program_id),
+            config,
+            system_program: anchor_client::solana_sdk::system_program::ID,
+        })
+        .args(airdrop_contract::instruction::InitializeConfig {
+            admin,
+            fee_recipient,
+            fee,
+        })
+        .send()?;
+
+    println!("\nConfig initialized successfully!");
+    println!("Signature: {}", signature);
+
+    Ok(())
+}
+
+/// Change the config's admin, fee recipient or fee (admin only)
+pub fn update_config(args: UpdateConfigArgs) -> Result<()> {
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let admin = parse_pubkey(args.admin.as_deref())?;
+    let fee_recipient = parse_pubkey(args.fee_recipient.as_deref())?;
+    let fee = fee_from_flags(args.funded_fee_bps, args.claim_fee_lamports, args.no_fee)?;
+    if admin.is_none() && fee_recipient.is_none() && fee.is_none() {
+        anyhow::bail!("Nothing to update: pass --admin, --fee-recipient or a fee flag");
+    }
+
+    // Load keypair
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let config = config_pda(&program_id);
+    let current = fetch_config(&rpc_client, &program_id)?
+        .ok_or_else(|| anyhow::anyhow!("Config {} is not initialized; run init-config", config))?;
+    if current.admin != program.payer() {
+        anyhow::bail!("Keypair {} is not the config admin ({})", program.payer(), current.admin);
+    }
+
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Config PDA: {}", config);
+    if let Some(admin) = admin {
+        println!("Admin: {} -> {}", current.admin, admin);
+    }
+    if let Some(fee_recipient) = fee_recipient {
+        println!("Fee recipient: {} -> {}", current.fee_recipient, fee_recipient);
+    }
+    if let Some(fee) = &fee {
+        println!("Fee: {} -> {}", describe_fee(&current.fee), describe_fee(fee));
+    }
+
+    println!("\nSending update config transaction...");
+    let signature = program
+        .request()
+        .accounts(UpdateConfig {
+            admin: program.payer(),
+            config,
+        })
+        .args(airdrop_contract::instruction::UpdateConfig {
+            admin,
+            fee_recipient,
+            fee,
+        })
+        .send()?;
+
+    println!("\nConfig updated successfully!");
+    println!("Signature: {}", signature);
+
+    Ok(())
+}
+
+/// Print the program's config. No keypair is needed.
+pub fn show_config(args: ShowConfigArgs) -> Result<()> {
+    let program_id = Pubkey::from_str(&args.program_id)?;
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    let config = config_pda(&program_id);
+    match fetch_config(&rpc_client, &program_id)? {
+        Some(current) => print_config(&config, &current),
+        None => println!("Config {} is not initialized; no protocol fees are charged", config),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_from_flags() {
+        assert_eq!(fee_from_flags(None, None, false).unwrap(), None);
+        assert_eq!(
+            fee_from_flags(Some(250), None, false).unwrap(),
+            Some(ProtocolFee::FundedBps(250))
+        );
+        assert_eq!(
+            fee_from_flags(None, Some(5000), false).unwrap(),
+            Some(ProtocolFee::ClaimLamports(5000))
+        );
+        assert_eq!(fee_from_flags(None, None, true).unwrap(), Some(ProtocolFee::None));
+        assert!(fee_from_flags(Some(250), Some(5000), false).is_err());
+        assert!(fee_from_flags(Some(10_001), None, false).is_err());
+    }
+
+    #[test]
+    fn test_funding_fee_rounds_down() {
+        let fee = ProtocolFee::FundedBps(250);
+        assert_eq!(fee.funding_fee(1000), 25);
+        assert_eq!(fee.funding_fee(39), 0);
+        assert_eq!(ProtocolFee::ClaimLamports(5000).funding_fee(1000), 0);
+    }
+}
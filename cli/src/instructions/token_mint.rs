@@ -0,0 +1,347 @@
+use airdrop_contract::state::VestingSchedule;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::instruction::AccountMeta;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::TransferFeeConfig, transfer_hook, BaseStateWithExtensions,
+        StateWithExtensions,
+    },
+    offchain::{add_extra_account_metas, AccountDataResult},
+};
+use anyhow::{Context, Result};
+use solana_client::rpc_client::RpcClient;
+
+/// A mint owned by either SPL Token or Token-2022, with what is needed to move its tokens
+pub struct TokenMint {
+    pub address: Pubkey,
+    /// Program that owns the mint, and so every token account of it
+    pub token_program: Pubkey,
+    data: Vec<u8>,
+}
+
+impl TokenMint {
+    pub fn fetch(rpc_client: &RpcClient, address: &Pubkey) -> Result<Self> {
+        let account = rpc_client
+            .get_account(address)
+            .with_context(|| format!("Mint {} not found", address))?;
+        if account.owner != spl_token::ID && account.owner != spl_token_2022::ID {
+            anyhow::bail!("{} is not a token mint (owned by {})", address, account.owner);
+        }
+        Ok(Self {
+            address: *address,
+            token_program: account.owner,
+            data: account.data,
+        })
+    }
+
+    fn state(&self) -> Result<StateWithExtensions<'_, spl_token_2022::state::Mint>> {
+        StateWithExtensions::unpack(&self.data)
+            .map_err(|e| anyhow::anyhow!("Invalid mint account {}: {}", self.address, e))
+    }
+
+    pub fn decimals(&self) -> Result<u8> {
+        Ok(self.state()?.base.decimals)
+    }
+
+    /// Associated token account of `owner` under the mint's token program
+    pub fn associated_token_address(&self, owner: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(owner, &self.address, &self.token_program)
+    }
+
+    /// Amount to send so that `net` arrives after the mint's transfer fee for `epoch`
+    pub fn gross_up(&self, net: u64, epoch: u64) -> Result<u64> {
+        let state = self.state()?;
+        let Ok(config) = state.get_extension::<TransferFeeConfig>() else {
+            return Ok(net);
+        };
+        config
+            .get_epoch_fee(epoch)
+            .calculate_pre_fee_amount(net)
+            .ok_or_else(|| anyhow::anyhow!("Transfer fee on {} overflows", net))
+    }
+
+    /// Most the program can send to pay out `amount` in up to `installments` transfers. Each
+    /// installment is grossed up on its own, so each can round its fee up by a base unit, or pay
+    /// the fee's maximum in full
+    pub fn installments_gross(&self, amount: u64, installments: u64, epoch: u64) -> Result<u64> {
+        let installments = installments.clamp(1, amount.max(1));
+        if installments == 1 {
+            return self.gross_up(amount, epoch);
+        }
+        let state = self.state()?;
+        let Ok(config) = state.get_extension::<TransferFeeConfig>() else {
+            return Ok(amount);
+        };
+        let fee = config.get_epoch_fee(epoch);
+        let basis_points = u16::from(fee.transfer_fee_basis_points) as u128;
+        if basis_points == 0 {
+            return Ok(amount);
+        }
+        let installments = installments as u128;
+        let mut worst_fee = u64::from(fee.maximum_fee) as u128 * installments;
+        if basis_points < 10_000 {
+            let proportional = (amount as u128 * basis_points).div_ceil(10_000 - basis_points);
+            worst_fee = worst_fee.min(proportional + installments);
+        }
+        u64::try_from(amount as u128 + worst_fee)
+            .map_err(|_| anyhow::anyhow!("Transfer fee on {} overflows", amount))
+    }
+
+    /// Deposit that lets the vault pay each of `allocations` in full over up to `installments`
+    /// claims, covering the transfer fee on every claim as well as on the deposit itself
+    pub fn funding_amount(
+        &self,
+        allocations: impl IntoIterator<Item = u64>,
+        installments: u64,
+        epoch: u64,
+    ) -> Result<u64> {
+        let mut vault_total: u64 = 0;
+        for amount in allocations {
+            vault_total = vault_total
+                .checked_add(self.installments_gross(amount, installments, epoch)?)
+                .ok_or_else(|| anyhow::anyhow!("Total amount overflow"))?;
+        }
+        self.gross_up(vault_total, epoch)
+    }
+
+    /// Accounts the mint's transfer hook needs for a transfer of `amount` from `source` to
+    /// `destination`, passed to the program as remaining accounts. `amount` must be what the
+    /// program actually sends, fee included, since a hook may derive accounts from it. Empty for
+    /// mints without a hook.
+    pub fn transfer_hook_accounts(
+        &self,
+        rpc_client: &RpcClient,
+        source: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+    ) -> Result<Vec<AccountMeta>> {
+        self.resolve_hook_accounts(source, destination, authority, amount, |address| {
+            rpc_client
+                .get_account_with_commitment(&address, CommitmentConfig::confirmed())
+                .map(|response| response.value.map(|account| account.data))
+                .map_err(|e| e.to_string().into())
+        })
+    }
+
+    /// `transfer_hook_accounts`, reading accounts through `fetch_account_data`
+    fn resolve_hook_accounts(
+        &self,
+        source: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+        fetch_account_data: impl Fn(Pubkey) -> AccountDataResult,
+    ) -> Result<Vec<AccountMeta>> {
+        if transfer_hook::get_program_id(&self.state()?).is_none() {
+            return Ok(Vec::new());
+        }
+
+        let mut transfer = spl_token_2022::instruction::transfer_checked(
+            &self.token_program,
+            source,
+            &self.address,
+            destination,
+            authority,
+            &[],
+            amount,
+            self.decimals()?,
+        )?;
+        futures_executor::block_on(add_extra_account_metas(
+            &mut transfer,
+            source,
+            &self.address,
+            destination,
+            authority,
+            amount,
+            |address| std::future::ready(fetch_account_data(address)),
+        ))
+        .map_err(|e| {
+            anyhow::anyhow!("Failed to resolve transfer hook accounts for {}: {}", self.address, e)
+        })?;
+
+        // Everything after source, mint, destination and authority belongs to the hook
+        Ok(transfer.accounts.split_off(4))
+    }
+}
+
+/// Most installments a claim under `vesting` can be paid in. Vesting advances by the second and
+/// an installment must pay something, so there is at most one per second from the cliff through
+/// the end; without vesting a claim pays out at once
+pub fn max_installments(vesting: Option<&VestingSchedule>) -> u64 {
+    vesting.map_or(1, |vesting| {
+        u64::try_from(vesting.end as i128 - vesting.cliff as i128 + 1).unwrap_or(u64::MAX)
+    })
+}
+
+/// Current epoch, which picks the transfer fee Token-2022 charges
+pub fn current_epoch(rpc_client: &RpcClient) -> Result<u64> {
+    Ok(rpc_client.get_epoch_info()?.epoch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::solana_sdk::program_pack::Pack;
+    use spl_token_2022::extension::transfer_fee::TransferFee;
+    use spl_token_2022::extension::{BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut};
+    use spl_token_2022::extension::transfer_hook::TransferHook;
+    use spl_token_2022::state::Mint;
+    use spl_pod::optional_keys::OptionalNonZeroPubkey;
+    use spl_tlv_account_resolution::{
+        account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+    };
+    use spl_transfer_hook_interface::{
+        get_extra_account_metas_address, instruction::ExecuteInstruction,
+    };
+    use std::collections::HashMap;
+
+    fn token_mint(token_program: Pubkey, data: Vec<u8>) -> TokenMint {
+        TokenMint {
+            address: Pubkey::new_unique(),
+            token_program,
+            data,
+        }
+    }
+
+    /// A Token-2022 mint charging `basis_points`, capped at `maximum_fee`
+    fn fee_mint(basis_points: u16, maximum_fee: u64) -> TokenMint {
+        let space =
+            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])
+                .unwrap();
+        let mut data = vec![0u8; space];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        let fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: basis_points.into(),
+        };
+        config.older_transfer_fee = fee;
+        config.newer_transfer_fee = fee;
+        state.base = Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        token_mint(spl_token_2022::ID, data)
+    }
+
+    #[test]
+    fn test_legacy_mint_has_no_fee() {
+        let mut data = vec![0u8; Mint::LEN];
+        let mint = Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        Mint::pack(mint, &mut data).unwrap();
+        let mint = token_mint(spl_token::ID, data);
+        assert_eq!(mint.decimals().unwrap(), 6);
+        assert_eq!(mint.gross_up(1_000, 0).unwrap(), 1_000);
+        assert_eq!(mint.funding_amount([1_000, 500], 1, 0).unwrap(), 1_500);
+        assert_eq!(mint.funding_amount([1_000, 500], 100, 0).unwrap(), 1_500);
+    }
+
+    #[test]
+    fn test_fee_is_grossed_up_per_transfer() {
+        // 1% fee: 10_000 sent arrives as 9_900
+        let mint = fee_mint(100, 1_000_000);
+        assert_eq!(mint.gross_up(9_900, 0).unwrap(), 10_000);
+        // Each claim is grossed up, then the deposit into the vault on top
+        assert_eq!(mint.funding_amount([9_900, 9_900], 1, 0).unwrap(), 20_203);
+    }
+
+    #[test]
+    fn test_installments_are_funded_for_the_worst_case() {
+        let mint = fee_mint(100, 1_000_000);
+        // Each third of 9_900 is grossed up to 3_334, one more than a third of 10_000
+        let sent: u64 = (0..3).map(|_| mint.gross_up(3_300, 0).unwrap()).sum();
+        assert_eq!(sent, 10_002);
+        assert!(mint.installments_gross(9_900, 3, 0).unwrap() >= sent);
+        assert_eq!(mint.installments_gross(9_900, 3, 0).unwrap(), 10_003);
+        // A single installment is grossed up on its own
+        assert_eq!(mint.installments_gross(9_900, 1, 0).unwrap(), 10_000);
+        // No more installments than base units
+        assert_eq!(
+            mint.installments_gross(2, 1_000, 0).unwrap(),
+            mint.installments_gross(2, 2, 0).unwrap()
+        );
+
+        // Capped fees are paid in full by every installment
+        let mint = fee_mint(500, 10);
+        let sent: u64 = (0..4).map(|_| mint.gross_up(250_000, 0).unwrap()).sum();
+        assert_eq!(sent, 1_000_040);
+        assert_eq!(mint.installments_gross(1_000_000, 4, 0).unwrap(), sent);
+    }
+
+    #[test]
+    fn test_max_installments_follow_the_vesting_schedule() {
+        assert_eq!(max_installments(None), 1);
+        let vesting = VestingSchedule { start: 0, cliff: 10, end: 100 };
+        assert_eq!(max_installments(Some(&vesting)), 91);
+        let vesting = VestingSchedule { start: i64::MIN, cliff: i64::MIN, end: i64::MAX };
+        assert_eq!(max_installments(Some(&vesting)), u64::MAX);
+    }
+
+    #[test]
+    fn test_hook_accounts_follow_the_amount_sent() {
+        let hook_program = Pubkey::new_unique();
+        let space = ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferHook])
+            .unwrap();
+        let mut data = vec![0u8; space];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        state.init_extension::<TransferHook>(true).unwrap().program_id =
+            OptionalNonZeroPubkey::try_from(Some(hook_program)).unwrap();
+        state.base = Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        let mint = token_mint(spl_token_2022::ID, data);
+
+        // The hook wants a PDA seeded by the amount, which sits after the 8-byte discriminator
+        let extra = [ExtraAccountMeta::new_with_seeds(
+            &[Seed::InstructionData { index: 8, length: 8 }],
+            false,
+            false,
+        )
+        .unwrap()];
+        let mut validation = vec![0u8; ExtraAccountMetaList::size_of(extra.len()).unwrap()];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut validation, &extra).unwrap();
+        let accounts = HashMap::from([
+            (mint.address, mint.data.clone()),
+            (get_extra_account_metas_address(&mint.address, &hook_program), validation),
+        ]);
+
+        let (source, destination, authority) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let resolve = |amount: u64| {
+            mint.resolve_hook_accounts(&source, &destination, &authority, amount, |address| {
+                Ok(accounts.get(&address).cloned())
+            })
+            .unwrap()
+        };
+        let seeded_by = |amount: u64| {
+            Pubkey::find_program_address(&[&amount.to_le_bytes()], &hook_program).0
+        };
+        let metas = resolve(10_000);
+        assert_eq!(metas[0].pubkey, seeded_by(10_000));
+        assert!(metas.iter().any(|meta| meta.pubkey == hook_program));
+        assert_ne!(resolve(9_900)[0].pubkey, metas[0].pubkey);
+    }
+
+    #[test]
+    fn test_fee_respects_maximum() {
+        let mint = fee_mint(100, 50);
+        assert_eq!(mint.gross_up(1_000_000, 0).unwrap(), 1_000_050);
+    }
+}
@@ -0,0 +1,278 @@
+use airdrop_contract::accounts::UpdateRoot;
+use airdrop_contract::state::MerkleRoot;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair};
+use anchor_client::Client;
+use anyhow::{Context, Result};
+use solana_client::rpc_client::RpcClient;
+use std::{path::PathBuf, rc::Rc, str::FromStr};
+
+use crate::instructions::airdrop_json::{AirdropJson, ClaimInfo};
+use crate::instructions::deploy_airdrop::Network;
+use crate::instructions::program_config::FundingFee;
+use crate::instructions::token_mint::{current_epoch, max_installments, TokenMint};
+
+#[derive(Debug)]
+pub struct UpdateRootArgs {
+    pub json_path: PathBuf,
+    pub previous_path: PathBuf,
+    pub network: Network,
+    pub program_id: String,
+    pub keypair_path: PathBuf,
+}
+
+/// Check that `next` only ever raises lifetime totals relative to `previous`, and return
+/// the amount the vault must be topped up by.
+fn cumulative_top_up(previous: &AirdropJson, next: &AirdropJson) -> Result<u64> {
+    for (address, claim) in &previous.claims {
+        let previous_amount: u64 = claim
+            .amount
+            .parse()
+            .with_context(|| format!("invalid amount '{}' for {}", claim.amount, address))?;
+        let next_claim = next.claims.get(address).ok_or_else(|| {
+            anyhow::anyhow!("{} is missing from the new airdrop; cumulative totals cannot be removed", address)
+        })?;
+        let next_amount: u64 = next_claim
+            .amount
+            .parse()
+            .with_context(|| format!("invalid amount '{}' for {}", next_claim.amount, address))?;
+        if next_amount < previous_amount {
+            anyhow::bail!(
+                "{} decreases from {} to {}; cumulative totals can only grow",
+                address,
+                previous_amount,
+                next_amount
+            );
+        }
+    }
+
+    Ok(next.total_amount()? - previous.total_amount()?)
+}
+
+/// What each claimant of `next` gains over `previous`, which is all the new epoch pays them
+fn cumulative_increases(previous: &AirdropJson, next: &AirdropJson) -> Result<Vec<u64>> {
+    next.claims
+        .iter()
+        .map(|(address, claim)| {
+            let amount = |claim: &ClaimInfo| -> Result<u64> {
+                claim
+                    .amount
+                    .parse()
+                    .with_context(|| format!("invalid amount '{}' for {}", claim.amount, address))
+            };
+            let previous_amount = previous.claims.get(address).map(amount).transpose()?;
+            Ok(amount(claim)? - previous_amount.unwrap_or(0))
+        })
+        .collect()
+}
+
+pub fn update_root(args: UpdateRootArgs) -> Result<()> {
+    let previous = AirdropJson::read(&args.previous_path)?;
+    let mut next = AirdropJson::read(&args.json_path)?;
+    if previous.is_multi_mint() || next.is_multi_mint() {
+        anyhow::bail!("update-root does not support multi-mint airdrops");
+    }
+
+    let mut top_up = cumulative_top_up(&previous, &next)?;
+    let merkle_root_hash = next.merkle_root_hash()?;
+    let mint = previous.resolve_mint(None)?;
+    let program_id = Pubkey::from_str(&args.program_id)?;
+
+    // Load keypair
+    let payer = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair from {:?}: {}", args.keypair_path, e))?;
+
+    // Create Anchor client
+    let client = Client::new_with_options(
+        args.network.to_cluster(),
+        Rc::new(Keypair::try_from(payer.to_bytes().as_ref())?),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(program_id)?;
+    let authority = program.payer();
+
+    // Derive PDAs
+    let merkle_root_pda = previous.merkle_root_pda(&program_id)?;
+    let rpc_client = RpcClient::new_with_commitment(
+        args.network.rpc_url().to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let token_mint = TokenMint::fetch(&rpc_client, &mint)?;
+    let authority_token_account = token_mint.associated_token_address(&authority);
+    let merkle_root_token_account = token_mint.associated_token_address(&merkle_root_pda);
+
+    // The previous file must describe the root that is live on-chain
+    let merkle_root: MerkleRoot = program.account(merkle_root_pda)?;
+    if !merkle_root.cumulative {
+        anyhow::bail!("Airdrop {} was not deployed with --cumulative", merkle_root_pda);
+    }
+    if merkle_root.hash != previous.merkle_root_hash()? {
+        anyhow::bail!(
+            "On-chain root {} does not match the previous airdrop.json root {}",
+            hex::encode(merkle_root.hash),
+            previous.merkle_root
+        );
+    }
+    if next.hash_version != merkle_root.hash_version {
+        anyhow::bail!(
+            "New tree uses hash version {} but the airdrop was deployed with version {}; \
+            rebuild it with create-airdrop --hash-version {}",
+            next.hash_version,
+            merkle_root.hash_version,
+            merkle_root.hash_version
+        );
+    }
+    if next.leaf_encoding != merkle_root.leaf_encoding {
+        anyhow::bail!(
+            "New tree uses leaf encoding {} but the airdrop was deployed with encoding {}; \
+            rebuild it with the matching create-airdrop --leaf-encoding",
+            next.leaf_encoding,
+            merkle_root.leaf_encoding
+        );
+    }
+
+    // Cover Token-2022 transfer fees on the top-up and on every installment of each claimant's
+    // increase
+    if top_up > 0 {
+        let epoch = current_epoch(&rpc_client)?;
+        top_up = token_mint.funding_amount(
+            cumulative_increases(&previous, &next)?,
+            max_installments(merkle_root.vesting.as_ref()),
+            epoch,
+        )?;
+    }
+
+    println!("Previous root: {}", previous.merkle_root);
+    println!("New root: {}", next.merkle_root);
+    println!("Network: {}", args.network.name());
+    println!("Program ID: {}", program_id);
+    println!("Merkle root PDA: {}", merkle_root_pda);
+    println!("Epoch: {} -> {}", merkle_root.epoch, merkle_root.epoch + 1);
+    println!("Top-up amount: {}", top_up);
+    let funding_fee =
+        FundingFee::fetch(&rpc_client, &program_id, &token_mint, &authority_token_account, top_up)?;
+    if funding_fee.fee > 0 {
+        println!("Protocol fee: {}", funding_fee.fee);
+    }
+
+    println!("\nSending update root transaction...");
+
+    let mut request = program.request();
+    if let Some(ix) = funding_fee.create_account_instruction(&authority, &token_mint) {
+        request = request.instruction(ix);
+    }
+    let signature = request
+        .accounts(UpdateRoot {
+            authority,
+            merkle_root: merkle_root_pda,
+            authority_token_account,
+            merkle_root_token_account,
+            mint,
+            config: funding_fee.config,
+            fee_recipient_token_account: funding_fee.fee_recipient_token_account,
+            token_program: token_mint.token_program,
+        })
+        .accounts(token_mint.transfer_hook_accounts(
+            &rpc_client,
+            &authority_token_account,
+            &merkle_root_token_account,
+            &authority,
+            top_up,
+        )?)
+        .args(airdrop_contract::instruction::UpdateRoot {
+            merkle_root_hash,
+            amount: top_up,
+            num_leaves: next.leaf_count()?,
+        })
+        .send()?;
+
+    println!("\nRoot updated successfully!");
+    println!("Signature: {}", signature);
+    println!(
+        "Explorer: https://explorer.solana.com/tx/{}?cluster={}",
+        signature,
+        args.network.name()
+    );
+
+    // Carry the mint and PDA seeds forward so later commands find the same airdrop
+    next.mint = Some(mint.to_string());
+    next.creator = previous.creator.clone();
+    next.nonce = previous.nonce;
+    next.initial_merkle_root = Some(
+        previous
+            .initial_merkle_root
+            .clone()
+            .unwrap_or_else(|| previous.merkle_root.clone()),
+    );
+    next.write(&args.json_path)?;
+    println!("\nUpdated {:?} with mint address and initial root", args.json_path);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use airdrop_contract::constants::{HASH_VERSION_V2, LEAF_ENCODING_PUBKEY};
+
+    fn airdrop(claims: &[(&str, u64)]) -> AirdropJson {
+        AirdropJson {
+            merkle_root: hex::encode([0u8; 32]),
+            merkle_tree: Vec::new(),
+            claims: claims
+                .iter()
+                .enumerate()
+                .map(|(i, (address, amount))| {
+                    (
+                        address.to_string(),
+                        ClaimInfo {
+                            amount: amount.to_string(),
+                            leaf_index: i as u64,
+                        },
+                    )
+                })
+                .collect(),
+            mint_claims: BTreeMap::new(),
+            mint: None,
+            sol: false,
+            mint_on_claim: false,
+            hash_version: HASH_VERSION_V2,
+            leaf_encoding: LEAF_ENCODING_PUBKEY,
+            initial_merkle_root: None,
+            creator: None,
+            nonce: None,
+            voucher_signer: None,
+            voucher_amount: None,
+        }
+    }
+
+    #[test]
+    fn test_top_up_is_difference_of_totals() {
+        let previous = airdrop(&[("alice", 100), ("bob", 50)]);
+        let next = airdrop(&[("alice", 150), ("bob", 50), ("carol", 25)]);
+        assert_eq!(cumulative_top_up(&previous, &next).unwrap(), 75);
+    }
+
+    #[test]
+    fn test_decreasing_total_is_rejected() {
+        let previous = airdrop(&[("alice", 100)]);
+        let next = airdrop(&[("alice", 99)]);
+        assert!(cumulative_top_up(&previous, &next).is_err());
+    }
+
+    #[test]
+    fn test_removed_recipient_is_rejected() {
+        let previous = airdrop(&[("alice", 100), ("bob", 50)]);
+        let next = airdrop(&[("alice", 200)]);
+        assert!(cumulative_top_up(&previous, &next).is_err());
+    }
+
+    #[test]
+    fn test_increases_are_per_claimant() {
+        let previous = airdrop(&[("alice", 100), ("bob", 50)]);
+        let next = airdrop(&[("alice", 150), ("bob", 50), ("carol", 25)]);
+        assert_eq!(cumulative_increases(&previous, &next).unwrap(), [50, 0, 25]);
+    }
+}
@@ -2,8 +2,16 @@ mod instructions;
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use instructions::{
-    claim_airdrop, deploy_airdrop, get_default_keypair_path, get_default_program_id,
-    ClaimAirdropArgs, DeployAirdropArgs, Network,
+    airdrop_status, claim_airdrop, claim_link, claim_status, claim_voucher, clawback_airdrop,
+    close_airdrop, create_voucher_airdrop, decode_events, deploy_airdrop, distribute_airdrop,
+    fund_airdrop, get_default_keypair_path, get_default_program_id, init_config, issue_voucher,
+    list_airdrops, metadata_from_flags, parse_leaf_encoding, parse_timestamp, pause_airdrop,
+    show_config, unpause_airdrop, update_config, update_metadata, update_root,
+    AirdropStatusArgs, ClaimAirdropArgs, ClaimLinkArgs, ClaimStatusArgs, ClaimVoucherArgs,
+    ClawbackAirdropArgs, CloseAirdropArgs, DecodeEventsArgs, DeployAirdropArgs,
+    DistributeAirdropArgs, FundAirdropArgs, InitConfigArgs, IssueVoucherArgs, ListAirdropsArgs,
+    MetadataFields, Network, PauseAirdropArgs, ShowConfigArgs, UpdateConfigArgs, UpdateMetadataArgs,
+    UpdateRootArgs,
 };
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -18,11 +26,39 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Construct a Merkle-tree from a CSV file that contains `address,amount` rows.
+    /// An optional third `mint` column lets one tree pay out several tokens.
     /// Outputs an airdrop.json file for use with the server and deploy command.
     CreateAirdrop {
-        /// Path to CSV file (with header `address,amount`)
+        /// Path to CSV file (with header `address,amount` or `address,amount,mint`)
         #[arg(long, value_name = "FILE")]
         input: PathBuf,
+
+        /// Link mode: treat the first CSV column as a label, generate a throwaway keypair per row,
+        /// and write secret claim links built on this URL to claim_links.csv
+        #[arg(long, value_name = "URL")]
+        link_base_url: Option<String>,
+
+        /// Leaf/node hashing scheme: 2 adds domain-separation prefixes, 1 is the legacy plain SHA-256,
+        /// 3 is keccak256 with sorted pairs, verifiable by OpenZeppelin's MerkleProof on EVM chains
+        #[arg(long, default_value_t = 2)]
+        hash_version: u8,
+
+        /// How leaves encode each address: `pubkey` hashes the raw 32 bytes (cheapest to verify),
+        /// `base58` hashes the address text as older airdrops did
+        #[arg(long, value_parser = parse_leaf_encoding, default_value = "pubkey")]
+        leaf_encoding: u8,
+    },
+
+    /// Create an airdrop.json for a voucher airdrop, where claims carry a voucher
+    /// signed off-chain by `--signer` instead of a merkle proof.
+    CreateVoucherAirdrop {
+        /// Public key of the ed25519 keypair that will sign vouchers
+        #[arg(long)]
+        signer: String,
+
+        /// Total amount to deposit for vouchers
+        #[arg(long)]
+        amount: u64,
     },
 
     /// Deploy an airdrop on-chain using a previously generated airdrop.json file.
@@ -46,6 +82,83 @@ enum Commands {
         /// Path to keypair file (defaults to ~/.config/solana/id.json)
         #[arg(long)]
         keypair: Option<PathBuf>,
+
+        /// When unclaimed tokens can be clawed back, as unix seconds or RFC3339 (defaults to 30 days from now)
+        #[arg(long, value_parser = parse_timestamp)]
+        expires_at: Option<i64>,
+
+        /// When claiming opens, as unix seconds or RFC3339 (defaults to immediately)
+        #[arg(long, value_parser = parse_timestamp)]
+        start: Option<i64>,
+
+        /// When claiming closes, as unix seconds or RFC3339 (defaults to never)
+        #[arg(long, value_parser = parse_timestamp)]
+        end: Option<i64>,
+
+        /// When linear vesting begins, as unix seconds or RFC3339 (defaults to --start, or now)
+        #[arg(long, value_parser = parse_timestamp)]
+        vesting_start: Option<i64>,
+
+        /// Nothing unlocks before the cliff, as unix seconds or RFC3339 (defaults to the vesting start)
+        #[arg(long, value_parser = parse_timestamp)]
+        vesting_cliff: Option<i64>,
+
+        /// When allocations are fully vested, as unix seconds or RFC3339 (enables vesting)
+        #[arg(long, value_parser = parse_timestamp)]
+        vesting_end: Option<i64>,
+
+        /// Treat amounts as lifetime totals so later epochs can be published with update-root
+        #[arg(long)]
+        cumulative: bool,
+
+        /// Track claims in bitmap accounts allocated (and paid for) at deploy instead of one receipt per claimer
+        #[arg(long)]
+        claim_bitmap: bool,
+
+        /// Nonce mixed into the airdrop address, to deploy the same tree again from the same keypair
+        #[arg(long, default_value_t = 0)]
+        nonce: u64,
+
+        /// Airdrop native SOL instead of a token: amounts are lamports
+        #[arg(long, conflicts_with = "mint")]
+        sol: bool,
+
+        /// Hand the mint authority to the airdrop and mint each claim instead of funding a vault.
+        /// With --mint, the keypair must be the mint's current mint authority.
+        #[arg(long, conflicts_with = "sol")]
+        mint_on_claim: bool,
+
+        /// Create the new mint under Token-2022 rather than SPL Token
+        #[arg(long, conflicts_with_all = ["mint", "sol"])]
+        token_2022: bool,
+
+        /// Display name for wallets and apps (at most 32 bytes)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Link to a page or JSON document describing the airdrop
+        #[arg(long)]
+        description_uri: Option<String>,
+
+        /// Link to the airdrop's image
+        #[arg(long)]
+        image_uri: Option<String>,
+
+        /// Where recipients go to claim
+        #[arg(long)]
+        website: Option<String>,
+
+        /// TOML file with name, description_uri, image_uri and website keys; flags take precedence
+        #[arg(long, value_name = "FILE")]
+        metadata: Option<PathBuf>,
+
+        /// Only pay claimants who hold this token or NFT mint when they claim
+        #[arg(long)]
+        gate_mint: Option<String>,
+
+        /// Base units of the gate mint a claimant must hold (defaults to 1)
+        #[arg(long, requires = "gate_mint")]
+        gate_min_balance: Option<u64>,
     },
 
     /// Claim tokens from an airdrop using a previously generated airdrop.json file.
@@ -54,7 +167,8 @@ enum Commands {
         #[arg(long, value_name = "FILE")]
         json: PathBuf,
 
-        /// Token mint address (optional - reads from airdrop.json if not provided)
+        /// Token mint address (optional - reads from airdrop.json if not provided).
+        /// For multi-mint airdrops, claims only this mint instead of all of them.
         #[arg(long)]
         mint: Option<String>,
 
@@ -73,53 +187,901 @@ enum Commands {
         /// Path to keypair file (defaults to ~/.config/solana/id.json)
         #[arg(long)]
         keypair: Option<PathBuf>,
+
+        /// Keypair that pays fees and rent on behalf of the claimant (defaults to --keypair)
+        #[arg(long, value_name = "KEYPAIR")]
+        fee_payer: Option<PathBuf>,
     },
-}
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
-    match cli.command {
-        Commands::CreateAirdrop { input } => {
-            instructions::create_airdrop(&input)?;
-        }
-        Commands::DeployAirdrop {
-            json,
-            mint,
-            network,
-            program_id,
-            keypair,
-        } => {
-            let network = Network::from_str(&network)?;
-            let program_id = program_id.unwrap_or_else(get_default_program_id);
-            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+    /// Claim an allocation using a secret claim link from `create-airdrop --link-base-url`.
+    ClaimLink {
+        /// Path to airdrop.json file generated by create-airdrop
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
 
-            deploy_airdrop(DeployAirdropArgs {
-                json_path: json,
-                mint,
-                network,
-                program_id,
-                keypair_path,
-            })?;
-        }
-        Commands::ClaimAirdrop {
-            json,
-            mint,
-            address,
-            network,
-            program_id,
-            keypair,
-        } => {
-            let network = Network::from_str(&network)?;
-            let program_id = program_id.unwrap_or_else(get_default_program_id);
-            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+        /// Claim link URL (or the base58 secret key it contains)
+        #[arg(long)]
+        link: String,
 
-            claim_airdrop(ClaimAirdropArgs {
-                json_path: json,
-                mint,
-                address,
-                network,
-                program_id,
-                keypair_path,
+        /// Wallet to receive the tokens (defaults to keypair's address)
+        #[arg(long)]
+        destination: Option<String>,
+
+        /// Token mint address (optional - reads from airdrop.json if not provided)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Keypair that pays fees and rent (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Sign a voucher for a voucher airdrop (offline, using the voucher signer keypair).
+    IssueVoucher {
+        /// Path to the voucher airdrop's airdrop.json
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Address that receives the tokens
+        #[arg(long)]
+        recipient: String,
+
+        /// Amount the recipient may claim
+        #[arg(long)]
+        amount: u64,
+
+        /// Unique voucher nonce (random if not provided)
+        #[arg(long)]
+        nonce: Option<u64>,
+
+        /// When the voucher stops being valid, as unix seconds or RFC3339 (defaults to 7 days from now)
+        #[arg(long, value_parser = parse_timestamp)]
+        expires_at: Option<i64>,
+
+        /// Where to write the signed voucher
+        #[arg(long, value_name = "FILE", default_value = "voucher.json")]
+        output: PathBuf,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Voucher signer keypair (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Redeem a signed voucher; tokens go to the voucher's recipient.
+    ClaimVoucher {
+        /// Path to the voucher airdrop's airdrop.json
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Path to the voucher file written by issue-voucher
+        #[arg(long, value_name = "FILE")]
+        voucher: PathBuf,
+
+        /// Token mint address (optional - reads from airdrop.json if not provided)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Keypair that pays fees and rent (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Show which allocations of a bitmap airdrop have been claimed, read from its claim bitmaps.
+    ClaimStatus {
+        /// Path to airdrop.json file generated by create-airdrop
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Only report this address (defaults to a summary of every allocation)
+        #[arg(long)]
+        address: Option<String>,
+
+        /// Network to query (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+    },
+
+    /// Show an airdrop's on-chain accounting (funded, claimed, claim count) and vault balance.
+    AirdropStatus {
+        /// Path to airdrop.json file generated by create-airdrop
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Network to query (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+    },
+
+    /// Decode the events the airdrop program emitted in a transaction, one JSON object per line.
+    DecodeEvents {
+        /// Transaction signature
+        #[arg(long)]
+        signature: String,
+
+        /// Network to query (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+    },
+
+    /// Reclaim unclaimed tokens from an expired airdrop (authority only).
+    ClawbackAirdrop {
+        /// Path to airdrop.json file generated by create-airdrop
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Wallet to receive the unclaimed tokens (defaults to keypair's address)
+        #[arg(long, conflicts_with = "burn")]
+        destination: Option<String>,
+
+        /// Burn the unclaimed tokens instead of transferring them
+        #[arg(long)]
+        burn: bool,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Close an expired, emptied airdrop and its claim bitmaps or receipts to reclaim rent.
+    /// Rent from the merkle root, vault and bitmaps goes to the authority; receipt rent goes back to whoever paid for each claim.
+    CloseAirdrop {
+        /// Path to airdrop.json file generated by create-airdrop
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Number of claim bitmaps or receipts to close per transaction
+        #[arg(long, default_value_t = 10)]
+        batch_size: usize,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Push every unclaimed allocation to its recipient's token account (authority only).
+    /// Claim receipts record what was paid, so re-running resumes after a failure.
+    DistributeAirdrop {
+        /// Path to airdrop.json file updated by deploy-airdrop
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Number of recipients to pay per transaction
+        #[arg(long, default_value_t = 1)]
+        batch_size: usize,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Top up the vault of a deployed airdrop from your token account (anyone may fund an open vault).
+    FundAirdrop {
+        /// Path to airdrop.json file updated by deploy-airdrop (provides the mint)
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Amount of tokens to add, in base units
+        #[arg(long)]
+        amount: u64,
+
+        /// Mint to fund, for multi-mint airdrops (defaults to the mint in the JSON)
+        #[arg(long)]
+        mint: Option<String>,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Halt claims on an airdrop (authority only).
+    PauseAirdrop {
+        /// Path to airdrop.json file generated by create-airdrop
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Resume claims on a paused airdrop (authority only).
+    UnpauseAirdrop {
+        /// Path to airdrop.json file generated by create-airdrop
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Publish the next epoch of a cumulative airdrop and top up its vault (authority only).
+    UpdateRoot {
+        /// Path to the new airdrop.json file generated by create-airdrop
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Path to the airdrop.json file currently live on-chain
+        #[arg(long, value_name = "FILE")]
+        previous: PathBuf,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Create the program-wide config holding the admin, fee recipient and protocol fee.
+    /// Run once by the program's upgrade authority, which names the admin (itself by default).
+    InitConfig {
+        /// Config admin (defaults to the keypair)
+        #[arg(long)]
+        admin: Option<String>,
+
+        /// Wallet that receives protocol fees (defaults to the admin)
+        #[arg(long)]
+        fee_recipient: Option<String>,
+
+        /// Charge this many basis points of every amount deposited into an airdrop
+        #[arg(long, conflicts_with = "claim_fee_lamports")]
+        funded_fee_bps: Option<u16>,
+
+        /// Charge a flat number of lamports per claim, paid by the claim's fee payer
+        #[arg(long)]
+        claim_fee_lamports: Option<u64>,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to the program upgrade authority's keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Change the program config's admin, fee recipient or protocol fee (config admin only).
+    UpdateConfig {
+        /// Hand the admin role to this address
+        #[arg(long)]
+        admin: Option<String>,
+
+        /// Wallet that receives protocol fees
+        #[arg(long)]
+        fee_recipient: Option<String>,
+
+        /// Charge this many basis points of every amount deposited into an airdrop
+        #[arg(long, conflicts_with_all = ["claim_fee_lamports", "no_fee"])]
+        funded_fee_bps: Option<u16>,
+
+        /// Charge a flat number of lamports per claim, paid by the claim's fee payer
+        #[arg(long, conflicts_with = "no_fee")]
+        claim_fee_lamports: Option<u64>,
+
+        /// Stop charging a protocol fee
+        #[arg(long)]
+        no_fee: bool,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Show the program config: admin, fee recipient and protocol fee.
+    ShowConfig {
+        /// Network to query (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+    },
+
+    /// Set or change an airdrop's name, image and links (authority only).
+    /// Fields that are not given keep their current value.
+    UpdateMetadata {
+        /// Path to airdrop.json file
+        #[arg(long, value_name = "FILE")]
+        json: PathBuf,
+
+        /// Display name for wallets and apps (at most 32 bytes)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Link to a page or JSON document describing the airdrop
+        #[arg(long)]
+        description_uri: Option<String>,
+
+        /// Link to the airdrop's image
+        #[arg(long)]
+        image_uri: Option<String>,
+
+        /// Where recipients go to claim
+        #[arg(long)]
+        website: Option<String>,
+
+        /// TOML file with name, description_uri, image_uri and website keys; flags take precedence
+        #[arg(long, value_name = "FILE")]
+        metadata: Option<PathBuf>,
+
+        /// Network to use (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Path to the authority keypair file (defaults to ~/.config/solana/id.json)
+        #[arg(long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// List the program's airdrops with their metadata.
+    ListAirdrops {
+        /// Only list airdrops created by this authority
+        #[arg(long)]
+        authority: Option<String>,
+
+        /// Network to query (devnet, testnet, mainnet, localnet)
+        #[arg(long, default_value = "devnet")]
+        network: String,
+
+        /// Program ID (defaults to the deployed airdrop contract)
+        #[arg(long)]
+        program_id: Option<String>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Commands::CreateAirdrop {
+            input,
+            link_base_url,
+            hash_version,
+            leaf_encoding,
+        } => {
+            instructions::create_airdrop(
+                &input,
+                link_base_url.as_deref(),
+                hash_version,
+                leaf_encoding,
+            )?;
+        }
+        Commands::CreateVoucherAirdrop { signer, amount } => {
+            create_voucher_airdrop(&signer, amount)?;
+        }
+        Commands::DeployAirdrop {
+            json,
+            mint,
+            network,
+            program_id,
+            keypair,
+            expires_at,
+            start,
+            end,
+            vesting_start,
+            vesting_cliff,
+            vesting_end,
+            cumulative,
+            claim_bitmap,
+            nonce,
+            sol,
+            mint_on_claim,
+            token_2022,
+            name,
+            description_uri,
+            image_uri,
+            website,
+            metadata,
+            gate_mint,
+            gate_min_balance,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+            let metadata = metadata_from_flags(
+                metadata.as_deref(),
+                MetadataFields {
+                    name,
+                    description_uri,
+                    image_uri,
+                    website,
+                },
+            )?;
+
+            deploy_airdrop(DeployAirdropArgs {
+                json_path: json,
+                mint,
+                network,
+                program_id,
+                keypair_path,
+                expires_at,
+                claim_start: start,
+                claim_end: end,
+                vesting_start,
+                vesting_cliff,
+                vesting_end,
+                cumulative,
+                claim_bitmap,
+                nonce,
+                sol,
+                mint_on_claim,
+                token_2022,
+                metadata,
+                gate_mint,
+                gate_min_balance,
+            })?;
+        }
+        Commands::ClaimAirdrop {
+            json,
+            mint,
+            address,
+            network,
+            program_id,
+            keypair,
+            fee_payer,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            claim_airdrop(ClaimAirdropArgs {
+                json_path: json,
+                mint,
+                address,
+                network,
+                program_id,
+                keypair_path,
+                fee_payer_path: fee_payer,
+            })?;
+        }
+        Commands::ClaimLink {
+            json,
+            link,
+            destination,
+            mint,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            claim_link(ClaimLinkArgs {
+                json_path: json,
+                link,
+                destination,
+                mint,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::IssueVoucher {
+            json,
+            recipient,
+            amount,
+            nonce,
+            expires_at,
+            output,
+            program_id,
+            keypair,
+        } => {
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            issue_voucher(IssueVoucherArgs {
+                json_path: json,
+                recipient,
+                amount,
+                nonce,
+                expires_at,
+                output_path: output,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::ClaimVoucher {
+            json,
+            voucher,
+            mint,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            claim_voucher(ClaimVoucherArgs {
+                json_path: json,
+                voucher_path: voucher,
+                mint,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::ClaimStatus {
+            json,
+            address,
+            network,
+            program_id,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+
+            claim_status(ClaimStatusArgs {
+                json_path: json,
+                address,
+                network,
+                program_id,
+            })?;
+        }
+        Commands::AirdropStatus {
+            json,
+            network,
+            program_id,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+
+            airdrop_status(AirdropStatusArgs {
+                json_path: json,
+                network,
+                program_id,
+            })?;
+        }
+        Commands::DecodeEvents {
+            signature,
+            network,
+            program_id,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+
+            decode_events(DecodeEventsArgs {
+                signature,
+                network,
+                program_id,
+            })?;
+        }
+        Commands::ClawbackAirdrop {
+            json,
+            destination,
+            burn,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            clawback_airdrop(ClawbackAirdropArgs {
+                json_path: json,
+                destination,
+                burn,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::CloseAirdrop {
+            json,
+            batch_size,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            close_airdrop(CloseAirdropArgs {
+                json_path: json,
+                batch_size,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::DistributeAirdrop {
+            json,
+            batch_size,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            distribute_airdrop(DistributeAirdropArgs {
+                json_path: json,
+                batch_size,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::FundAirdrop {
+            json,
+            amount,
+            mint,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            fund_airdrop(FundAirdropArgs {
+                json_path: json,
+                amount,
+                mint,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::PauseAirdrop {
+            json,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            pause_airdrop(PauseAirdropArgs {
+                json_path: json,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::UnpauseAirdrop {
+            json,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            unpause_airdrop(PauseAirdropArgs {
+                json_path: json,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::UpdateRoot {
+            json,
+            previous,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            update_root(UpdateRootArgs {
+                json_path: json,
+                previous_path: previous,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::InitConfig {
+            admin,
+            fee_recipient,
+            funded_fee_bps,
+            claim_fee_lamports,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            init_config(InitConfigArgs {
+                admin,
+                fee_recipient,
+                funded_fee_bps,
+                claim_fee_lamports,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::UpdateConfig {
+            admin,
+            fee_recipient,
+            funded_fee_bps,
+            claim_fee_lamports,
+            no_fee,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            update_config(UpdateConfigArgs {
+                admin,
+                fee_recipient,
+                funded_fee_bps,
+                claim_fee_lamports,
+                no_fee,
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::ShowConfig {
+            network,
+            program_id,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+
+            show_config(ShowConfigArgs {
+                network,
+                program_id,
+            })?;
+        }
+        Commands::UpdateMetadata {
+            json,
+            name,
+            description_uri,
+            image_uri,
+            website,
+            metadata,
+            network,
+            program_id,
+            keypair,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+            let keypair_path = keypair.unwrap_or_else(get_default_keypair_path);
+
+            update_metadata(UpdateMetadataArgs {
+                json_path: json,
+                metadata_path: metadata,
+                fields: MetadataFields {
+                    name,
+                    description_uri,
+                    image_uri,
+                    website,
+                },
+                network,
+                program_id,
+                keypair_path,
+            })?;
+        }
+        Commands::ListAirdrops {
+            authority,
+            network,
+            program_id,
+        } => {
+            let network = Network::from_str(&network)?;
+            let program_id = program_id.unwrap_or_else(get_default_program_id);
+
+            list_airdrops(ListAirdropsArgs {
+                authority,
+                network,
+                program_id,
             })?;
         }
     }
@@ -1,12 +1,11 @@
 use std::fs::{self, File};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use tempfile::TempDir;
 
 /// Test context containing temporary directories and keypair paths
 pub struct TestContext {
-    #[allow(dead_code)] // Kept to ensure TempDir lives as long as the context
     pub temp_dir: TempDir,
     pub authority_keypair_path: PathBuf,
     pub claimant_keypair_paths: Vec<PathBuf>,
@@ -86,6 +85,12 @@ impl TestContext {
         Ok(())
     }
 
+    /// Run create-airdrop over the context's CSV with extra `flags`, returning the path of the
+    /// airdrop.json it wrote (`json_path`)
+    pub fn create_airdrop(&self, flags: &[&str]) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        create_airdrop_in(self.temp_dir.path(), &self.csv_path, flags)
+    }
+
     /// Read the generated airdrop.json
     pub fn read_airdrop_json(&self) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
         let content = fs::read_to_string(&self.json_path)?;
@@ -95,7 +100,7 @@ impl TestContext {
 }
 
 /// Generate a new Solana keypair at the given path
-fn generate_keypair(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+pub fn generate_keypair(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
     let status = Command::new("solana-keygen")
         .args([
             "new",
@@ -117,7 +122,7 @@ fn generate_keypair(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
 }
 
 /// Get the public key from a keypair file
-fn get_pubkey(keypair_path: &PathBuf) -> Result<String, Box<dyn std::error::Error>> {
+pub fn get_pubkey(keypair_path: &PathBuf) -> Result<String, Box<dyn std::error::Error>> {
     let output = Command::new("solana-keygen")
         .args(["pubkey", keypair_path.to_str().unwrap()])
         .output()?;
@@ -139,6 +144,20 @@ pub fn cli_binary_path() -> PathBuf {
     PathBuf::from(env!("CARGO_BIN_EXE_cli"))
 }
 
+/// Run create-airdrop over `csv` with extra `flags` inside `dir`. The command writes
+/// airdrop.json, and claim_links.csv in link mode, to its working directory; the returned path
+/// is that airdrop.json.
+pub fn create_airdrop_in(
+    dir: &Path,
+    csv: &Path,
+    flags: &[&str],
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut args = vec!["create-airdrop", "--input", csv.to_str().unwrap()];
+    args.extend_from_slice(flags);
+    run_cli_success_in(dir, &args)?;
+    Ok(dir.join("airdrop.json"))
+}
+
 /// Run a CLI command and return the output
 pub fn run_cli(args: &[&str]) -> Result<std::process::Output, Box<dyn std::error::Error>> {
     let output = Command::new(cli_binary_path()).args(args).output()?;
@@ -159,3 +178,22 @@ pub fn run_cli_success(args: &[&str]) -> Result<String, Box<dyn std::error::Erro
     }
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
+
+/// Run CLI command inside `dir`, for commands that write their output to the working directory,
+/// and assert success
+pub fn run_cli_success_in(dir: &Path, args: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+    let output = Command::new(cli_binary_path())
+        .args(args)
+        .current_dir(dir)
+        .output()?;
+    if !output.status.success() {
+        return Err(format!(
+            "CLI command failed: {}\nstdout: {}\nstderr: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
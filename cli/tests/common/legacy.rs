@@ -0,0 +1,142 @@
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_token::solana_program::program_option::COption;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use super::fixtures::{create_airdrop_in, generate_keypair, get_pubkey, TestClaimant};
+use super::validator::PROGRAM_ID;
+
+/// An airdrop as the program's first release left it on-chain: a funded vault owned by a
+/// `MerkleRoot` at the root-only PDA, in the original `{hash, bump, mint}` layout, and an
+/// airdrop.json with a `mint` but no `creator`. The shared validator loads it at startup.
+pub struct LegacyAirdrop {
+    pub json_path: PathBuf,
+    pub claimants: Vec<TestClaimant>,
+    pub mint: String,
+    pub merkle_root_pda: String,
+}
+
+/// Write the legacy airdrop's files into `dir`, returning it along with the accounts the
+/// validator must load, as `(address, account JSON file)`
+pub fn write_legacy_airdrop(
+    dir: &Path,
+) -> Result<(LegacyAirdrop, Vec<(Pubkey, PathBuf)>), Box<dyn std::error::Error>> {
+    let amounts = [100u64, 200u64];
+    let mut claimants = Vec::new();
+    let mut csv = File::create(dir.join("legacy.csv"))?;
+    writeln!(csv, "address,amount")?;
+    for (i, amount) in amounts.iter().enumerate() {
+        let keypair_path = dir.join(format!("legacy_claimant_{}.json", i));
+        generate_keypair(&keypair_path)?;
+        let address = get_pubkey(&keypair_path)?;
+        writeln!(csv, "{},{}", address, amount)?;
+        claimants.push(TestClaimant {
+            address,
+            amount: *amount,
+            keypair_path,
+        });
+    }
+    drop(csv);
+
+    // The first release hashed base58 addresses into plain sha256 leaves and nodes
+    let json_path = create_airdrop_in(
+        dir,
+        &dir.join("legacy.csv"),
+        &["--hash-version", "1", "--leaf-encoding", "base58"],
+    )?;
+
+    // Its deploy recorded only the mint
+    let mut json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&json_path)?)?;
+    let mint = Keypair::new().pubkey();
+    json["mint"] = serde_json::Value::String(mint.to_string());
+    fs::write(&json_path, serde_json::to_string_pretty(&json)?)?;
+
+    let hash: [u8; 32] = hex::decode(json["merkle_root"].as_str().ok_or("No merkle_root")?)?
+        .try_into()
+        .map_err(|_| "merkle_root must be 32 bytes")?;
+    let program_id = Pubkey::from_str(PROGRAM_ID)?;
+    let (merkle_root, bump) =
+        Pubkey::find_program_address(&[b"merkle_root", hash.as_ref()], &program_id);
+    let vault = get_associated_token_address(&merkle_root, &mint);
+    let total: u64 = amounts.iter().sum();
+
+    let mut mint_data = vec![0u8; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: COption::None,
+        supply: total,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut mint_data);
+
+    // Anchor's discriminator for `MerkleRoot`, then the fields the first release stored
+    let mut merkle_root_data = Sha256::digest(b"account:MerkleRoot")[..8].to_vec();
+    merkle_root_data.extend_from_slice(&hash);
+    merkle_root_data.push(bump);
+    merkle_root_data.extend_from_slice(mint.as_ref());
+
+    let mut vault_data = vec![0u8; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint,
+        owner: merkle_root,
+        amount: total,
+        delegate: COption::None,
+        state: spl_token::state::AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(&mut vault_data);
+
+    let accounts = [
+        (mint, mint_data, spl_token::ID),
+        (merkle_root, merkle_root_data, program_id),
+        (vault, vault_data, spl_token::ID),
+    ];
+    let mut account_files = Vec::new();
+    for (address, data, owner) in accounts {
+        let path = dir.join(format!("{}.json", address));
+        write_account_file(&path, &address, &data, &owner)?;
+        account_files.push((address, path));
+    }
+
+    let legacy = LegacyAirdrop {
+        json_path,
+        claimants,
+        mint: mint.to_string(),
+        merkle_root_pda: merkle_root.to_string(),
+    };
+    Ok((legacy, account_files))
+}
+
+/// Write a rent-exempt account in the JSON format `solana-test-validator --account` loads
+fn write_account_file(
+    path: &Path,
+    address: &Pubkey,
+    data: &[u8],
+    owner: &Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let account = serde_json::json!({
+        "pubkey": address.to_string(),
+        "account": {
+            "lamports": Rent::default().minimum_balance(data.len()),
+            "data": [base64::engine::general_purpose::STANDARD.encode(data), "base64"],
+            "owner": owner.to_string(),
+            "executable": false,
+            "rentEpoch": 0,
+            "space": data.len(),
+        },
+    });
+    fs::write(path, serde_json::to_string_pretty(&account)?)?;
+    Ok(())
+}
@@ -1,5 +1,10 @@
 pub mod fixtures;
+pub mod legacy;
 pub mod validator;
 
 pub use fixtures::*;
-pub use validator::{fund_account, get_shared_validator, verify_program_loaded, PROGRAM_ID};
+pub use validator::{
+    compute_units_consumed, create_mint, create_token_2022_fee_mint, fund_account,
+    get_shared_validator, lamport_balance, legacy_airdrop, mint_tokens, token_balance,
+    upgrade_authority_keypair_path, verify_program_loaded, PROGRAM_ID,
+};
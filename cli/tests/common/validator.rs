@@ -1,9 +1,13 @@
+use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
 use std::sync::OnceLock;
 use std::thread;
 use std::time::{Duration, Instant};
 use tempfile::TempDir;
 
+use super::fixtures::generate_keypair;
+use super::legacy::{write_legacy_airdrop, LegacyAirdrop};
+
 pub const PROGRAM_ID: &str = "F6fHBUyYyaW14CxjSnJjLck8vMmWew3PbCnt5TMqRdZX";
 pub const RPC_URL: &str = "http://localhost:8899";
 
@@ -13,6 +17,10 @@ static SHARED_VALIDATOR: OnceLock<SharedValidator> = OnceLock::new();
 struct SharedValidator {
     process: Child,
     _ledger_dir: TempDir,
+    _keys_dir: TempDir,
+    /// Upgrade authority of the loaded program, the only signer `init-config` accepts
+    upgrade_authority_keypair_path: PathBuf,
+    legacy_airdrop: LegacyAirdrop,
 }
 
 impl Drop for SharedValidator {
@@ -31,11 +39,24 @@ pub fn get_shared_validator() -> Result<(), Box<dyn std::error::Error>> {
     SHARED_VALIDATOR.get_or_init(|| {
         let ledger_dir = TempDir::new().expect("Failed to create temp dir");
         let so_path = get_program_so_path();
+        // Outside the ledger, which --reset wipes
+        let keys_dir = TempDir::new().expect("Failed to create temp dir");
+        let upgrade_authority_keypair_path = keys_dir.path().join("upgrade_authority.json");
+        generate_keypair(&upgrade_authority_keypair_path)
+            .expect("Failed to generate upgrade authority keypair");
+
+        let (legacy_airdrop, legacy_accounts) =
+            write_legacy_airdrop(keys_dir.path()).expect("Failed to write legacy airdrop");
 
-        let process = Command::new("solana-test-validator")
-            .arg("--bpf-program")
+        let mut command = Command::new("solana-test-validator");
+        for (address, account_file) in &legacy_accounts {
+            command.arg("--account").arg(address.to_string()).arg(account_file);
+        }
+        let process = command
+            .arg("--upgradeable-program")
             .arg(PROGRAM_ID)
             .arg(&so_path)
+            .arg(&upgrade_authority_keypair_path)
             .arg("--ledger")
             .arg(ledger_dir.path())
             .arg("--reset")
@@ -64,11 +85,30 @@ pub fn get_shared_validator() -> Result<(), Box<dyn std::error::Error>> {
         SharedValidator {
             process,
             _ledger_dir: ledger_dir,
+            _keys_dir: keys_dir,
+            upgrade_authority_keypair_path,
+            legacy_airdrop,
         }
     });
     Ok(())
 }
 
+/// Keypair of the program's upgrade authority on the shared validator
+pub fn upgrade_authority_keypair_path() -> PathBuf {
+    get_shared_validator().expect("Failed to start validator");
+    SHARED_VALIDATOR
+        .get()
+        .expect("Validator not started")
+        .upgrade_authority_keypair_path
+        .clone()
+}
+
+/// Airdrop of the program's first release that the shared validator was started with
+pub fn legacy_airdrop() -> &'static LegacyAirdrop {
+    get_shared_validator().expect("Failed to start validator");
+    &SHARED_VALIDATOR.get().expect("Validator not started").legacy_airdrop
+}
+
 /// Path to the compiled airdrop contract .so file
 fn get_program_so_path() -> String {
     let manifest_dir = env!("CARGO_MANIFEST_DIR");
@@ -102,6 +142,38 @@ pub fn fund_account(address: &str, amount_sol: u64) -> Result<(), Box<dyn std::e
     Ok(())
 }
 
+/// Compute units consumed by a confirmed transaction, as reported by `solana confirm`
+pub fn compute_units_consumed(signature: &str) -> Result<u64, Box<dyn std::error::Error>> {
+    let output = Command::new("solana")
+        .args(["confirm", "-v", signature, "--output", "json", "-u", RPC_URL])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to fetch transaction {}: {}",
+            signature,
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    find_u64(&json, "computeUnitsConsumed")
+        .ok_or_else(|| format!("No computeUnitsConsumed in transaction {}", signature).into())
+}
+
+/// Depth-first search for a numeric field anywhere in a JSON document
+fn find_u64(value: &serde_json::Value, key: &str) -> Option<u64> {
+    match value {
+        serde_json::Value::Object(map) => map
+            .get(key)
+            .and_then(|v| v.as_u64())
+            .or_else(|| map.values().find_map(|v| find_u64(v, key))),
+        serde_json::Value::Array(items) => items.iter().find_map(|v| find_u64(v, key)),
+        _ => None,
+    }
+}
+
 /// Verify a program is loaded on the validator
 pub fn verify_program_loaded(program_id: &str) -> Result<(), Box<dyn std::error::Error>> {
     let output = Command::new("solana")
@@ -119,3 +191,190 @@ pub fn verify_program_loaded(program_id: &str) -> Result<(), Box<dyn std::error:
 
     Ok(())
 }
+
+/// Mint `amount` base units of `mint` into the mint authority's associated token account
+pub fn mint_tokens(
+    mint: &str,
+    mint_authority_keypair_path: &std::path::Path,
+    amount: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+    use anchor_spl::token_2022::spl_token_2022;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::pubkey::Pubkey;
+    use solana_sdk::signature::{read_keypair_file, Signer};
+    use solana_sdk::transaction::Transaction;
+    use std::str::FromStr;
+
+    let authority = read_keypair_file(mint_authority_keypair_path)?;
+    let mint = Pubkey::from_str(mint)?;
+
+    let rpc_client =
+        RpcClient::new_with_commitment(RPC_URL.to_string(), CommitmentConfig::confirmed());
+    // Works for SPL Token and Token-2022 mints alike
+    let token_program = rpc_client.get_account(&mint)?.owner;
+    let token_account =
+        get_associated_token_address_with_program_id(&authority.pubkey(), &mint, &token_program);
+    let mint_to_ix = spl_token_2022::instruction::mint_to(
+        &token_program,
+        &mint,
+        &token_account,
+        &authority.pubkey(),
+        &[],
+        amount,
+    )?;
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_to_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        rpc_client.get_latest_blockhash()?,
+    );
+    rpc_client.send_and_confirm_transaction(&tx)?;
+    Ok(())
+}
+
+/// Create a fresh mint controlled by the given keypair, plus that keypair's associated token account
+pub fn create_mint(
+    mint_authority_keypair_path: &std::path::Path,
+) -> Result<String, Box<dyn std::error::Error>> {
+    use anchor_spl::token::spl_token;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::program_pack::Pack;
+    use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+    #[allow(deprecated)]
+    use solana_sdk::system_instruction;
+    use solana_sdk::transaction::Transaction;
+
+    let authority = read_keypair_file(mint_authority_keypair_path)?;
+    let mint = Keypair::new();
+
+    let rpc_client =
+        RpcClient::new_with_commitment(RPC_URL.to_string(), CommitmentConfig::confirmed());
+    let rent = rpc_client.get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)?;
+    let instructions = [
+        system_instruction::create_account(
+            &authority.pubkey(),
+            &mint.pubkey(),
+            rent,
+            spl_token::state::Mint::LEN as u64,
+            &spl_token::ID,
+        ),
+        spl_token::instruction::initialize_mint(
+            &spl_token::ID,
+            &mint.pubkey(),
+            &authority.pubkey(),
+            None,
+            6,
+        )?,
+        spl_associated_token_account::instruction::create_associated_token_account(
+            &authority.pubkey(),
+            &authority.pubkey(),
+            &mint.pubkey(),
+            &spl_token::ID,
+        ),
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&authority.pubkey()),
+        &[&authority, &mint],
+        rpc_client.get_latest_blockhash()?,
+    );
+    rpc_client.send_and_confirm_transaction(&tx)?;
+    Ok(mint.pubkey().to_string())
+}
+
+/// Create a Token-2022 mint charging a transfer fee of `basis_points` (capped at `maximum_fee`),
+/// plus the mint authority's associated token account
+pub fn create_token_2022_fee_mint(
+    mint_authority_keypair_path: &std::path::Path,
+    basis_points: u16,
+    maximum_fee: u64,
+) -> Result<String, Box<dyn std::error::Error>> {
+    use anchor_spl::token_2022::spl_token_2022;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+    #[allow(deprecated)]
+    use solana_sdk::system_instruction;
+    use solana_sdk::transaction::Transaction;
+    use spl_token_2022::extension::{transfer_fee, ExtensionType};
+
+    let authority = read_keypair_file(mint_authority_keypair_path)?;
+    let mint = Keypair::new();
+
+    let rpc_client =
+        RpcClient::new_with_commitment(RPC_URL.to_string(), CommitmentConfig::confirmed());
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])?;
+    let rent = rpc_client.get_minimum_balance_for_rent_exemption(space)?;
+    let instructions = [
+        system_instruction::create_account(
+            &authority.pubkey(),
+            &mint.pubkey(),
+            rent,
+            space as u64,
+            &spl_token_2022::ID,
+        ),
+        transfer_fee::instruction::initialize_transfer_fee_config(
+            &spl_token_2022::ID,
+            &mint.pubkey(),
+            Some(&authority.pubkey()),
+            Some(&authority.pubkey()),
+            basis_points,
+            maximum_fee,
+        )?,
+        spl_token_2022::instruction::initialize_mint(
+            &spl_token_2022::ID,
+            &mint.pubkey(),
+            &authority.pubkey(),
+            None,
+            6,
+        )?,
+        spl_associated_token_account::instruction::create_associated_token_account(
+            &authority.pubkey(),
+            &authority.pubkey(),
+            &mint.pubkey(),
+            &spl_token_2022::ID,
+        ),
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&authority.pubkey()),
+        &[&authority, &mint],
+        rpc_client.get_latest_blockhash()?,
+    );
+    rpc_client.send_and_confirm_transaction(&tx)?;
+    Ok(mint.pubkey().to_string())
+}
+
+/// Balance of `owner`'s associated token account for `mint`, in base units
+pub fn token_balance(owner: &str, mint: &str) -> Result<u64, Box<dyn std::error::Error>> {
+    use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    let mint = Pubkey::from_str(mint)?;
+    let rpc_client =
+        RpcClient::new_with_commitment(RPC_URL.to_string(), CommitmentConfig::confirmed());
+    let token_program = rpc_client.get_account(&mint)?.owner;
+    let token_account =
+        get_associated_token_address_with_program_id(&Pubkey::from_str(owner)?, &mint, &token_program);
+    Ok(rpc_client.get_token_account_balance(&token_account)?.amount.parse()?)
+}
+
+/// Lamport balance of `address`
+pub fn lamport_balance(address: &str) -> Result<u64, Box<dyn std::error::Error>> {
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    let rpc_client =
+        RpcClient::new_with_commitment(RPC_URL.to_string(), CommitmentConfig::confirmed());
+    Ok(rpc_client.get_balance(&Pubkey::from_str(address)?)?)
+}
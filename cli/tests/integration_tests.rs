@@ -9,8 +9,10 @@
 mod common;
 
 use common::{
-    cli_binary_path, fund_account, get_shared_validator, run_cli, run_cli_success,
-    verify_program_loaded, TestContext, PROGRAM_ID,
+    cli_binary_path, compute_units_consumed, create_airdrop_in, create_mint,
+    create_token_2022_fee_mint, fund_account, get_pubkey, get_shared_validator, lamport_balance,
+    legacy_airdrop, mint_tokens, run_cli, run_cli_success, run_cli_success_in, token_balance,
+    upgrade_authority_keypair_path, verify_program_loaded, TestContext, PROGRAM_ID,
 };
 use serial_test::serial;
 
@@ -85,6 +87,10 @@ fn test_create_airdrop_generates_valid_json() {
     // Check merkle_tree exists
     let merkle_tree = json["merkle_tree"].as_array().expect("merkle_tree missing");
     assert!(!merkle_tree.is_empty(), "merkle_tree should not be empty");
+
+    // New trees default to the domain-separated v2 scheme
+    assert_eq!(json["hash_version"].as_u64(), Some(2));
+    assert_eq!(json["leaf_encoding"].as_u64(), Some(1));
 }
 
 /// Test create-airdrop fails with empty CSV
@@ -394,3 +400,2741 @@ fn test_full_e2e_flow() {
 
     println!("\n=== Full E2E flow completed successfully! ===");
 }
+
+/// Test clawback is rejected while the airdrop is still live
+#[test]
+#[serial]
+fn test_clawback_before_expiry_fails() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    let amounts = [500u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    // Deploy with the default expiry (30 days out)
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    let output = run_cli(&[
+        "clawback-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Failed to run CLI");
+
+    assert!(
+        !output.status.success(),
+        "Clawback before expiry should fail but succeeded"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("has not expired"),
+        "Expected not-expired error, got: {}",
+        stderr
+    );
+}
+
+/// Test the authority can reclaim unclaimed tokens once the airdrop expires
+#[test]
+#[serial]
+fn test_clawback_after_expiry() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1000u64, 2000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    // Expire a few seconds after deployment
+    let expires_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 5;
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--expires-at",
+        &expires_at.to_string(),
+    ])
+    .expect("deploy-airdrop failed");
+
+    // Wait for both the local clock and the validator clock to pass the expiry
+    std::thread::sleep(std::time::Duration::from_secs(10));
+
+    let output = run_cli_success(&[
+        "clawback-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("clawback-airdrop failed");
+
+    println!("clawback-airdrop output: {}", output);
+
+    assert!(
+        output.contains("Airdrop clawed back successfully"),
+        "Expected success message in output"
+    );
+    assert!(
+        output.contains(&format!("Unclaimed balance: {}", amounts[0] + amounts[1])),
+        "Expected full unclaimed balance in output"
+    );
+}
+
+/// Test claims are rejected before the claim window opens
+#[test]
+#[serial]
+fn test_claim_before_start_fails() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    let amounts = [500u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    // Claims that would only open after the authority can claw back are rejected
+    let output = run_cli(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--start",
+        "2099-06-01T00:00:00Z",
+        "--expires-at",
+        "2099-01-01T00:00:00Z",
+    ])
+    .expect("Failed to run CLI");
+    assert!(!output.status.success(), "A window opening after expiry should be rejected");
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        combined.contains("InvalidClaimWindow") || combined.contains("open before expiry"),
+        "Expected InvalidClaimWindow error, got: {}",
+        combined
+    );
+
+    // Claims open far in the future
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--start",
+        "2099-01-01T00:00:00Z",
+        "--expires-at",
+        "2099-06-01T00:00:00Z",
+    ])
+    .expect("deploy-airdrop failed");
+
+    let output = run_cli(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Failed to run CLI");
+
+    assert!(
+        !output.status.success(),
+        "Claim before the window opens should fail but succeeded"
+    );
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        combined.contains("ClaimNotStarted") || combined.contains("not started"),
+        "Expected ClaimNotStarted error, got: {}",
+        combined
+    );
+}
+
+/// Test vesting allocations can be claimed repeatedly as they unlock
+#[test]
+#[serial]
+fn test_vesting_partial_claims() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    let amounts = [1_000_000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    // Vest linearly over 10 minutes, starting a minute ago
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--vesting-start",
+        &(now - 60).to_string(),
+        "--vesting-end",
+        &(now + 540).to_string(),
+    ])
+    .expect("deploy-airdrop failed");
+
+    let claim_args = [
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ];
+
+    let output = run_cli_success(&claim_args).expect("First vesting claim failed");
+    println!("first claim output: {}", output);
+    assert!(output.contains("Airdrop claimed successfully"));
+    assert!(
+        !output.contains(&format!("Claimable: {}", amounts[0])),
+        "Only part of the allocation should be claimable mid-vesting"
+    );
+
+    // More unlocks over time, so a second claim releases the difference
+    std::thread::sleep(std::time::Duration::from_secs(3));
+    let output = run_cli_success(&claim_args).expect("Second vesting claim failed");
+    println!("second claim output: {}", output);
+    assert!(output.contains("Airdrop claimed successfully"));
+}
+
+/// Test a cumulative airdrop pays only the increase after a new root is published
+#[test]
+#[serial]
+fn test_cumulative_update_root() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [100u64, 200u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    // Epoch 0
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--cumulative",
+    ])
+    .expect("deploy-airdrop failed");
+
+    let claim_args = [
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ];
+    run_cli_success(&claim_args).expect("Epoch 0 claim failed");
+
+    // Epoch 1 raises the first claimant's lifetime total by 50; the second claimant's stays put
+    let epoch1_csv = ctx.temp_dir.path().join("epoch1.csv");
+    std::fs::write(
+        &epoch1_csv,
+        format!(
+            "address,amount\n{},{}\n{},{}\n",
+            claimants[0].address, 150, claimants[1].address, 200
+        ),
+    )
+    .expect("Failed to write epoch 1 CSV");
+    let epoch1_dir = ctx.temp_dir.path().join("epoch1");
+    std::fs::create_dir(&epoch1_dir).expect("Failed to create epoch 1 dir");
+    let epoch1_json =
+        create_airdrop_in(&epoch1_dir, &epoch1_csv, &[]).expect("create-airdrop failed");
+
+    // Deploy minted exactly the epoch 0 total, so mint the epoch 1 increase for the top-up
+    let mint = ctx.read_airdrop_json().expect("Failed to read airdrop JSON")["mint"]
+        .as_str()
+        .expect("mint missing")
+        .to_string();
+    mint_tokens(&mint, &ctx.authority_keypair_path, 50).expect("Failed to mint top-up");
+
+    let output = run_cli_success(&[
+        "update-root",
+        "--json",
+        epoch1_json.to_str().unwrap(),
+        "--previous",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("update-root failed");
+    println!("update-root output: {}", output);
+    assert!(output.contains("Top-up amount: 50"), "Expected top-up of 50");
+
+    // Claiming against the new root pays only the difference
+    let claim_args = [
+        "claim-airdrop",
+        "--json",
+        epoch1_json.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ];
+    let output = run_cli_success(&claim_args).expect("Epoch 1 claim failed");
+    println!("epoch 1 claim output: {}", output);
+    assert!(output.contains("Claimable: 50"), "Expected only the increase to be claimable");
+}
+
+/// Test claims are rejected while paused and succeed again after unpausing
+#[test]
+#[serial]
+fn test_pause_blocks_claims() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    let amounts = [500u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 2).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    // Only the authority may pause
+    let output = run_cli(&[
+        "pause-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Failed to run CLI");
+    assert!(
+        !output.status.success(),
+        "Pause by a non-authority should fail but succeeded"
+    );
+
+    run_cli_success(&[
+        "pause-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("pause-airdrop failed");
+
+    let claim_args = [
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ];
+
+    let output = run_cli(&claim_args).expect("Failed to run CLI");
+    assert!(
+        !output.status.success(),
+        "Claim while paused should fail but succeeded"
+    );
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        combined.contains("AirdropPaused") || combined.contains("paused"),
+        "Expected AirdropPaused error, got: {}",
+        combined
+    );
+
+    run_cli_success(&[
+        "unpause-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("unpause-airdrop failed");
+
+    run_cli_success(&claim_args).expect("Claim after unpausing should succeed");
+}
+
+/// Test closing an expired, clawed-back airdrop and its claim receipts
+#[test]
+#[serial]
+fn test_close_airdrop_reclaims_rent() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1000u64, 2000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 2).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    let expires_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 8;
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--expires-at",
+        &expires_at.to_string(),
+    ])
+    .expect("deploy-airdrop failed");
+
+    let claim_args = [
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ];
+    run_cli_success(&claim_args).expect("Claim should succeed");
+
+    let close_args = [
+        "close-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--batch-size",
+        "1",
+    ];
+
+    std::thread::sleep(std::time::Duration::from_secs(12));
+
+    // The second claimant's tokens are still in the vault
+    let output = run_cli(&close_args).expect("Failed to run CLI");
+    assert!(
+        !output.status.success(),
+        "Close with a non-empty vault should fail but succeeded"
+    );
+
+    run_cli_success(&[
+        "clawback-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("clawback-airdrop failed");
+
+    let output = run_cli_success(&close_args).expect("close-airdrop failed");
+    println!("close-airdrop output: {}", output);
+    assert!(
+        output.contains("Closed 1/1 receipts"),
+        "Expected the claimed receipt to be closed"
+    );
+    assert!(
+        output.contains("Airdrop closed successfully"),
+        "Expected success message in output"
+    );
+
+    // Re-running is a no-op
+    let output = run_cli_success(&close_args).expect("Second close-airdrop failed");
+    assert!(
+        output.contains("Merkle root already closed"),
+        "Expected merkle root to be reported as closed"
+    );
+    assert!(
+        output.contains("Closing 0 claim receipt(s)"),
+        "Expected no receipts left to close"
+    );
+
+    // A closed airdrop can no longer be claimed from
+    let output = run_cli(&claim_args).expect("Failed to run CLI");
+    assert!(
+        !output.status.success(),
+        "Claim after close should fail but succeeded"
+    );
+
+    // Its receipts are gone, so deploying the same tree and nonce again must be refused
+    let output = run_cli(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Failed to run CLI");
+    assert!(
+        !output.status.success(),
+        "Re-deploying a closed airdrop should fail but succeeded"
+    );
+}
+
+/// Test a sponsor can pay fees and rent for a claimant with no SOL
+#[test]
+#[serial]
+fn test_sponsored_claim() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    let amounts = [750u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    // Only the authority is funded; it also sponsors the claim
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    // Without a sponsor the empty wallet cannot pay for the transaction
+    let output = run_cli(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Failed to run CLI");
+    assert!(
+        !output.status.success(),
+        "Unsponsored claim from an empty wallet should fail but succeeded"
+    );
+
+    let output = run_cli_success(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--fee-payer",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Sponsored claim should succeed");
+
+    assert!(
+        output.contains(&format!("Fee payer: {}", authority_pubkey)),
+        "Expected the sponsor to pay fees"
+    );
+    assert!(
+        output.contains("Airdrop claimed successfully"),
+        "Expected success message in output"
+    );
+}
+
+/// Test link mode: ephemeral keypairs, exported claim links, and claiming to any wallet
+#[test]
+#[serial]
+fn test_claim_link_flow() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [300u64, 400u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 2).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&["--link-base-url", "https://example.com/claim"])
+        .expect("create-airdrop failed");
+    let links_path = ctx.json_path.with_file_name("claim_links.csv");
+
+    // The tree is built over the ephemeral keys, not the CSV labels
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop.json");
+    let claims = json["claims"].as_object().unwrap();
+    assert!(!claims.contains_key(&claimants[0].address));
+
+    let mut reader = csv::Reader::from_path(&links_path).expect("Failed to open claim_links.csv");
+    let rows: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(rows.len(), 2);
+    for row in &rows {
+        assert!(claims.contains_key(&row[1]), "Link address missing from airdrop.json");
+        assert!(row[3].starts_with("https://example.com/claim?root="));
+    }
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    // A fresh wallet redeems the first link and receives the tokens
+    let output = run_cli_success(&[
+        "claim-link",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--link",
+        &rows[0][3],
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("claim-link failed");
+
+    assert!(output.contains(&format!("Destination: {}", claimants[0].address)));
+    assert!(output.contains(&format!("Claimable: {}", &rows[0][2])));
+    assert!(output.contains("Airdrop claimed successfully"));
+}
+
+/// Test voucher airdrops: deploy without a tree, issue a signed voucher, and redeem it once
+#[test]
+#[serial]
+fn test_voucher_airdrop_flow() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    let claimants = ctx.get_claimants(&[250u64]).expect("Failed to get claimants");
+
+    // The authority doubles as the voucher signer and relays the claim
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+
+    run_cli_success_in(
+        ctx.temp_dir.path(),
+        &[
+            "create-voucher-airdrop",
+            "--signer",
+            &authority_pubkey,
+            "--amount",
+            "1000",
+        ],
+    )
+    .expect("create-voucher-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    // Only the registered signer can issue vouchers
+    let voucher_path = ctx.json_path.with_file_name("voucher.json");
+    let output = run_cli(&[
+        "issue-voucher",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--recipient",
+        &claimants[0].address,
+        "--amount",
+        "250",
+        "--output",
+        voucher_path.to_str().unwrap(),
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Failed to run CLI");
+    assert!(
+        !output.status.success(),
+        "Issuing with the wrong signer should fail but succeeded"
+    );
+
+    run_cli_success(&[
+        "issue-voucher",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--recipient",
+        &claimants[0].address,
+        "--amount",
+        "250",
+        "--output",
+        voucher_path.to_str().unwrap(),
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("issue-voucher failed");
+
+    // A voucher whose amount was edited no longer matches its signature
+    let mut tampered: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&voucher_path).unwrap()).unwrap();
+    tampered["amount"] = serde_json::Value::String("999".to_string());
+    let tampered_path = ctx.json_path.with_file_name("tampered_voucher.json");
+    std::fs::write(&tampered_path, tampered.to_string()).unwrap();
+
+    let claim_args = |voucher: &str| {
+        run_cli(&[
+            "claim-voucher",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--voucher",
+            voucher,
+            "--network",
+            "localnet",
+            "--keypair",
+            ctx.authority_keypair_path.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("Failed to run CLI")
+    };
+
+    let output = claim_args(tampered_path.to_str().unwrap());
+    assert!(
+        !output.status.success(),
+        "Tampered voucher should fail but succeeded"
+    );
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        combined.contains("InvalidVoucherSignature") || combined.contains("Error"),
+        "Expected a signature error, got: {}",
+        combined
+    );
+
+    let output = claim_args(voucher_path.to_str().unwrap());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "claim-voucher failed: {}", stdout);
+    assert!(stdout.contains("Claimable: 250"));
+    assert!(stdout.contains("Voucher claimed successfully"));
+
+    // Each voucher can only be redeemed once
+    let output = claim_args(voucher_path.to_str().unwrap());
+    assert!(
+        !output.status.success(),
+        "Second redemption should fail but succeeded"
+    );
+}
+
+/// Test legacy v1 trees still deploy and verify on-chain
+#[test]
+#[serial]
+fn test_hash_version_1_still_claims() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [100u64, 200u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[1].address, 2).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[
+        "--hash-version",
+        "1",
+        "--leaf-encoding",
+        "base58",
+    ])
+    .expect("create-airdrop failed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    assert_eq!(json["hash_version"].as_u64(), Some(1));
+    assert_eq!(json["leaf_encoding"].as_u64(), Some(0));
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    run_cli_success(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[1].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Claim against a v1 root should succeed");
+}
+
+/// Test keccak sorted-pair trees deploy and verify on-chain
+#[test]
+#[serial]
+fn test_keccak_tree_claims() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(3).expect("Failed to create test context");
+    let amounts = [100u64, 200u64, 300u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[2].address, 2).expect("Failed to fund claimant");
+
+    assert!(
+        ctx.create_airdrop(&["--hash-version", "3", "--leaf-encoding", "base58"])
+            .is_err(),
+        "Keccak trees should reject base58 leaves"
+    );
+
+    ctx.create_airdrop(&["--hash-version", "3"]).expect("create-airdrop failed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    assert_eq!(json["hash_version"].as_u64(), Some(3));
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    run_cli_success(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[2].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Claim against a keccak root should succeed");
+}
+
+/// Test bitmap claim tracking: allocation at deploy, single claim, and status reads
+#[test]
+#[serial]
+fn test_claim_bitmap_flow() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(3).expect("Failed to create test context");
+    let amounts = [100u64, 200u64, 300u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[1].address, 2).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    let deploy_args = [
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--claim-bitmap",
+    ];
+    let output = run_cli_success(&deploy_args).expect("deploy-airdrop failed");
+    assert!(
+        output.contains("Allocating 1 claim bitmap account(s)"),
+        "Expected bitmap allocation in output: {}",
+        output
+    );
+
+    // Re-running, as after an interrupted deploy, leaves the allocated bitmap alone
+    let output = run_cli_success(&deploy_args).expect("Resumed deploy-airdrop failed");
+    assert!(output.contains("(already allocated)"), "Unexpected output: {}", output);
+
+    let claim_args = [
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[1].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ];
+    let output = run_cli_success(&claim_args).expect("Bitmap claim should succeed");
+    assert!(output.contains("Claim bitmap PDA"), "Expected bitmap claim path");
+
+    let output = run_cli(&claim_args).expect("Failed to run CLI");
+    assert!(!output.status.success(), "Second bitmap claim should fail");
+
+    let status_args = |address: Option<&str>| {
+        let mut args = vec![
+            "claim-status",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--network",
+            "localnet",
+            "--program-id",
+            PROGRAM_ID,
+        ];
+        if let Some(address) = address {
+            args.extend(["--address", address]);
+        }
+        run_cli_success(&args).expect("claim-status failed")
+    };
+
+    let output = status_args(None);
+    assert!(output.contains("Claimed: 1 / 3"), "Unexpected status: {}", output);
+    assert!(output.contains("Claimed amount: 200 / 600"), "Unexpected status: {}", output);
+
+    let output = status_args(Some(&claimants[1].address));
+    assert!(output.contains(": claimed"), "Unexpected status: {}", output);
+    let output = status_args(Some(&claimants[0].address));
+    assert!(output.contains(": unclaimed"), "Unexpected status: {}", output);
+}
+
+/// Test closing a bitmap airdrop returns the rent of its claim bitmaps to the authority
+#[test]
+#[serial]
+fn test_close_airdrop_reclaims_bitmap_rent() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [100u64, 200u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    let expires_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 8;
+    let output = run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--claim-bitmap",
+        "--expires-at",
+        &expires_at.to_string(),
+    ])
+    .expect("deploy-airdrop failed");
+    let claim_bitmap = output
+        .lines()
+        .find_map(|line| line.strip_prefix("Claim bitmap 0: "))
+        .and_then(|rest| rest.split_whitespace().next())
+        .expect("Expected the claim bitmap address in output")
+        .to_string();
+    let bitmap_rent = lamport_balance(&claim_bitmap).expect("Failed to read bitmap balance");
+    assert!(bitmap_rent > 0, "Expected the claim bitmap to be allocated");
+
+    std::thread::sleep(std::time::Duration::from_secs(12));
+
+    run_cli_success(&[
+        "clawback-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("clawback-airdrop failed");
+
+    let before = lamport_balance(&authority_pubkey).expect("Failed to read authority balance");
+    let close_args = [
+        "close-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--batch-size",
+        "1",
+    ];
+    let output = run_cli_success(&close_args).expect("close-airdrop failed");
+    assert!(
+        output.contains("Closed 1/1 bitmaps"),
+        "Expected the claim bitmap to be closed: {}",
+        output
+    );
+    assert_eq!(lamport_balance(&claim_bitmap).unwrap(), 0, "Claim bitmap should be closed");
+
+    // The merkle root and vault rent cover the tombstone and fees, so the bitmap rent must
+    // have come back on top of them
+    let after = lamport_balance(&authority_pubkey).expect("Failed to read authority balance");
+    assert!(
+        after > before + bitmap_rent,
+        "Expected the bitmap rent ({}) back: {} -> {}",
+        bitmap_rent,
+        before,
+        after
+    );
+
+    // Re-running finds nothing left to close
+    let output = run_cli_success(&close_args).expect("Second close-airdrop failed");
+    assert!(!output.contains("claim bitmap(s)"), "Unexpected bitmap close: {}", output);
+}
+
+/// Test a squatter deploying our root first cannot block our deploy, and nonces allow redeploys
+#[test]
+#[serial]
+fn test_merkle_root_pda_namespaced_by_creator() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [100u64, 200u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    // The second claimant doubles as the squatter who copies our airdrop.json
+    fund_account(&claimants[1].address, 10).expect("Failed to fund squatter");
+    fund_account(&claimants[0].address, 2).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+    let squatter_json = ctx.temp_dir.path().join("squatter.json");
+    let redeploy_json = ctx.temp_dir.path().join("redeploy.json");
+    std::fs::copy(&ctx.json_path, &squatter_json).expect("Failed to copy airdrop.json");
+    std::fs::copy(&ctx.json_path, &redeploy_json).expect("Failed to copy airdrop.json");
+
+    let deploy = |json: &std::path::Path, keypair: &std::path::Path, nonce: &str| {
+        run_cli_success(&[
+            "deploy-airdrop",
+            "--json",
+            json.to_str().unwrap(),
+            "--network",
+            "localnet",
+            "--keypair",
+            keypair.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+            "--nonce",
+            nonce,
+        ])
+    };
+
+    deploy(&squatter_json, &claimants[1].keypair_path, "0").expect("Squatter deploy failed");
+    deploy(&ctx.json_path, &ctx.authority_keypair_path, "0")
+        .expect("Our deploy should not collide with the squatter's");
+    deploy(&redeploy_json, &ctx.authority_keypair_path, "1")
+        .expect("Redeploying the same root under a new nonce should succeed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    assert_eq!(json["creator"].as_str(), Some(authority_pubkey.as_str()));
+    assert_eq!(json["nonce"].as_u64(), Some(0));
+
+    run_cli_success(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Claim against our namespaced airdrop should succeed");
+}
+
+/// Test an airdrop left by the program's first release, loaded into the validator in its
+/// original layout at the root-only PDA, can still be claimed and closed
+#[test]
+#[serial]
+fn test_legacy_airdrop() {
+    let legacy = legacy_airdrop();
+    let json_path = legacy.json_path.to_str().unwrap();
+    for claimant in &legacy.claimants {
+        fund_account(&claimant.address, 1).expect("Failed to fund claimant");
+    }
+    let upgrade_authority = upgrade_authority_keypair_path();
+    let upgrade_authority_pubkey =
+        get_pubkey(&upgrade_authority).expect("Failed to get upgrade authority pubkey");
+    fund_account(&upgrade_authority_pubkey, 1).expect("Failed to fund upgrade authority");
+
+    let legacy_cmd = |command: &str, keypair: &std::path::Path| {
+        run_cli(&[
+            command,
+            "--json",
+            json_path,
+            "--network",
+            "localnet",
+            "--keypair",
+            keypair.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("Failed to run legacy command")
+    };
+
+    let first = &legacy.claimants[0];
+    let output = legacy_cmd("claim-airdrop", &first.keypair_path);
+    assert!(
+        output.status.success(),
+        "Legacy claim failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(token_balance(&first.address, &legacy.mint).unwrap(), first.amount);
+    assert!(
+        !legacy_cmd("claim-airdrop", &first.keypair_path).status.success(),
+        "A legacy leaf can only be claimed once"
+    );
+
+    assert!(
+        !legacy_cmd("close-airdrop", &upgrade_authority).status.success(),
+        "Closing should wait for the vault to empty"
+    );
+
+    // The second leaf goes unclaimed and is swept back by the upgrade authority
+    let second = &legacy.claimants[1];
+    assert!(
+        !legacy_cmd("clawback-airdrop", &first.keypair_path).status.success(),
+        "Only the upgrade authority may claw back a legacy airdrop"
+    );
+    let output = legacy_cmd("clawback-airdrop", &upgrade_authority);
+    assert!(
+        output.status.success(),
+        "Legacy clawback failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        token_balance(&upgrade_authority_pubkey, &legacy.mint).unwrap(),
+        second.amount
+    );
+    assert!(
+        !legacy_cmd("claim-airdrop", &second.keypair_path).status.success(),
+        "Nothing is left to claim after a clawback"
+    );
+
+    assert!(
+        !legacy_cmd("close-airdrop", &first.keypair_path).status.success(),
+        "Only the upgrade authority may close a legacy airdrop"
+    );
+    let output = legacy_cmd("close-airdrop", &upgrade_authority);
+    assert!(
+        output.status.success(),
+        "Legacy close failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(lamport_balance(&legacy.merkle_root_pda).unwrap(), 0);
+
+    let output = run_cli(&[
+        "deploy-airdrop",
+        "--json",
+        json_path,
+        "--network",
+        "localnet",
+        "--keypair",
+        upgrade_authority.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Failed to run deploy-airdrop");
+    assert!(!output.status.success(), "A legacy airdrop.json should not be redeployed");
+}
+
+/// Test anyone can top up a deployed airdrop's vault with fund-airdrop
+#[test]
+#[serial]
+fn test_fund_airdrop_tops_up_vault() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1000u64, 2000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    // Expire soon so clawback can report the vault balance
+    let expires_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 10;
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--expires-at",
+        &expires_at.to_string(),
+    ])
+    .expect("deploy-airdrop failed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    let mint = json["mint"].as_str().expect("mint missing").to_string();
+
+    let fund = |amount: &str| {
+        run_cli(&[
+            "fund-airdrop",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--amount",
+            amount,
+            "--network",
+            "localnet",
+            "--keypair",
+            ctx.authority_keypair_path.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("Failed to run fund-airdrop")
+    };
+
+    // The deploy moved the whole supply into the vault, so there is nothing left to add
+    assert!(!fund("500").status.success(), "Funding beyond the balance should fail");
+
+    mint_tokens(&mint, &ctx.authority_keypair_path, 500).expect("Failed to mint top-up");
+    let output = fund("500");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    println!("fund-airdrop output: {}", stdout);
+    assert!(output.status.success(), "fund-airdrop failed: {}", stdout);
+    assert!(stdout.contains("Airdrop funded successfully"));
+
+    std::thread::sleep(std::time::Duration::from_secs(15));
+
+    let output = run_cli_success(&[
+        "clawback-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("clawback-airdrop failed");
+    assert!(
+        output.contains(&format!("Unclaimed balance: {}", amounts[0] + amounts[1] + 500)),
+        "Expected the top-up in the vault balance"
+    );
+}
+
+/// Test one root paying out several mints, with receipts tracked per mint
+#[test]
+#[serial]
+fn test_multi_mint_airdrop() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let claimants = ctx.get_claimants(&[0, 0]).expect("Failed to get claimants");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+    fund_account(&claimants[1].address, 1).expect("Failed to fund claimant");
+
+    let mint_a = create_mint(&ctx.authority_keypair_path).expect("Failed to create mint");
+    let mint_b = create_mint(&ctx.authority_keypair_path).expect("Failed to create mint");
+    mint_tokens(&mint_a, &ctx.authority_keypair_path, 100).expect("Failed to mint");
+    mint_tokens(&mint_b, &ctx.authority_keypair_path, 500).expect("Failed to mint");
+
+    // The first claimant gets both tokens, the second only one
+    std::fs::write(
+        &ctx.csv_path,
+        format!(
+            "address,amount,mint\n{},100,{}\n{},200,{}\n{},300,{}\n",
+            claimants[0].address,
+            mint_a,
+            claimants[0].address,
+            mint_b,
+            claimants[1].address,
+            mint_b
+        ),
+    )
+    .expect("Failed to write CSV");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    assert_eq!(json["mint_claims"][&claimants[0].address].as_object().unwrap().len(), 2);
+
+    let deploy_args = [
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ];
+    run_cli_success(&deploy_args).expect("deploy-airdrop failed");
+
+    // Re-running, as after an interrupted deploy, finds every vault funded and deposits nothing
+    let output = run_cli_success(&deploy_args).expect("Resumed deploy-airdrop failed");
+    assert!(output.contains("Merkle root already created"), "Unexpected output: {}", output);
+    assert_eq!(output.matches("already funded").count(), 2, "Unexpected output: {}", output);
+    assert_eq!(token_balance(&authority_pubkey, &mint_a).unwrap(), 0);
+    assert_eq!(token_balance(&authority_pubkey, &mint_b).unwrap(), 0);
+
+    let claim = |keypair: &std::path::Path, mint: Option<&str>| {
+        let mut args = vec![
+            "claim-airdrop",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--network",
+            "localnet",
+            "--keypair",
+            keypair.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ];
+        if let Some(mint) = mint {
+            args.extend(["--mint", mint]);
+        }
+        run_cli(&args).expect("Failed to run claim-airdrop")
+    };
+
+    // Claiming one mint leaves the other claimable
+    assert!(claim(&claimants[0].keypair_path, Some(&mint_b)).status.success());
+    assert_eq!(token_balance(&claimants[0].address, &mint_b).unwrap(), 200);
+    assert!(claim(&claimants[0].keypair_path, None).status.success());
+    assert_eq!(token_balance(&claimants[0].address, &mint_a).unwrap(), 100);
+    assert!(
+        !claim(&claimants[0].keypair_path, None).status.success(),
+        "Claiming again should fail once every mint is claimed"
+    );
+
+    // The second claimant has no leaf for the first mint
+    assert!(!claim(&claimants[1].keypair_path, Some(&mint_a)).status.success());
+    assert!(claim(&claimants[1].keypair_path, None).status.success());
+    assert_eq!(token_balance(&claimants[1].address, &mint_b).unwrap(), 300);
+}
+
+/// Test a multi-mint airdrop cannot be closed while the vault of another mint is still open
+#[test]
+#[serial]
+fn test_multi_mint_close_waits_for_every_vault() {
+    use airdrop_contract::constants::{CLOSED_AIRDROP_SEED, MERKLE_ROOT_SEED};
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use anchor_spl::associated_token::get_associated_token_address;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::instruction::Instruction;
+    use solana_sdk::pubkey::Pubkey;
+    use solana_sdk::signature::{read_keypair_file, Signer};
+    use solana_sdk::transaction::Transaction;
+    use std::str::FromStr;
+
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    let claimants = ctx.get_claimants(&[0]).expect("Failed to get claimants");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+
+    let mint_a = create_mint(&ctx.authority_keypair_path).expect("Failed to create mint");
+    let mint_b = create_mint(&ctx.authority_keypair_path).expect("Failed to create mint");
+    mint_tokens(&mint_a, &ctx.authority_keypair_path, 100).expect("Failed to mint");
+    mint_tokens(&mint_b, &ctx.authority_keypair_path, 200).expect("Failed to mint");
+
+    std::fs::write(
+        &ctx.csv_path,
+        format!(
+            "address,amount,mint\n{},100,{}\n{},200,{}\n",
+            claimants[0].address, mint_a, claimants[0].address, mint_b
+        ),
+    )
+    .expect("Failed to write CSV");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    let expires_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 8;
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--expires-at",
+        &expires_at.to_string(),
+    ])
+    .expect("deploy-airdrop failed");
+
+    std::thread::sleep(std::time::Duration::from_secs(12));
+    run_cli_success(&[
+        "clawback-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("clawback-airdrop failed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    let program_id = Pubkey::from_str(PROGRAM_ID).unwrap();
+    let authority = read_keypair_file(&ctx.authority_keypair_path).unwrap();
+    let creator = Pubkey::from_str(json["creator"].as_str().unwrap()).unwrap();
+    let nonce = json["nonce"].as_u64().unwrap_or(0);
+    let root = hex::decode(json["merkle_root"].as_str().unwrap()).unwrap();
+    let (merkle_root, _) = Pubkey::find_program_address(
+        &[MERKLE_ROOT_SEED, creator.as_ref(), &nonce.to_le_bytes(), &root],
+        &program_id,
+    );
+    let primary_mint = Pubkey::from_str(json["mint"].as_str().unwrap()).unwrap();
+    let other_mint = if primary_mint.to_string() == mint_a { &mint_b } else { &mint_a };
+    let other_vault =
+        get_associated_token_address(&merkle_root, &Pubkey::from_str(other_mint).unwrap());
+
+    // Both vaults are empty, but closing the merkle root first would strand the other one
+    let rpc_client =
+        RpcClient::new_with_commitment(common::validator::RPC_URL.to_string(), CommitmentConfig::confirmed());
+    let close_ix = Instruction {
+        program_id,
+        accounts: airdrop_contract::accounts::CloseAirdrop {
+            authority: authority.pubkey(),
+            merkle_root,
+            closed_airdrop: Pubkey::find_program_address(
+                &[CLOSED_AIRDROP_SEED, merkle_root.as_ref()],
+                &program_id,
+            )
+            .0,
+            merkle_root_token_account: get_associated_token_address(&merkle_root, &primary_mint),
+            mint: primary_mint,
+            system_program: solana_sdk::system_program::ID,
+            token_program: anchor_spl::token::ID,
+        }
+        .to_account_metas(None),
+        data: airdrop_contract::instruction::CloseAirdrop {}.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        rpc_client.get_latest_blockhash().unwrap(),
+    );
+    assert!(
+        rpc_client.send_and_confirm_transaction(&tx).is_err(),
+        "Closing with another mint's vault open should fail but succeeded"
+    );
+
+    // The CLI closes the other vault first, then the airdrop
+    let output = run_cli_success(&[
+        "close-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("close-airdrop failed");
+    assert!(output.contains("Closed vault"), "Expected the other vault to be closed first");
+    assert!(output.contains("Airdrop closed successfully"));
+    assert!(rpc_client.get_account(&other_vault).is_err(), "Other vault should be closed");
+}
+
+/// Test only the authority can open a vault for another mint of a multi-mint airdrop
+#[test]
+#[serial]
+fn test_multi_mint_vault_opened_by_authority_only() {
+    use airdrop_contract::constants::{CONFIG_SEED, MERKLE_ROOT_SEED, VAULT_RECORD_SEED};
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use anchor_spl::associated_token::get_associated_token_address;
+    use solana_client::rpc_client::RpcClient;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::instruction::Instruction;
+    use solana_sdk::pubkey::Pubkey;
+    use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+    use solana_sdk::transaction::Transaction;
+    use std::str::FromStr;
+
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    let claimants = ctx.get_claimants(&[0]).expect("Failed to get claimants");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    // The claimant doubles as a stranger holding a mint of their own
+    fund_account(&claimants[0].address, 2).expect("Failed to fund claimant");
+
+    let mint_a = create_mint(&ctx.authority_keypair_path).expect("Failed to create mint");
+    let mint_b = create_mint(&ctx.authority_keypair_path).expect("Failed to create mint");
+    mint_tokens(&mint_a, &ctx.authority_keypair_path, 100).expect("Failed to mint");
+    mint_tokens(&mint_b, &ctx.authority_keypair_path, 200).expect("Failed to mint");
+
+    std::fs::write(
+        &ctx.csv_path,
+        format!(
+            "address,amount,mint\n{},100,{}\n{},200,{}\n",
+            claimants[0].address, mint_a, claimants[0].address, mint_b
+        ),
+    )
+    .expect("Failed to write CSV");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    let program_id = Pubkey::from_str(PROGRAM_ID).unwrap();
+    let creator = Pubkey::from_str(json["creator"].as_str().unwrap()).unwrap();
+    let nonce = json["nonce"].as_u64().unwrap_or(0);
+    let root = hex::decode(json["merkle_root"].as_str().unwrap()).unwrap();
+    let (merkle_root, _) = Pubkey::find_program_address(
+        &[MERKLE_ROOT_SEED, creator.as_ref(), &nonce.to_le_bytes(), &root],
+        &program_id,
+    );
+
+    let rpc_client =
+        RpcClient::new_with_commitment(common::validator::RPC_URL.to_string(), CommitmentConfig::confirmed());
+    // Fund one base unit of `mint`, opening its vault if it has none
+    let fund = |funder: &Keypair, mint: &Pubkey| {
+        let funder_token_account = get_associated_token_address(&funder.pubkey(), mint);
+        let fund_ix = Instruction {
+            program_id,
+            accounts: airdrop_contract::accounts::FundAirdrop {
+                funder: funder.pubkey(),
+                funder_token_account,
+                merkle_root_token_account: get_associated_token_address(&merkle_root, mint),
+                mint: *mint,
+                merkle_root,
+                vault_record: Some(
+                    Pubkey::find_program_address(
+                        &[VAULT_RECORD_SEED, merkle_root.as_ref(), mint.as_ref()],
+                        &program_id,
+                    )
+                    .0,
+                ),
+                config: Pubkey::find_program_address(&[CONFIG_SEED], &program_id).0,
+                fee_recipient_token_account: funder_token_account,
+                system_program: solana_sdk::system_program::ID,
+                token_program: anchor_spl::token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+            }
+            .to_account_metas(None),
+            data: airdrop_contract::instruction::FundAirdrop { amount: 1 }.data(),
+        };
+        let tx = Transaction::new_signed_with_payer(
+            &[fund_ix],
+            Some(&funder.pubkey()),
+            &[funder],
+            rpc_client.get_latest_blockhash().unwrap(),
+        );
+        rpc_client.send_and_confirm_transaction(&tx)
+    };
+
+    // A vault the stranger could freeze would keep the airdrop from ever closing
+    let stranger = read_keypair_file(&claimants[0].keypair_path).unwrap();
+    let stranger_mint = create_mint(&claimants[0].keypair_path).expect("Failed to create mint");
+    mint_tokens(&stranger_mint, &claimants[0].keypair_path, 10).expect("Failed to mint");
+    let stranger_mint = Pubkey::from_str(&stranger_mint).unwrap();
+    assert!(
+        fund(&stranger, &stranger_mint).is_err(),
+        "A third party opening a vault should fail but succeeded"
+    );
+    assert!(
+        rpc_client
+            .get_account(&get_associated_token_address(&merkle_root, &stranger_mint))
+            .is_err(),
+        "No vault should have been opened"
+    );
+
+    // The authority still can
+    let authority = read_keypair_file(&ctx.authority_keypair_path).unwrap();
+    let mint_c = create_mint(&ctx.authority_keypair_path).expect("Failed to create mint");
+    mint_tokens(&mint_c, &ctx.authority_keypair_path, 10).expect("Failed to mint");
+    let mint_c = Pubkey::from_str(&mint_c).unwrap();
+    fund(&authority, &mint_c).expect("Authority opening a vault failed");
+    assert_eq!(token_balance(&merkle_root.to_string(), &mint_c.to_string()).unwrap(), 1);
+}
+
+#[test]
+#[serial]
+fn test_sol_airdrop() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    // Lamports; recipients already hold SOL, so small amounts stay above the rent minimum
+    let amounts = [5_000_000u64, 7_000_000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--sol",
+    ])
+    .expect("deploy-airdrop failed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    assert_eq!(json["sol"], true);
+    assert!(json["mint"].is_null(), "SOL airdrops have no mint");
+
+    // The authority sponsors the claim, so the claimant's balance moves by the amount alone
+    let claim = || {
+        run_cli(&[
+            "claim-airdrop",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--network",
+            "localnet",
+            "--keypair",
+            claimants[0].keypair_path.to_str().unwrap(),
+            "--fee-payer",
+            ctx.authority_keypair_path.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("Failed to run claim-airdrop")
+    };
+
+    let before = lamport_balance(&claimants[0].address).expect("Failed to read balance");
+    let output = claim();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    println!("claim-airdrop output: {}", stdout);
+    assert!(output.status.success(), "claim-airdrop failed: {}", stdout);
+    let after = lamport_balance(&claimants[0].address).expect("Failed to read balance");
+    assert_eq!(after - before, amounts[0]);
+
+    assert!(!claim().status.success(), "Claiming twice should fail");
+}
+
+#[test]
+#[serial]
+fn test_token_2022_airdrop() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1_000u64, 2_000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--token-2022",
+    ])
+    .expect("deploy-airdrop failed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    let mint = json["mint"].as_str().expect("Deploy should record the new mint").to_string();
+
+    run_cli_success(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("claim-airdrop failed");
+    assert_eq!(token_balance(&claimants[0].address, &mint).unwrap(), amounts[0]);
+}
+
+#[test]
+#[serial]
+fn test_token_2022_transfer_fee_airdrop() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1_000u64, 2_000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+    fund_account(&claimants[1].address, 1).expect("Failed to fund claimant");
+
+    // 1% fee on every transfer, including the deposit into the vault
+    let mint = create_token_2022_fee_mint(&ctx.authority_keypair_path, 100, u64::MAX)
+        .expect("Failed to create mint");
+    mint_tokens(&mint, &ctx.authority_keypair_path, 10_000).expect("Failed to mint");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    let output = run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--mint",
+        &mint,
+    ])
+    .expect("deploy-airdrop failed");
+    assert!(output.contains("to cover transfer fees"), "Deploy should gross up: {}", output);
+
+    let claim = |keypair: &std::path::Path| {
+        run_cli(&[
+            "claim-airdrop",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--network",
+            "localnet",
+            "--keypair",
+            keypair.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("Failed to run claim-airdrop")
+    };
+
+    // Recipients net exactly their allocation, and the deposit covers every claim's fee
+    for (claimant, amount) in claimants.iter().zip(amounts) {
+        assert!(claim(&claimant.keypair_path).status.success());
+        assert_eq!(token_balance(&claimant.address, &mint).unwrap(), amount);
+    }
+}
+
+/// Test vesting installments on a transfer-fee mint never take more than the vault was funded with
+#[test]
+#[serial]
+fn test_vesting_with_transfer_fee() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    // Not a multiple of 100, so a 1% fee rounds differently per installment
+    let amounts = [999_999u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    let mint = create_token_2022_fee_mint(&ctx.authority_keypair_path, 100, u64::MAX)
+        .expect("Failed to create mint");
+    mint_tokens(&mint, &ctx.authority_keypair_path, 2_000_000).expect("Failed to mint");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    // Vest linearly over 10 seconds, starting now
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--mint",
+        &mint,
+        "--vesting-start",
+        &now.to_string(),
+        "--vesting-end",
+        &(now + 10).to_string(),
+    ])
+    .expect("deploy-airdrop failed");
+
+    let claim_args = [
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ];
+
+    // Claim twice mid-vesting, then the rest once fully vested
+    for _ in 0..2 {
+        std::thread::sleep(std::time::Duration::from_secs(3));
+        run_cli_success(&claim_args).expect("Vesting claim failed");
+    }
+    std::thread::sleep(std::time::Duration::from_secs(6));
+    run_cli_success(&claim_args).expect("Final vesting claim should not find the vault short");
+
+    // Each installment is grossed up on its own, so the CSV amount arrives in full
+    let received = token_balance(&claimants[0].address, &mint).unwrap();
+    assert_eq!(received, amounts[0]);
+}
+
+#[test]
+#[serial]
+fn test_decode_events() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1_000u64, 2_000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    let decode = |output: &str| {
+        let signature = output
+            .lines()
+            .find_map(|line| line.strip_prefix("Signature: "))
+            .expect("Command should print its signature");
+        let events = run_cli_success(&[
+            "decode-events",
+            "--signature",
+            signature,
+            "--network",
+            "localnet",
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("decode-events failed");
+        events
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).expect("Invalid event JSON"))
+            .collect::<Vec<_>>()
+    };
+
+    let output = run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+
+    let events = decode(&output);
+    assert_eq!(events.len(), 1, "Deploy should emit one event: {:?}", events);
+    assert_eq!(events[0]["event"], "AirdropCreated");
+    assert_eq!(events[0]["root"], json["merkle_root"]);
+    assert_eq!(events[0]["mint"], json["mint"]);
+    assert_eq!(events[0]["authority"], authority_pubkey.as_str());
+    assert_eq!(events[0]["amount"], amounts.iter().sum::<u64>());
+
+    let output = run_cli_success(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("claim-airdrop failed");
+
+    let events = decode(&output);
+    assert_eq!(events.len(), 1, "Claim should emit one event: {:?}", events);
+    assert_eq!(events[0]["event"], "Claimed");
+    assert_eq!(events[0]["claimant"], claimants[0].address.as_str());
+    assert_eq!(events[0]["amount"], amounts[0]);
+    assert_eq!(events[0]["leaf_index"], json["claims"][&claimants[0].address]["leaf_index"]);
+    assert!(events[0]["timestamp"].as_i64().unwrap() > 0);
+}
+
+#[test]
+#[serial]
+fn test_airdrop_status_accounting() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(3).expect("Failed to create test context");
+    let amounts = [1_000u64, 2_000u64, 3_000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    let mint = json["mint"].as_str().unwrap().to_string();
+
+    let status = || {
+        run_cli_success(&[
+            "airdrop-status",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--network",
+            "localnet",
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("airdrop-status failed")
+    };
+
+    let output = status();
+    println!("airdrop-status output: {}", output);
+    assert!(output.contains("Leaves: 3"));
+    assert!(output.contains("Claims: 0"));
+    assert!(output.contains("Funded: 6000"));
+    assert!(output.contains("Claimed: 0 (0.00%)"));
+    assert!(output.contains(&format!("Vault balance ({}): 6000", mint)));
+
+    run_cli_success(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("claim-airdrop failed");
+
+    // Top-ups count towards the funded total
+    mint_tokens(&mint, &ctx.authority_keypair_path, 500).expect("Failed to mint");
+    run_cli_success(&[
+        "fund-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--amount",
+        "500",
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("fund-airdrop failed");
+
+    let output = status();
+    println!("airdrop-status output: {}", output);
+    assert!(output.contains("Claims: 1"));
+    assert!(output.contains("Funded: 6500"));
+    assert!(output.contains("Claimed: 1000 (15.38%)"));
+    assert!(output.contains("Remaining: 5500"));
+    assert!(output.contains(&format!("Vault balance ({}): 5500", mint)));
+}
+
+#[test]
+#[serial]
+fn test_mint_on_claim_airdrop() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1000u64, 2000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+        "--mint-on-claim",
+    ])
+    .expect("deploy-airdrop failed");
+
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    assert_eq!(json["mint_on_claim"], true);
+    let mint = json["mint"].as_str().expect("mint should be set").to_string();
+
+    // There is no vault to top up
+    let output = run_cli(&[
+        "fund-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--amount",
+        "500",
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Failed to run fund-airdrop");
+    assert!(!output.status.success(), "Funding a mint-on-claim airdrop should fail");
+
+    let claim = || {
+        run_cli(&[
+            "claim-airdrop",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--network",
+            "localnet",
+            "--keypair",
+            claimants[0].keypair_path.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("Failed to run claim-airdrop")
+    };
+
+    let output = claim();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    println!("claim-airdrop output: {}", stdout);
+    assert!(output.status.success(), "claim-airdrop failed: {}", stdout);
+    assert_eq!(token_balance(&claimants[0].address, &mint).unwrap(), amounts[0]);
+    assert!(!claim().status.success(), "Claiming twice should fail");
+
+    // The declared total is the cap on what claims may mint
+    let output = run_cli_success(&[
+        "airdrop-status",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("airdrop-status failed");
+    println!("airdrop-status output: {}", output);
+    assert!(output.contains("Funded: 3000"));
+    assert!(output.contains("Claimed: 1000"));
+    assert!(output.contains(&format!("Mint on claim: {}", mint)));
+}
+
+/// The config is a program-wide singleton, so this test leaves it charging no fee for the
+/// tests that run after it on the shared validator.
+#[test]
+#[serial]
+fn test_protocol_fees() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1000u64, 2000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+    // The second claimant doubles as the fee recipient; it must exist to receive lamports
+    let fee_recipient = claimants[1].address.clone();
+    fund_account(&fee_recipient, 1).expect("Failed to fund fee recipient");
+
+    let config_cmd = |command: &str, keypair: &std::path::Path, extra: &[&str]| {
+        let mut cmd_args = vec![
+            command,
+            "--network",
+            "localnet",
+            "--keypair",
+            keypair.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ];
+        cmd_args.extend_from_slice(extra);
+        run_cli(&cmd_args).expect("Failed to run config command")
+    };
+
+    // Only the upgrade authority may initialize the config; it hands the admin role over
+    let upgrade_authority = upgrade_authority_keypair_path();
+    let upgrade_authority_pubkey = get_pubkey(&upgrade_authority)
+        .expect("Failed to get upgrade authority pubkey");
+    fund_account(&upgrade_authority_pubkey, 1).expect("Failed to fund upgrade authority");
+    let init_args = [
+        "--admin",
+        &authority_pubkey,
+        "--fee-recipient",
+        &fee_recipient,
+        "--funded-fee-bps",
+        "250",
+    ];
+    assert!(
+        !config_cmd("init-config", &ctx.authority_keypair_path, &init_args).status.success(),
+        "Only the upgrade authority should initialize the config"
+    );
+    let output = config_cmd("init-config", &upgrade_authority, &init_args);
+    assert!(
+        output.status.success(),
+        "init-config failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        !config_cmd("init-config", &upgrade_authority, &[]).status.success(),
+        "The config can only be initialized once"
+    );
+
+    let output =
+        run_cli_success(&["show-config", "--network", "localnet", "--program-id", PROGRAM_ID])
+            .expect("show-config failed");
+    println!("show-config output: {}", output);
+    assert!(output.contains(&format!("Admin: {}", authority_pubkey)));
+    assert!(output.contains(&format!("Fee recipient: {}", fee_recipient)));
+    assert!(output.contains("250 bps"));
+
+    // Only the admin may change the config
+    let output = config_cmd("update-config", &claimants[0].keypair_path, &["--no-fee"]);
+    assert!(!output.status.success(), "A non-admin should not update the config");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    // 2.5% of the 3000 deposited, on top of the deposit
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    let mint = json["mint"].as_str().expect("mint should be set").to_string();
+    assert_eq!(token_balance(&fee_recipient, &mint).unwrap(), 75);
+
+    // Switch to a flat fee per claim, paid by the claimant as fee payer
+    let output = config_cmd(
+        "update-config",
+        &ctx.authority_keypair_path,
+        &["--claim-fee-lamports", "5000"],
+    );
+    assert!(output.status.success(), "update-config failed");
+
+    let before = lamport_balance(&fee_recipient).expect("Failed to read balance");
+    run_cli_success(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("claim-airdrop failed");
+    let after = lamport_balance(&fee_recipient).expect("Failed to read balance");
+    assert_eq!(after - before, 5000);
+    assert_eq!(token_balance(&claimants[0].address, &mint).unwrap(), amounts[0]);
+
+    let output = config_cmd("update-config", &ctx.authority_keypair_path, &["--no-fee"]);
+    assert!(output.status.success(), "update-config failed");
+}
+
+#[test]
+#[serial]
+fn test_airdrop_metadata() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1_000u64, 2_000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    // The --name flag takes precedence over the file's name
+    let metadata_path = ctx.temp_dir.path().join("metadata.toml");
+    std::fs::write(
+        &metadata_path,
+        "name = \"From file\"\nimage_uri = \"https://example.com/logo.png\"\n",
+    )
+    .expect("Failed to write metadata file");
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--metadata",
+        metadata_path.to_str().unwrap(),
+        "--name",
+        "Community drop",
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+
+    let list = || {
+        run_cli_success(&[
+            "list-airdrops",
+            "--authority",
+            &authority_pubkey,
+            "--network",
+            "localnet",
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("list-airdrops failed")
+    };
+
+    let output = list();
+    println!("list-airdrops output: {}", output);
+    assert!(output.contains("Airdrops: 1"));
+    assert!(output.contains("Community drop"));
+    assert!(!output.contains("From file"));
+    assert!(output.contains("Image: https://example.com/logo.png"));
+    assert!(output.contains("Claimed: 0 / 3000"));
+
+    // Only the authority may change it
+    let result = run_cli(&[
+        "update-metadata",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--name",
+        "Hijacked",
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("Failed to run update-metadata");
+    assert!(!result.status.success(), "update-metadata by a claimant should fail");
+
+    // Fields that are not given keep their value
+    run_cli_success(&[
+        "update-metadata",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--website",
+        "https://example.com/claim",
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("update-metadata failed");
+
+    let output = list();
+    println!("list-airdrops output: {}", output);
+    assert!(output.contains("Community drop"));
+    assert!(output.contains("Image: https://example.com/logo.png"));
+    assert!(output.contains("Website: https://example.com/claim"));
+}
+
+#[test]
+#[serial]
+fn test_gated_airdrop() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(2).expect("Failed to create test context");
+    let amounts = [1_000u64, 2_000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+    fund_account(&claimants[1].address, 1).expect("Failed to fund claimant");
+
+    // The first claimant holds the membership token; the second does not
+    let gate_mint = create_mint(&claimants[0].keypair_path).expect("Failed to create gate mint");
+    mint_tokens(&gate_mint, &claimants[0].keypair_path, 1).expect("Failed to mint");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--gate-mint",
+        &gate_mint,
+        "--gate-min-balance",
+        "1",
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    let mint = json["mint"].as_str().unwrap().to_string();
+
+    let claim = |keypair_path: &std::path::Path| {
+        run_cli(&[
+            "claim-airdrop",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--network",
+            "localnet",
+            "--keypair",
+            keypair_path.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("Failed to run claim-airdrop")
+    };
+
+    let output = claim(&claimants[0].keypair_path);
+    assert!(
+        output.status.success(),
+        "gate holder's claim failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Gate token account"));
+    assert_eq!(token_balance(&claimants[0].address, &mint).unwrap(), 1_000);
+
+    let output = claim(&claimants[1].keypair_path);
+    assert!(!output.status.success(), "claim without the gate token should fail");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Airdrop is gated"));
+    assert_eq!(token_balance(&claimants[1].address, &mint).unwrap_or(0), 0);
+}
+
+#[test]
+#[serial]
+fn test_distribute_airdrop() {
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(3).expect("Failed to create test context");
+    let amounts = [1_000u64, 2_000u64, 3_000u64];
+    let claimants = ctx.get_claimants(&amounts).expect("Failed to get claimants");
+    ctx.create_csv(&claimants).expect("Failed to create CSV");
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 10).expect("Failed to fund authority");
+    fund_account(&claimants[0].address, 1).expect("Failed to fund claimant");
+
+    ctx.create_airdrop(&[]).expect("create-airdrop failed");
+
+    run_cli_success(&[
+        "deploy-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        ctx.authority_keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("deploy-airdrop failed");
+    let json = ctx.read_airdrop_json().expect("Failed to read airdrop JSON");
+    let mint = json["mint"].as_str().unwrap().to_string();
+
+    // The first claimant claims on their own; distribution must not pay them twice
+    run_cli_success(&[
+        "claim-airdrop",
+        "--json",
+        ctx.json_path.to_str().unwrap(),
+        "--network",
+        "localnet",
+        "--keypair",
+        claimants[0].keypair_path.to_str().unwrap(),
+        "--program-id",
+        PROGRAM_ID,
+    ])
+    .expect("claim-airdrop failed");
+
+    let distribute = || {
+        run_cli_success(&[
+            "distribute-airdrop",
+            "--json",
+            ctx.json_path.to_str().unwrap(),
+            "--batch-size",
+            "2",
+            "--network",
+            "localnet",
+            "--keypair",
+            ctx.authority_keypair_path.to_str().unwrap(),
+            "--program-id",
+            PROGRAM_ID,
+        ])
+        .expect("distribute-airdrop failed")
+    };
+
+    let output = distribute();
+    println!("distribute-airdrop output: {}", output);
+    assert!(output.contains("To distribute: 2"));
+    for (claimant, amount) in claimants.iter().zip(amounts) {
+        assert_eq!(token_balance(&claimant.address, &mint).unwrap(), amount);
+    }
+
+    // Receipts now cover every leaf, so a second run has nothing to send
+    let output = distribute();
+    assert!(output.contains("Nothing left to distribute"));
+    assert_eq!(token_balance(&claimants[1].address, &mint).unwrap(), 2_000);
+}
+
+/// Compare the compute units of a claim under each leaf encoding at several tree depths.
+///
+/// Not part of the regular suite; run with
+/// `cargo test --test integration_tests bench_claim_compute_units -- --ignored --nocapture`
+/// and the results are printed as a markdown table.
+#[test]
+#[serial]
+#[ignore]
+fn bench_claim_compute_units() {
+    use solana_sdk::pubkey::Pubkey;
+    use std::io::Write;
+
+    get_shared_validator().expect("Failed to start validator");
+
+    let ctx = TestContext::new(1).expect("Failed to create test context");
+    let claimant = ctx.get_claimants(&[100]).expect("Failed to get claimants").remove(0);
+
+    let authority_pubkey = ctx.authority_pubkey().expect("Failed to get authority pubkey");
+    fund_account(&authority_pubkey, 100).expect("Failed to fund authority");
+    fund_account(&claimant.address, 10).expect("Failed to fund claimant");
+
+    let mut rows = Vec::new();
+    for depth in [4u32, 10, 16, 20] {
+        // The claimant plus filler recipients, padding the tree to exactly `depth` levels
+        let mut file = std::fs::File::create(&ctx.csv_path).expect("Failed to create CSV");
+        writeln!(file, "address,amount").unwrap();
+        writeln!(file, "{},{}", claimant.address, claimant.amount).unwrap();
+        for _ in 1..(1usize << depth) {
+            writeln!(file, "{},1", Pubkey::new_unique()).unwrap();
+        }
+        drop(file);
+
+        let mut units = Vec::new();
+        for encoding in ["base58", "pubkey"] {
+            ctx.create_airdrop(&["--leaf-encoding", encoding]).expect("create-airdrop failed");
+
+            run_cli_success(&[
+                "deploy-airdrop",
+                "--json",
+                ctx.json_path.to_str().unwrap(),
+                "--network",
+                "localnet",
+                "--keypair",
+                ctx.authority_keypair_path.to_str().unwrap(),
+                "--program-id",
+                PROGRAM_ID,
+            ])
+            .expect("deploy-airdrop failed");
+
+            let output = run_cli_success(&[
+                "claim-airdrop",
+                "--json",
+                ctx.json_path.to_str().unwrap(),
+                "--network",
+                "localnet",
+                "--keypair",
+                claimant.keypair_path.to_str().unwrap(),
+                "--program-id",
+                PROGRAM_ID,
+            ])
+            .expect("claim-airdrop failed");
+
+            let signature = output
+                .lines()
+                .find_map(|line| line.strip_prefix("Signature: "))
+                .expect("Expected claim signature in output");
+            units.push(compute_units_consumed(signature).expect("Failed to read compute units"));
+        }
+        rows.push((depth, units[0], units[1]));
+    }
+
+    println!("| Tree depth | base58 leaf (CU) | pubkey leaf (CU) | Saved |");
+    println!("| ---------- | ---------------- | ---------------- | ----- |");
+    for (depth, base58, pubkey) in rows {
+        println!(
+            "| {} | {} | {} | {} |",
+            depth,
+            base58,
+            pubkey,
+            base58 as i64 - pubkey as i64
+        );
+        assert!(pubkey < base58, "pubkey leaves should be cheaper to verify");
+    }
+}
@@ -36,6 +36,7 @@ describe("claimAirdrop integration", () => {
       program,
       merkleRootHash,
       amount: totalAmount,
+      numLeaves: ctx.claimants.length,
     });
   });
 
@@ -52,6 +53,7 @@ describe("claimAirdrop integration", () => {
       signer: keypairToSigner(claimant.keypair),
       program: claimantProgram,
       merkleRootHash,
+      creator: ctx.authority.publicKey,
       claimData,
     });
 
@@ -73,6 +75,7 @@ describe("claimAirdrop integration", () => {
         signer: keypairToSigner(claimant.keypair),
         program: claimantProgram,
         merkleRootHash,
+        creator: ctx.authority.publicKey,
         claimData,
       })
     ).rejects.toThrow();
@@ -90,6 +93,7 @@ describe("claimAirdrop integration", () => {
       signer: keypairToSigner(claimant.keypair),
       program: claimantProgram,
       merkleRootHash,
+      creator: ctx.authority.publicKey,
       claimData,
     });
 
@@ -1,6 +1,7 @@
 import { describe, it, expect, beforeAll } from "vitest";
 import { PublicKey } from "@solana/web3.js";
 import { createAirdrop } from "../../src/core/createAirdrop";
+import { findMerkleRootPda } from "../../src/core/utils";
 import { TestContext, PROGRAM_ID } from "../fixtures/testContext";
 import {
   generateAirdropJson,
@@ -33,22 +34,29 @@ describe("createAirdrop integration", () => {
       program,
       merkleRootHash,
       amount: totalAmount,
+      numLeaves: ctx.claimants.length,
     });
 
     expect(result.signature).toBeDefined();
     expect(result.signature.length).toBeGreaterThan(0);
     expect(result.mint).toBeInstanceOf(PublicKey);
 
-    // Verify on-chain state
-    const merkleRootPda = PublicKey.findProgramAddressSync(
-      [Buffer.from("merkle_root"), Buffer.from(merkleRootHash)],
-      new PublicKey(PROGRAM_ID)
-    )[0];
+    // Verify on-chain state at the creator-namespaced address
+    const merkleRootPda = findMerkleRootPda(
+      new PublicKey(PROGRAM_ID),
+      ctx.authority.publicKey,
+      0,
+      merkleRootHash
+    );
+    expect(result.merkleRoot.toBase58()).toBe(merkleRootPda.toBase58());
 
     const merkleRootAccount = await program.account.merkleRoot.fetch(
       merkleRootPda
     );
     expect(merkleRootAccount.mint.toBase58()).toBe(result.mint.toBase58());
+    expect(merkleRootAccount.creator.toBase58()).toBe(
+      ctx.authority.publicKey.toBase58()
+    );
 
     // Verify the hash stored matches what we sent
     const storedHash = Array.from(merkleRootAccount.hash);
@@ -72,6 +80,7 @@ describe("createAirdrop integration", () => {
       program,
       merkleRootHash: merkleRootHash1,
       amount: 1000,
+      numLeaves: 2,
     });
 
     // Create second airdrop with different claimants
@@ -88,10 +97,35 @@ describe("createAirdrop integration", () => {
       program,
       merkleRootHash: merkleRootHash2,
       amount: 1000,
+      numLeaves: 2,
     });
 
     // Both should succeed with different mints
     expect(result1.mint.toBase58()).not.toBe(result2.mint.toBase58());
     expect(merkleRootHash1).not.toEqual(merkleRootHash2);
   });
+
+  it("creates the same root again under another nonce", async () => {
+    const program = ctx.getAuthorityProgram();
+    const airdropJson = generateAirdropJson(
+      ctx.claimants.map((c) => ({ address: c.address, amount: c.amount }))
+    );
+    const merkleRootHash = merkleRootToBytes(airdropJson.merkle_root);
+
+    const create = (nonce: number) =>
+      createAirdrop({
+        connection: ctx.connection,
+        signer: ctx.getAuthoritySigner(),
+        program,
+        merkleRootHash,
+        amount: 600,
+        numLeaves: ctx.claimants.length,
+        nonce,
+      });
+
+    const first = await create(0);
+    await expect(create(0)).rejects.toThrow();
+    const second = await create(1);
+    expect(second.merkleRoot.toBase58()).not.toBe(first.merkleRoot.toBase58());
+  });
 });
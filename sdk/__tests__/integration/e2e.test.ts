@@ -33,6 +33,7 @@ describe("E2E: Full airdrop flow", () => {
       program,
       merkleRootHash,
       amount: totalAmount,
+      numLeaves: ctx.claimants.length,
     });
 
     expect(createResult.signature).toBeDefined();
@@ -51,6 +52,7 @@ describe("E2E: Full airdrop flow", () => {
         signer: keypairToSigner(claimant.keypair),
         program: claimantProgram,
         merkleRootHash,
+        creator: ctx.authority.publicKey,
         claimData,
       });
 
@@ -92,6 +94,7 @@ describe("E2E: Full airdrop flow", () => {
       program,
       merkleRootHash,
       amount: totalAmount,
+      numLeaves: ctx.claimants.length,
     });
 
     expect(createResult.signature).toBeDefined();
@@ -109,6 +112,7 @@ describe("E2E: Full airdrop flow", () => {
         signer: keypairToSigner(claimant.keypair),
         program: claimantProgram,
         merkleRootHash,
+        creator: ctx.authority.publicKey,
         claimData,
       });
 
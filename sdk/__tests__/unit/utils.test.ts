@@ -1,5 +1,10 @@
 import { describe, it, expect } from "vitest";
-import { numberArrayToHex, hexToBytes } from "../../src/core/utils";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  numberArrayToHex,
+  hexToBytes,
+  findMerkleRootPda,
+} from "../../src/core/utils";
 
 describe("Utility functions", () => {
   describe("numberArrayToHex", () => {
@@ -60,4 +65,36 @@ describe("Utility functions", () => {
       expect(hexToBytes(hex)).toEqual(merkleRoot);
     });
   });
+
+  describe("findMerkleRootPda", () => {
+    const programId = new PublicKey(
+      "F6fHBUyYyaW14CxjSnJjLck8vMmWew3PbCnt5TMqRdZX"
+    );
+    const creator = Keypair.generate().publicKey;
+    const root = new Array(32).fill(7);
+
+    it("seeds the nonce as little-endian u64 between creator and root", () => {
+      const nonce = [1, 2, 0, 0, 0, 0, 0, 0];
+      const expected = PublicKey.findProgramAddressSync(
+        [
+          new TextEncoder().encode("merkle_root"),
+          creator.toBytes(),
+          new Uint8Array(nonce),
+          new Uint8Array(root),
+        ],
+        programId
+      )[0];
+      expect(findMerkleRootPda(programId, creator, 0x0201, root)).toEqual(
+        expected
+      );
+    });
+
+    it("gives each creator and nonce its own address", () => {
+      const pda = findMerkleRootPda(programId, creator, 0, root);
+      expect(findMerkleRootPda(programId, creator, 1, root)).not.toEqual(pda);
+      expect(
+        findMerkleRootPda(programId, Keypair.generate().publicKey, 0, root)
+      ).not.toEqual(pda);
+    });
+  });
 });
@@ -1,8 +1,14 @@
 import { BN, type Program } from "@coral-xyz/anchor";
 import { Connection, PublicKey, Transaction } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import type { AirdropContract, ClaimResponse } from "../types";
-import { hexToBytes, numberArrayToHex } from "./utils";
+import {
+  findClaimReceiptPda,
+  findConfigPda,
+  findMerkleRootPda,
+  hexToBytes,
+  numberArrayToHex,
+} from "./utils";
 import type { Signer } from "./createAirdrop";
 
 export interface ClaimAirdropParams {
@@ -10,6 +16,10 @@ export interface ClaimAirdropParams {
   signer: Signer;
   program: Program<AirdropContract>;
   merkleRootHash: number[];
+  /** Wallet that deployed the airdrop (`creator` in airdrop.json) */
+  creator: PublicKey;
+  /** Nonce the airdrop was deployed with (`nonce` in airdrop.json), default 0 */
+  nonce?: number;
   claimData: ClaimResponse;
 }
 
@@ -42,30 +52,73 @@ export async function fetchClaimData(
 export async function claimAirdrop(
   params: ClaimAirdropParams
 ): Promise<ClaimAirdropResult> {
-  const { connection, signer, program, merkleRootHash, claimData } = params;
+  const {
+    connection,
+    signer,
+    program,
+    merkleRootHash,
+    creator,
+    nonce = 0,
+    claimData,
+  } = params;
   const { claim, proof } = claimData;
 
-  const merkleRoot = PublicKey.findProgramAddressSync(
-    [
-      new TextEncoder().encode("merkle_root"),
-      new Uint8Array(merkleRootHash),
-    ],
-    program.programId
-  )[0];
+  const merkleRoot = findMerkleRootPda(
+    program.programId,
+    creator,
+    nonce,
+    merkleRootHash
+  );
 
   const merkleRootData = await program.account.merkleRoot.fetch(merkleRoot);
+  if (
+    merkleRootData.multiMint ||
+    merkleRootData.nativeSol ||
+    merkleRootData.mintOnClaim ||
+    merkleRootData.voucherSigner ||
+    merkleRootData.bitmapLeafCount !== null
+  ) {
+    throw new Error(
+      "Only single-mint token airdrops with receipts are supported; use the CLI"
+    );
+  }
   const mint = merkleRootData.mint;
+  const tokenProgram = await accountOwner(connection, mint);
+
+  // The fee recipient only matters while the program config charges a claim fee
+  const config = findConfigPda(program.programId);
+  const configData = await program.account.config.fetchNullable(config);
+  const feeRecipient = configData?.feeRecipient ?? signer.publicKey;
+
+  // Gated airdrops check the claimant's balance of the gate mint
+  const gateTokenAccount = merkleRootData.gate
+    ? getAssociatedTokenAddressSync(
+        merkleRootData.gate.mint,
+        signer.publicKey,
+        false,
+        await accountOwner(connection, merkleRootData.gate.mint)
+      )
+    : null;
 
   // Convert hex string proofs to byte arrays for Anchor
   const proofBytes = proof.map(hexToBytes);
 
   const claimIx = await program.methods
     .claim(proofBytes, new BN(claim.amount), claim.leaf_index)
-    .accounts({
-      merkleRoot,
-      mint,
-      tokenProgram: TOKEN_PROGRAM_ID,
+    .accountsPartial({
       authority: signer.publicKey,
+      payer: signer.publicKey,
+      mint,
+      merkleRoot,
+      claimReceipt: findClaimReceiptPda(
+        program.programId,
+        merkleRoot,
+        signer.publicKey
+      ),
+      gateTokenAccount,
+      config,
+      feeRecipient,
+      tokenProgram,
     })
     .instruction();
 
@@ -84,5 +137,19 @@ export async function claimAirdrop(
   return { signature, amount: claim.amount };
 }
 
+/**
+ * Program owning `address`, e.g. the token program of a mint
+ */
+async function accountOwner(
+  connection: Connection,
+  address: PublicKey
+): Promise<PublicKey> {
+  const account = await connection.getAccountInfo(address);
+  if (!account) {
+    throw new Error(`Account ${address.toBase58()} not found`);
+  }
+  return account.owner;
+}
+
 // Re-export utility for convenience
 export { numberArrayToHex, hexToBytes } from "./utils";
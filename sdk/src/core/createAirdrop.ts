@@ -7,6 +7,7 @@ import {
 } from "@solana/web3.js";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotentInstruction,
   createAssociatedTokenAccountInstruction,
   createInitializeMintInstruction,
   createMintToInstruction,
@@ -17,9 +18,14 @@ import {
 } from "@solana/spl-token";
 import { type Program, BN } from "@coral-xyz/anchor";
 import type { AirdropContract } from "../types";
+import { findConfigPda, findMerkleRootPda } from "./utils";
 
 const MINT_DECIMALS = 6;
 const TEN_THOUSAND_TOKENS = 10_000 * 10 ** MINT_DECIMALS;
+const DEFAULT_EXPIRY_SECS = 30 * 24 * 60 * 60;
+// Hashing the CLI's `create-airdrop` uses by default
+const HASH_VERSION_V2 = 2;
+const LEAF_ENCODING_PUBKEY = 1;
 
 /**
  * A signer interface compatible with both AnchorWallet and Keypair-based signers
@@ -35,11 +41,19 @@ export interface CreateAirdropParams {
   program: Program<AirdropContract>;
   merkleRootHash: number[];
   amount: number;
+  /** Leaves in the tree, recorded on-chain for accounting */
+  numLeaves: number;
+  /** Tells apart airdrops of the same root by the same creator; default 0 */
+  nonce?: number;
+  /** Unix time from which unclaimed tokens can be clawed back; default 30 days */
+  expiresAt?: number;
 }
 
 export interface CreateAirdropResult {
   signature: string;
   mint: PublicKey;
+  /** Address of the airdrop, derived from the creator, nonce and root */
+  merkleRoot: PublicKey;
 }
 
 /**
@@ -47,12 +61,21 @@ export interface CreateAirdropResult {
  * 1. Creating a new mint
  * 2. Creating the authority's associated token account
  * 3. Minting tokens to the authority
- * 4. Creating the on-chain merkle root account
+ * 4. Creating the on-chain merkle root account, paying any protocol deposit fee
  */
 export async function createAirdrop(
   params: CreateAirdropParams
 ): Promise<CreateAirdropResult> {
-  const { connection, signer, program, merkleRootHash, amount } = params;
+  const {
+    connection,
+    signer,
+    program,
+    merkleRootHash,
+    amount,
+    numLeaves,
+    nonce = 0,
+    expiresAt = Math.floor(Date.now() / 1000) + DEFAULT_EXPIRY_SECS,
+  } = params;
 
   // Generate keypair to use as address of mint
   const mint = Keypair.generate();
@@ -96,12 +119,48 @@ export async function createAirdrop(
     TOKEN_PROGRAM_ID
   );
 
+  // A deposit fee goes to the fee recipient's token account, which a new mint
+  // lacks; with no fee due the program ignores the account, so pass our own
+  const configData = await program.account.config.fetchNullable(
+    findConfigPda(program.programId)
+  );
+  const feeRecipient =
+    configData && "fundedBps" in configData.fee
+      ? configData.feeRecipient
+      : null;
+  const feeRecipientTokenAccount = feeRecipient
+    ? getAssociatedTokenAddressSync(mint.publicKey, feeRecipient)
+    : signerATA;
+
+  const merkleRoot = findMerkleRootPda(
+    program.programId,
+    signer.publicKey,
+    nonce,
+    merkleRootHash
+  );
+
   const createMerkleTree = await program.methods
-    .createAirdrop(merkleRootHash, new BN(amount))
-    .accounts({
+    .createAirdrop(merkleRootHash, new BN(amount), {
+      expiresAt: new BN(expiresAt),
+      claimStart: null,
+      claimEnd: null,
+      vesting: null,
+      cumulative: false,
+      hashVersion: HASH_VERSION_V2,
+      leafEncoding: LEAF_ENCODING_PUBKEY,
+      voucherSigner: null,
+      bitmapLeafCount: null,
+      nonce: new BN(nonce),
+      multiMint: false,
+      numLeaves,
+      gate: null,
+    })
+    .accountsPartial({
+      authority: signer.publicKey,
       mint: mint.publicKey,
+      merkleRoot,
+      feeRecipientTokenAccount,
       tokenProgram: TOKEN_PROGRAM_ID,
-      authority: signer.publicKey,
     })
     .instruction();
 
@@ -109,9 +168,21 @@ export async function createAirdrop(
     createAccountInstruction,
     initializeMintInstruction,
     createSignerATAInstruction,
-    mintToAtaInstruction,
-    createMerkleTree
+    mintToAtaInstruction
   );
+  if (feeRecipient) {
+    transaction.add(
+      createAssociatedTokenAccountIdempotentInstruction(
+        signer.publicKey,
+        feeRecipientTokenAccount,
+        feeRecipient,
+        mint.publicKey,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      )
+    );
+  }
+  transaction.add(createMerkleTree);
 
   const blockhash = await connection.getLatestBlockhash();
   transaction.recentBlockhash = blockhash.blockhash;
@@ -122,5 +193,5 @@ export async function createAirdrop(
 
   await connection.confirmTransaction(signature);
 
-  return { signature, mint: mint.publicKey };
+  return { signature, mint: mint.publicKey, merkleRoot };
 }
@@ -13,4 +13,10 @@ export {
   type ClaimAirdropResult,
 } from "./claimAirdrop";
 
-export { numberArrayToHex, hexToBytes } from "./utils";
+export {
+  numberArrayToHex,
+  hexToBytes,
+  findMerkleRootPda,
+  findClaimReceiptPda,
+  findConfigPda,
+} from "./utils";
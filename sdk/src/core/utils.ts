@@ -1,3 +1,6 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
 /**
  * Convert a number array (bytes) to a hex string
  */
@@ -15,3 +18,50 @@ export function hexToBytes(hex: string): number[] {
   }
   return bytes;
 }
+
+const MERKLE_ROOT_SEED = new TextEncoder().encode("merkle_root");
+const CLAIM_RECEIPT_SEED = new TextEncoder().encode("receipt");
+const CONFIG_SEED = new TextEncoder().encode("config");
+
+/**
+ * Derive the MerkleRoot PDA that `creator` deployed for a root under `nonce`
+ */
+export function findMerkleRootPda(
+  programId: PublicKey,
+  creator: PublicKey,
+  nonce: number | BN,
+  merkleRootHash: number[]
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      MERKLE_ROOT_SEED,
+      creator.toBytes(),
+      new Uint8Array(new BN(nonce).toArray("le", 8)),
+      new Uint8Array(merkleRootHash),
+    ],
+    programId
+  )[0];
+}
+
+/**
+ * Derive the receipt tracking a claimant's claims, keyed by mint if multi-mint
+ */
+export function findClaimReceiptPda(
+  programId: PublicKey,
+  merkleRoot: PublicKey,
+  claimant: PublicKey,
+  mint?: PublicKey
+): PublicKey {
+  const seeds = [CLAIM_RECEIPT_SEED, merkleRoot.toBytes(), claimant.toBytes()];
+  if (mint) {
+    seeds.push(mint.toBytes());
+  }
+  return PublicKey.findProgramAddressSync(seeds, programId)[0];
+}
+
+/**
+ * Derive the program config PDA holding the protocol fee settings
+ */
+export function findConfigPda(programId: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync([CONFIG_SEED], programId)[0];
+}
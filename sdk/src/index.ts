@@ -31,6 +31,9 @@ export {
   fetchClaimData,
   numberArrayToHex,
   hexToBytes,
+  findMerkleRootPda,
+  findClaimReceiptPda,
+  findConfigPda,
   type CreateAirdropParams,
   type CreateAirdropResult,
   type ClaimAirdropParams,
@@ -36,6 +36,149 @@ export type AirdropContract = {
       accounts: [
         {
           name: "authority";
+          docs: ["Recipient named in the leaf; only needs to sign"];
+          signer: true;
+        },
+        {
+          name: "payer";
+          docs: [
+            "Covers fees and rent; may be a sponsor or the recipient themselves"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "authorityTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "authority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "claimReceipt";
+          writable: true;
+        },
+        {
+          name: "gateTokenAccount";
+          docs: [
+            "The claimant's token account of the gate mint; required when the airdrop is gated"
+          ];
+          optional: true;
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipient";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        }
+      ];
+      args: [
+        {
+          name: "proof";
+          type: {
+            vec: {
+              array: ["u8", 32];
+            };
+          };
+        },
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "leafIndex";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "claimLegacy";
+      discriminator: [241, 247, 40, 192, 213, 172, 177, 89];
+      accounts: [
+        {
+          name: "authority";
+          docs: ["Recipient named in the leaf; only needs to sign"];
+          signer: true;
+        },
+        {
+          name: "payer";
+          docs: ["Covers rent; may be a sponsor or the recipient themselves"];
           writable: true;
           signer: true;
         },
@@ -100,9 +243,14 @@ export type AirdropContract = {
         },
         {
           name: "merkleRoot";
+          docs: ["seeds and mint"];
         },
         {
           name: "claimReceipt";
+          docs: [
+            "Claims were one-shot, so an existing receipt, including one the first release wrote,",
+            "means the leaf has been paid"
+          ];
           writable: true;
           pda: {
             seeds: [
@@ -153,22 +301,33 @@ export type AirdropContract = {
       ];
     },
     {
-      name: "createAirdrop";
-      discriminator: [227, 135, 208, 66, 137, 177, 80, 94];
+      name: "claimLink";
+      discriminator: [87, 206, 8, 104, 149, 12, 185, 250];
       accounts: [
         {
           name: "authority";
+          docs: ["Ephemeral key from the claim link; named in the leaf"];
+          signer: true;
+        },
+        {
+          name: "payer";
+          docs: [
+            "Covers fees and rent; usually the destination wallet or a sponsor"
+          ];
           writable: true;
           signer: true;
         },
         {
-          name: "authorityTokenAccount";
+          name: "destination";
+        },
+        {
+          name: "destinationTokenAccount";
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "account";
-                path: "authority";
+                path: "destination";
               },
               {
                 kind: "account";
@@ -223,19 +382,33 @@ export type AirdropContract = {
         {
           name: "merkleRoot";
           writable: true;
+        },
+        {
+          name: "claimReceipt";
+          writable: true;
+        },
+        {
+          name: "gateTokenAccount";
+          docs: [
+            "The destination's token account of the gate mint; required when the airdrop is gated"
+          ];
+          optional: true;
+        },
+        {
+          name: "config";
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [109, 101, 114, 107, 108, 101, 95, 114, 111, 111, 116];
-              },
-              {
-                kind: "arg";
-                path: "merkleRootHash";
+                value: [99, 111, 110, 102, 105, 103];
               }
             ];
           };
         },
+        {
+          name: "feeRecipient";
+          writable: true;
+        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
@@ -250,73 +423,3312 @@ export type AirdropContract = {
       ];
       args: [
         {
-          name: "merkleRootHash";
+          name: "proof";
           type: {
-            array: ["u8", 32];
+            vec: {
+              array: ["u8", 32];
+            };
           };
         },
         {
           name: "amount";
           type: "u64";
+        },
+        {
+          name: "leafIndex";
+          type: "u32";
         }
       ];
-    }
-  ];
-  accounts: [
-    {
-      name: "claimReceipt";
-      discriminator: [223, 233, 11, 229, 124, 165, 207, 28];
-    },
-    {
-      name: "merkleRoot";
-      discriminator: [30, 240, 94, 145, 74, 59, 138, 185];
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "invalidAmount";
-      msg: "Invalid amount";
-    },
-    {
-      code: 6001;
-      name: "invalidProof";
-      msg: "Invalid proof";
-    }
-  ];
-  types: [
-    {
-      name: "claimReceipt";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "amount";
-            type: "u64";
-          }
-        ];
-      };
     },
     {
-      name: "merkleRoot";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "hash";
-            type: {
-              array: ["u8", 32];
+      name: "claimMint";
+      discriminator: [49, 111, 110, 128, 99, 195, 144, 164];
+      accounts: [
+        {
+          name: "authority";
+          docs: ["Recipient named in the leaf; only needs to sign"];
+          signer: true;
+        },
+        {
+          name: "payer";
+          docs: [
+            "Covers fees and rent; may be a sponsor or the recipient themselves"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "authorityTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "authority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
             };
-          },
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "mint";
-            type: "pubkey";
-          }
-        ];
+          };
+        },
+        {
+          name: "mint";
+          writable: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "claimReceipt";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 101, 99, 101, 105, 112, 116];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "gateTokenAccount";
+          docs: [
+            "The claimant's token account of the gate mint; required when the airdrop is gated"
+          ];
+          optional: true;
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipient";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        }
+      ];
+      args: [
+        {
+          name: "proof";
+          type: {
+            vec: {
+              array: ["u8", 32];
+            };
+          };
+        },
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "leafIndex";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "claimSol";
+      discriminator: [139, 113, 179, 189, 190, 30, 132, 195];
+      accounts: [
+        {
+          name: "authority";
+          docs: [
+            "Recipient named in the leaf; signs and receives the lamports"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "payer";
+          docs: [
+            "Covers fees and rent; may be a sponsor or the recipient themselves"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "claimReceipt";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 101, 99, 101, 105, 112, 116];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "gateTokenAccount";
+          docs: [
+            "The claimant's token account of the gate mint; required when the airdrop is gated"
+          ];
+          optional: true;
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipient";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "proof";
+          type: {
+            vec: {
+              array: ["u8", 32];
+            };
+          };
+        },
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "leafIndex";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "claimVoucher";
+      discriminator: [229, 30, 138, 35, 188, 87, 230, 7];
+      accounts: [
+        {
+          name: "recipient";
+        },
+        {
+          name: "payer";
+          docs: [
+            "Covers fees and rent; the recipient or anyone relaying the voucher"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "recipientTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "recipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "claimReceipt";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 101, 99, 101, 105, 112, 116];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "arg";
+                path: "voucher.nonce";
+              }
+            ];
+          };
+        },
+        {
+          name: "instructionsSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
+        },
+        {
+          name: "gateTokenAccount";
+          docs: [
+            "The recipient's token account of the gate mint; required when the airdrop is gated"
+          ];
+          optional: true;
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipient";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        }
+      ];
+      args: [
+        {
+          name: "voucher";
+          type: {
+            defined: {
+              name: "voucher";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "claimWithBitmap";
+      discriminator: [231, 8, 33, 36, 232, 240, 199, 199];
+      accounts: [
+        {
+          name: "authority";
+          docs: ["Recipient named in the leaf; only needs to sign"];
+          signer: true;
+        },
+        {
+          name: "payer";
+          docs: [
+            "Covers fees and token account rent; may be a sponsor or the recipient themselves"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "authorityTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "authority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "claimBitmap";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [98, 105, 116, 109, 97, 112];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "claim_bitmap.index";
+                account: "claimBitmap";
+              }
+            ];
+          };
+        },
+        {
+          name: "gateTokenAccount";
+          docs: [
+            "The claimant's token account of the gate mint; required when the airdrop is gated"
+          ];
+          optional: true;
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipient";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        }
+      ];
+      args: [
+        {
+          name: "proof";
+          type: {
+            vec: {
+              array: ["u8", 32];
+            };
+          };
+        },
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "leafIndex";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "clawback";
+      discriminator: [111, 92, 142, 79, 33, 234, 82, 27];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "destinationTokenAccount";
+          docs: ["Receives the unclaimed balance. Omit when burning."];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "mint";
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [
+        {
+          name: "burn";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "clawbackLegacy";
+      discriminator: [74, 50, 10, 34, 36, 140, 141, 231];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "program";
+          address: "F6fHBUyYyaW14CxjSnJjLck8vMmWew3PbCnt5TMqRdZX";
+        },
+        {
+          name: "programData";
+        },
+        {
+          name: "merkleRoot";
+          docs: ["seeds and mint"];
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "destinationTokenAccount";
+          docs: ["Receives the unclaimed balance"];
+          writable: true;
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "clawbackSol";
+      discriminator: [181, 86, 33, 24, 14, 242, 225, 244];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "destination";
+          writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeAirdrop";
+      discriminator: [85, 138, 99, 129, 104, 203, 94, 4];
+      accounts: [
+        {
+          name: "authority";
+          docs: ["Receives the rent of the merkle root account and its vault"];
+          writable: true;
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "closedAirdrop";
+          docs: [
+            "Stops the merkle root from ever being created again once its receipts can be closed"
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 108, 111, 115, 101, 100];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              }
+            ];
+          };
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeClaimBitmap";
+      discriminator: [118, 241, 18, 111, 212, 33, 82, 96];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+          relations: ["closedAirdrop"];
+        },
+        {
+          name: "merkleRoot";
+        },
+        {
+          name: "closedAirdrop";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 108, 111, 115, 101, 100];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              }
+            ];
+          };
+        },
+        {
+          name: "claimBitmap";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [98, 105, 116, 109, 97, 112];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "claim_bitmap.index";
+                account: "claimBitmap";
+              }
+            ];
+          };
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeClaimReceipt";
+      discriminator: [86, 202, 240, 236, 35, 20, 157, 183];
+      accounts: [
+        {
+          name: "claimant";
+        },
+        {
+          name: "payer";
+          writable: true;
+        },
+        {
+          name: "merkleRoot";
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "claimReceipt";
+          writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeLegacyAirdrop";
+      discriminator: [246, 152, 72, 98, 124, 155, 105, 253];
+      accounts: [
+        {
+          name: "authority";
+          docs: ["Receives the rent of the merkle root account and its vault"];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "program";
+          address: "F6fHBUyYyaW14CxjSnJjLck8vMmWew3PbCnt5TMqRdZX";
+        },
+        {
+          name: "programData";
+        },
+        {
+          name: "merkleRoot";
+          docs: ["seeds and mint"];
+          writable: true;
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeMetadata";
+      discriminator: [10, 220, 196, 138, 19, 60, 204, 130];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+        },
+        {
+          name: "metadata";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [109, 101, 116, 97, 100, 97, 116, 97];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              }
+            ];
+          };
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeMintAirdrop";
+      discriminator: [172, 61, 178, 148, 116, 159, 158, 13];
+      accounts: [
+        {
+          name: "authority";
+          docs: ["Receives the rent and the mint authority back"];
+          writable: true;
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "closedAirdrop";
+          docs: [
+            "Stops the merkle root from ever being created again once its receipts can be closed"
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 108, 111, 115, 101, 100];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          writable: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeSolAirdrop";
+      discriminator: [66, 130, 191, 179, 87, 134, 156, 141];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "closedAirdrop";
+          docs: [
+            "Stops the merkle root from ever being created again once its receipts can be closed"
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 108, 111, 115, 101, 100];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeVault";
+      discriminator: [141, 103, 17, 126, 72, 75, 29, 29];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "vaultRecord";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createAirdrop";
+      discriminator: [227, 135, 208, 66, 137, 177, 80, 94];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "authorityTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "authority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [109, 101, 114, 107, 108, 101, 95, 114, 111, 111, 116];
+              },
+              {
+                kind: "account";
+                path: "authority";
+              },
+              {
+                kind: "arg";
+                path: "params.nonce";
+              },
+              {
+                kind: "arg";
+                path: "merkleRootHash";
+              }
+            ];
+          };
+        },
+        {
+          name: "closedAirdrop";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 108, 111, 115, 101, 100];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              }
+            ];
+          };
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipientTokenAccount";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        }
+      ];
+      args: [
+        {
+          name: "merkleRootHash";
+          type: {
+            array: ["u8", 32];
+          };
+        },
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "params";
+          type: {
+            defined: {
+              name: "createAirdropParams";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "createMintAirdrop";
+      discriminator: [64, 156, 207, 230, 205, 141, 83, 92];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [109, 101, 114, 107, 108, 101, 95, 114, 111, 111, 116];
+              },
+              {
+                kind: "account";
+                path: "authority";
+              },
+              {
+                kind: "arg";
+                path: "params.nonce";
+              },
+              {
+                kind: "arg";
+                path: "merkleRootHash";
+              }
+            ];
+          };
+        },
+        {
+          name: "closedAirdrop";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 108, 111, 115, 101, 100];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          docs: [
+            "Mint authority must already have been handed to the merkle root PDA"
+          ];
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "merkleRootHash";
+          type: {
+            array: ["u8", 32];
+          };
+        },
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "params";
+          type: {
+            defined: {
+              name: "createAirdropParams";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "createSolAirdrop";
+      discriminator: [124, 10, 199, 134, 135, 220, 102, 197];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "merkleRoot";
+          docs: ["Holds the airdropped lamports on top of its own rent"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [109, 101, 114, 107, 108, 101, 95, 114, 111, 111, 116];
+              },
+              {
+                kind: "account";
+                path: "authority";
+              },
+              {
+                kind: "arg";
+                path: "params.nonce";
+              },
+              {
+                kind: "arg";
+                path: "merkleRootHash";
+              }
+            ];
+          };
+        },
+        {
+          name: "closedAirdrop";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 108, 111, 115, 101, 100];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              }
+            ];
+          };
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipient";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "merkleRootHash";
+          type: {
+            array: ["u8", 32];
+          };
+        },
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "params";
+          type: {
+            defined: {
+              name: "createAirdropParams";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "distribute";
+      discriminator: [191, 44, 223, 207, 164, 236, 126, 61];
+      accounts: [
+        {
+          name: "authority";
+          docs: ["Airdrop authority; covers fees and rent"];
+          writable: true;
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "recipient";
+        },
+        {
+          name: "recipientTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "recipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "claimReceipt";
+          docs: [
+            "Same receipt the recipient's own claim would use, so neither can pay twice"
+          ];
+          writable: true;
+        },
+        {
+          name: "gateTokenAccount";
+          docs: [
+            "The recipient's token account of the gate mint; required when the airdrop is gated"
+          ];
+          optional: true;
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipient";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        }
+      ];
+      args: [
+        {
+          name: "proof";
+          type: {
+            vec: {
+              array: ["u8", 32];
+            };
+          };
+        },
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "leafIndex";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "fundAirdrop";
+      discriminator: [63, 24, 238, 60, 169, 178, 131, 99];
+      accounts: [
+        {
+          name: "funder";
+          docs: [
+            "Anyone may add tokens to an open vault; only the authority can open one for another mint",
+            "of a multi-mint airdrop, or ever take tokens back out"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "funderTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "funder";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "merkleRootTokenAccount";
+          docs: ["Opened here for the other mints of a multi-mint airdrop"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "vaultRecord";
+          docs: [
+            "Required when funding one of a multi-mint airdrop's additional mints"
+          ];
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+          };
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipientTokenAccount";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "initClaimBitmap";
+      discriminator: [81, 138, 63, 243, 239, 39, 77, 218];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+        },
+        {
+          name: "claimBitmap";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [98, 105, 116, 109, 97, 112];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "arg";
+                path: "index";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "initializeConfig";
+      discriminator: [208, 127, 21, 1, 194, 190, 196, 70];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "program";
+          address: "F6fHBUyYyaW14CxjSnJjLck8vMmWew3PbCnt5TMqRdZX";
+        },
+        {
+          name: "programData";
+        },
+        {
+          name: "config";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "admin";
+          type: "pubkey";
+        },
+        {
+          name: "feeRecipient";
+          type: "pubkey";
+        },
+        {
+          name: "fee";
+          type: {
+            defined: {
+              name: "protocolFee";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "pause";
+      discriminator: [211, 22, 221, 251, 74, 121, 193, 47];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "unpause";
+      discriminator: [169, 144, 4, 38, 10, 141, 188, 255];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateConfig";
+      discriminator: [29, 158, 252, 191, 10, 83, 219, 99];
+      accounts: [
+        {
+          name: "admin";
+          signer: true;
+          relations: ["config"];
+        },
+        {
+          name: "config";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        }
+      ];
+      args: [
+        {
+          name: "admin";
+          type: {
+            option: "pubkey";
+          };
+        },
+        {
+          name: "feeRecipient";
+          type: {
+            option: "pubkey";
+          };
+        },
+        {
+          name: "fee";
+          type: {
+            option: {
+              defined: {
+                name: "protocolFee";
+              };
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "updateMetadata";
+      discriminator: [170, 182, 43, 239, 97, 78, 225, 186];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+        },
+        {
+          name: "metadata";
+          docs: [
+            "Sized for the longest fields up front, so later edits never need a realloc"
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [109, 101, 116, 97, 100, 97, 116, 97];
+              },
+              {
+                kind: "account";
+                path: "merkleRoot";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: {
+              name: "metadataParams";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "updateRoot";
+      discriminator: [58, 195, 57, 246, 116, 198, 170, 138];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "merkleRoot";
+          writable: true;
+        },
+        {
+          name: "authorityTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "authority";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "merkleRootTokenAccount";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "merkleRoot";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          relations: ["merkleRoot"];
+        },
+        {
+          name: "config";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: "feeRecipientTokenAccount";
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [
+        {
+          name: "merkleRootHash";
+          type: {
+            array: ["u8", 32];
+          };
+        },
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "numLeaves";
+          type: "u32";
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "airdropMetadata";
+      discriminator: [33, 119, 191, 211, 97, 60, 140, 244];
+    },
+    {
+      name: "claimBitmap";
+      discriminator: [61, 25, 148, 196, 164, 208, 65, 169];
+    },
+    {
+      name: "claimReceipt";
+      discriminator: [223, 233, 11, 229, 124, 165, 207, 28];
+    },
+    {
+      name: "closedAirdrop";
+      discriminator: [124, 144, 232, 233, 91, 215, 14, 207];
+    },
+    {
+      name: "config";
+      discriminator: [155, 12, 170, 224, 30, 250, 204, 130];
+    },
+    {
+      name: "merkleRoot";
+      discriminator: [30, 240, 94, 145, 74, 59, 138, 185];
+    },
+    {
+      name: "vaultRecord";
+      discriminator: [47, 1, 218, 116, 82, 70, 124, 119];
+    }
+  ];
+  events: [
+    {
+      discriminator: [196, 85, 30, 72, 165, 151, 163, 126];
+      name: "airdropClosed";
+    },
+    {
+      discriminator: [190, 219, 101, 33, 208, 187, 149, 96];
+      name: "airdropCreated";
+    },
+    {
+      discriminator: [18, 9, 102, 76, 28, 210, 194, 99];
+      name: "airdropFunded";
+    },
+    {
+      discriminator: [112, 247, 12, 27, 2, 79, 224, 53];
+      name: "claimBitmapClosed";
+    },
+    {
+      discriminator: [73, 90, 217, 190, 100, 148, 41, 100];
+      name: "claimBitmapInitialized";
+    },
+    {
+      discriminator: [253, 101, 92, 9, 109, 176, 169, 100];
+      name: "claimReceiptClosed";
+    },
+    {
+      discriminator: [217, 192, 123, 72, 108, 150, 248, 33];
+      name: "claimed";
+    },
+    {
+      discriminator: [128, 17, 202, 207, 94, 191, 64, 83];
+      name: "clawedBack";
+    },
+    {
+      discriminator: [40, 241, 230, 122, 11, 19, 198, 194];
+      name: "configUpdated";
+    },
+    {
+      discriminator: [115, 86, 221, 209, 3, 52, 52, 149];
+      name: "metadataClosed";
+    },
+    {
+      discriminator: [132, 36, 215, 246, 166, 90, 189, 44];
+      name: "metadataUpdated";
+    },
+    {
+      discriminator: [175, 57, 198, 136, 192, 66, 204, 73];
+      name: "pauseSet";
+    },
+    {
+      discriminator: [224, 102, 140, 88, 121, 78, 18, 63];
+      name: "protocolFeePaid";
+    },
+    {
+      discriminator: [94, 53, 22, 128, 141, 113, 98, 231];
+      name: "rootUpdated";
+    },
+    {
+      discriminator: [238, 129, 38, 228, 227, 118, 249, 215];
+      name: "vaultClosed";
+    },
+    {
+      discriminator: [45, 117, 206, 58, 70, 216, 79, 135];
+      name: "voucherClaimed";
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "invalidAmount";
+      msg: "Invalid amount";
+    },
+    {
+      code: 6001;
+      name: "invalidProof";
+      msg: "Invalid proof";
+    },
+    {
+      code: 6002;
+      name: "invalidExpiry";
+      msg: "Expiry must be in the future";
+    },
+    {
+      code: 6003;
+      name: "airdropNotExpired";
+      msg: "Airdrop has not expired yet";
+    },
+    {
+      code: 6004;
+      name: "unauthorized";
+      msg: "Signer is not the airdrop authority";
+    },
+    {
+      code: 6005;
+      name: "missingDestination";
+      msg: "A destination token account is required unless burning";
+    },
+    {
+      code: 6006;
+      name: "invalidClaimWindow";
+      msg: "Claim window must open before expiry and end after it opens, no later than expiry";
+    },
+    {
+      code: 6007;
+      name: "claimNotStarted";
+      msg: "Claiming has not started yet";
+    },
+    {
+      code: 6008;
+      name: "claimEnded";
+      msg: "Claiming has ended";
+    },
+    {
+      code: 6009;
+      name: "invalidVestingSchedule";
+      msg: "Vesting schedule must satisfy start <= cliff <= end and start < end";
+    },
+    {
+      code: 6010;
+      name: "nothingToClaim";
+      msg: "Nothing is claimable yet";
+    },
+    {
+      code: 6011;
+      name: "notCumulative";
+      msg: "Only cumulative airdrops can publish a new root";
+    },
+    {
+      code: 6012;
+      name: "airdropPaused";
+      msg: "Airdrop is paused";
+    },
+    {
+      code: 6013;
+      name: "vaultNotEmpty";
+      msg: "Vault still holds tokens; claw them back first";
+    },
+    {
+      code: 6014;
+      name: "vaultsStillOpen";
+      msg: "Close the vaults of the airdrop's other mints first";
+    },
+    {
+      code: 6015;
+      name: "vaultRecordMissing";
+      msg: "Funding another mint of a multi-mint airdrop needs its vault record";
+    },
+    {
+      code: 6016;
+      name: "airdropNotClosed";
+      msg: "Airdrop must be closed before its receipts";
+    },
+    {
+      code: 6017;
+      name: "airdropAlreadyClosed";
+      msg: "An airdrop at this address was closed and cannot be created again";
+    },
+    {
+      code: 6018;
+      name: "invalidVoucherConfig";
+      msg: "Voucher airdrops cannot be cumulative";
+    },
+    {
+      code: 6019;
+      name: "wrongDistributionType";
+      msg: "Instruction does not match the airdrop's distribution type";
+    },
+    {
+      code: 6020;
+      name: "invalidVoucherSignature";
+      msg: "Voucher must be signed by the airdrop's voucher signer";
+    },
+    {
+      code: 6021;
+      name: "voucherExpired";
+      msg: "Voucher has expired";
+    },
+    {
+      code: 6022;
+      name: "unsupportedHashVersion";
+      msg: "Unsupported hash version";
+    },
+    {
+      code: 6023;
+      name: "unsupportedLeafEncoding";
+      msg: "Unsupported leaf encoding";
+    },
+    {
+      code: 6024;
+      name: "invalidBitmapConfig";
+      msg: "Claim bitmaps need 1 to 2^31 leaves and a one-shot, index-bound sha256 tree";
+    },
+    {
+      code: 6025;
+      name: "invalidBitmapAccount";
+      msg: "Claim bitmap account does not cover this leaf";
+    },
+    {
+      code: 6026;
+      name: "alreadyClaimed";
+      msg: "Leaf has already been claimed";
+    },
+    {
+      code: 6027;
+      name: "invalidMint";
+      msg: "Mint is not part of this airdrop";
+    },
+    {
+      code: 6028;
+      name: "invalidMultiMintConfig";
+      msg: "Multi-mint airdrops only support receipt-based merkle claims";
+    },
+    {
+      code: 6029;
+      name: "invalidSolConfig";
+      msg: "SOL airdrops cannot be cumulative, multi-mint, voucher or bitmap airdrops";
+    },
+    {
+      code: 6030;
+      name: "insufficientVaultBalance";
+      msg: "Vault does not hold enough to pay this claim";
+    },
+    {
+      code: 6031;
+      name: "recipientNotRentExempt";
+      msg: "Recipient would hold less than the rent-exempt minimum; fund it with some SOL first";
+    },
+    {
+      code: 6032;
+      name: "claimExceedsFunding";
+      msg: "Claim would pay out more than the airdrop has been funded with";
+    },
+    {
+      code: 6033;
+      name: "invalidMintOnClaimConfig";
+      msg: "Mint-on-claim airdrops need the merkle root as mint authority and only support merkle claims with receipts";
+    },
+    {
+      code: 6034;
+      name: "notConfigAdmin";
+      msg: "Signer is not the config admin";
+    },
+    {
+      code: 6035;
+      name: "notUpgradeAuthority";
+      msg: "Signer is not the program's upgrade authority";
+    },
+    {
+      code: 6036;
+      name: "invalidFeeConfig";
+      msg: "Funding fee cannot exceed 10000 basis points";
+    },
+    {
+      code: 6037;
+      name: "invalidFeeRecipient";
+      msg: "Fee recipient does not match the program config";
+    },
+    {
+      code: 6038;
+      name: "metadataTooLong";
+      msg: "Metadata name is limited to 32 bytes and URIs to 200 bytes";
+    },
+    {
+      code: 6039;
+      name: "invalidGateConfig";
+      msg: "Claim gate needs a minimum balance of at least 1";
+    },
+    {
+      code: 6040;
+      name: "gateAccountMissing";
+      msg: "This airdrop is gated: pass the claimant's token account of the gate mint";
+    },
+    {
+      code: 6041;
+      name: "invalidGateAccount";
+      msg: "Gate token account is not the claimant's account of the gate mint";
+    },
+    {
+      code: 6042;
+      name: "gateBalanceTooLow";
+      msg: "Claimant does not hold enough of the gate mint";
+    }
+  ];
+  types: [
+    {
+      name: "airdropClosed";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "airdropCreated";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "root";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            docs: [
+              "Deposited into the vault, before any Token-2022 transfer fee"
+            ];
+            name: "amount";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "airdropFunded";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "funder";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "airdropMetadata";
+      docs: [
+        "Display details that wallets and apps show instead of the hex root, at",
+        "`[METADATA_SEED, merkle_root]`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "creator";
+            docs: ["Authority of the airdrop that wrote the metadata"];
+            type: "pubkey";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "descriptionUri";
+            docs: ["Off-chain JSON or page describing the airdrop"];
+            type: "string";
+          },
+          {
+            name: "imageUri";
+            type: "string";
+          },
+          {
+            name: "website";
+            docs: ["Where recipients go to claim"];
+            type: "string";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "claimBitmap";
+      docs: [
+        "One slice of a bitmap airdrop's claim flags: bit `i` is set once the leaf at",
+        "position `index * CLAIM_BITMAP_BITS + i` has been claimed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "index";
+            type: "u32";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "bits";
+            type: "bytes";
+          }
+        ];
+      };
+    },
+    {
+      name: "claimBitmapClosed";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "index";
+            type: "u32";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "claimBitmapInitialized";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "index";
+            type: "u32";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "claimGate";
+      docs: [
+        "Claims are only paid to holders of at least `min_balance` of `mint` at claim time."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "minBalance";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "claimReceipt";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amount";
+            docs: ["Total allocation committed to by the claimant's leaf"];
+            type: "u64";
+          },
+          {
+            name: "claimedSoFar";
+            type: "u64";
+          },
+          {
+            name: "payer";
+            docs: [
+              "Paid the receipt's rent and receives it back when the receipt is closed"
+            ];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "claimReceiptClosed";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "claimant";
+            type: "pubkey";
+          },
+          {
+            name: "payer";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "claimed";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "root";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            docs: [
+              "Address named in the leaf; a claim link pays someone else on its behalf"
+            ];
+            name: "claimant";
+            type: "pubkey";
+          },
+          {
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            docs: [
+              "Paid out by this claim, which is less than the allocation while vesting"
+            ];
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "leafIndex";
+            type: "u32";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "clawedBack";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            docs: ["`None` when the tokens were burned"];
+            name: "destination";
+            type: {
+              option: "pubkey";
+            };
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "closedAirdrop";
+      docs: [
+        "Left at `[CLOSED_AIRDROP_SEED, merkle_root]` when an airdrop is closed. Its claim receipts",
+        "may be closed afterwards, so the merkle root PDA must never be created again: a fresh",
+        "deployment at the same address would pay every recipient a second time."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "closedAt";
+            type: "i64";
+          },
+          {
+            name: "authority";
+            docs: [
+              "The airdrop's authority, who may still close its claim bitmaps"
+            ];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "config";
+      docs: [
+        "Program-wide settings, a singleton PDA at `[CONFIG_SEED]`. Until it is initialized no fees",
+        "are charged."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "admin";
+            docs: [
+              "May change any of these settings, including handing over the admin role"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "feeRecipient";
+            docs: [
+              "Wallet that receives lamport fees and owns the token accounts receiving token fees"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "fee";
+            type: {
+              defined: {
+                name: "protocolFee";
+              };
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Emitted when the config is initialized and whenever the admin changes it"
+      ];
+      name: "configUpdated";
+      type: {
+        fields: [
+          {
+            name: "config";
+            type: "pubkey";
+          },
+          {
+            name: "admin";
+            type: "pubkey";
+          },
+          {
+            name: "feeRecipient";
+            type: "pubkey";
+          },
+          {
+            docs: ["0 unless the fee is charged on deposits"];
+            name: "fundedFeeBps";
+            type: "u16";
+          },
+          {
+            docs: ["0 unless the fee is charged per claim"];
+            name: "claimFeeLamports";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "createAirdropParams";
+      docs: ["Airdrop settings fixed at creation time."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "expiresAt";
+            docs: ["When the authority may claw back unclaimed tokens"];
+            type: "i64";
+          },
+          {
+            name: "claimStart";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "claimEnd";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "vesting";
+            type: {
+              option: {
+                defined: {
+                  name: "vestingSchedule";
+                };
+              };
+            };
+          },
+          {
+            name: "cumulative";
+            docs: [
+              "Allow the authority to publish new cumulative roots with `update_root`"
+            ];
+            type: "bool";
+          },
+          {
+            name: "hashVersion";
+            docs: [
+              "Leaf and node hashing scheme the tree was built with (`HASH_VERSION_*`)"
+            ];
+            type: "u8";
+          },
+          {
+            name: "leafEncoding";
+            docs: [
+              "How leaves encode the claimant's address (`LEAF_ENCODING_*`)"
+            ];
+            type: "u8";
+          },
+          {
+            name: "voucherSigner";
+            docs: [
+              "Make this a voucher airdrop signed by this key; `merkle_root_hash` then only identifies it"
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "bitmapLeafCount";
+            docs: [
+              "Track claims in bitmap accounts sized for this many leaves instead of per-claimer receipts"
+            ];
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "nonce";
+            docs: [
+              "Distinguishes airdrops of the same root by the same creator"
+            ];
+            type: "u64";
+          },
+          {
+            name: "multiMint";
+            docs: [
+              "Leaves name their mint; vaults for mints other than `mint` are opened by `fund_airdrop`"
+            ];
+            type: "bool";
+          },
+          {
+            name: "numLeaves";
+            docs: [
+              "Leaves in the tree, recorded for accounting; 0 for voucher airdrops"
+            ];
+            type: "u32";
+          },
+          {
+            name: "gate";
+            docs: [
+              "Only pay claimants holding at least a minimum balance of a token"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "claimGate";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "merkleRoot";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "hash";
+            docs: ["Current root that claims are verified against"];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "seedHash";
+            docs: [
+              "Root the PDA was derived from; stays fixed when a cumulative airdrop publishes new roots"
+            ];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
+          {
+            name: "claimStart";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "claimEnd";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "vesting";
+            type: {
+              option: {
+                defined: {
+                  name: "vestingSchedule";
+                };
+              };
+            };
+          },
+          {
+            name: "cumulative";
+            docs: [
+              "Leaves commit to lifetime totals and the authority may publish new roots"
+            ];
+            type: "bool";
+          },
+          {
+            name: "epoch";
+            type: "u32";
+          },
+          {
+            name: "paused";
+            docs: ["Set by the authority to halt claims in an emergency"];
+            type: "bool";
+          },
+          {
+            name: "hashVersion";
+            docs: [
+              "Leaf and node hashing scheme, one of the `HASH_VERSION_*` constants"
+            ];
+            type: "u8";
+          },
+          {
+            name: "leafEncoding";
+            docs: [
+              "How leaves commit to the claimant's address, one of the `LEAF_ENCODING_*` constants"
+            ];
+            type: "u8";
+          },
+          {
+            name: "voucherSigner";
+            docs: [
+              "Set for voucher airdrops: claims carry an ed25519 voucher from this key instead of a merkle proof"
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "bitmapLeafCount";
+            docs: [
+              "Set for bitmap airdrops: claims flip a bit in `ClaimBitmap` accounts instead of creating receipts"
+            ];
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "creator";
+            docs: ["Creator the PDA is namespaced under"];
+            type: "pubkey";
+          },
+          {
+            name: "nonce";
+            docs: [
+              "Creator-chosen nonce as it appears in the PDA seeds (little-endian u64)"
+            ];
+            type: {
+              array: ["u8", 8];
+            };
+          },
+          {
+            name: "multiMint";
+            docs: [
+              "Leaves commit to `(claimant, mint, amount)` and each mint has its own vault;",
+              "`mint` is then just the one funded at creation"
+            ];
+            type: "bool";
+          },
+          {
+            name: "nativeSol";
+            docs: [
+              "Pays out lamports held by this account instead of SPL tokens; `mint` is then unused"
+            ];
+            type: "bool";
+          },
+          {
+            name: "totalAmount";
+            docs: [
+              "Everything that has reached the vault, net of any transfer fee on the way in.",
+              "Stays 0 for multi-mint airdrops, whose base units of different mints cannot be added up."
+            ];
+            type: "u64";
+          },
+          {
+            name: "claimedAmount";
+            docs: [
+              "Everything claims have taken out of the vault, including transfer fees they covered.",
+              "Stays 0 for multi-mint airdrops."
+            ];
+            type: "u64";
+          },
+          {
+            name: "numClaims";
+            docs: [
+              "Successful claim instructions; each installment of a vesting or cumulative claim counts"
+            ];
+            type: "u64";
+          },
+          {
+            name: "numLeaves";
+            docs: ["Leaves in the current tree; 0 for voucher airdrops"];
+            type: "u32";
+          },
+          {
+            name: "mintOnClaim";
+            docs: [
+              "Claims mint `mint` to the recipient, this account being its mint authority, instead of",
+              "paying from a vault; `total_amount` is then the declared cap on what may be minted"
+            ];
+            type: "bool";
+          },
+          {
+            name: "gate";
+            docs: [
+              "Claimants must hold this token when they claim, not just at snapshot time"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "claimGate";
+                };
+              };
+            };
+          },
+          {
+            name: "openVaults";
+            docs: [
+              "Vaults of a multi-mint airdrop's additional mints that `close_vault` has yet to close"
+            ];
+            type: "u16";
+          }
+        ];
+      };
+    },
+    {
+      name: "metadataClosed";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "metadata";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "metadataParams";
+      docs: [
+        "Metadata fields as given to `update_metadata`; empty strings leave a field blank."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "descriptionUri";
+            type: "string";
+          },
+          {
+            name: "imageUri";
+            type: "string";
+          },
+          {
+            name: "website";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "metadataUpdated";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "metadata";
+            type: "pubkey";
+          },
+          {
+            name: "name";
+            type: "string";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "pauseSet";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "paused";
+            type: "bool";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "protocolFee";
+      docs: [
+        "What the program charges for its use, paid to the config's fee recipient."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "none";
+          },
+          {
+            name: "fundedBps";
+            fields: ["u16"];
+          },
+          {
+            name: "claimLamports";
+            fields: ["u64"];
+          }
+        ];
+      };
+    },
+    {
+      name: "protocolFeePaid";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "payer";
+            type: "pubkey";
+          },
+          {
+            name: "feeRecipient";
+            type: "pubkey";
+          },
+          {
+            docs: ["`Pubkey::default()` when the fee is paid in lamports"];
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "rootUpdated";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "root";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "epoch";
+            type: "u32";
+          },
+          {
+            docs: ["Top-up deposited alongside the new root"];
+            name: "amount";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "vaultClosed";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "mint";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "vaultRecord";
+      docs: [
+        "Kept at `[VAULT_RECORD_SEED, merkle_root, mint]` while a multi-mint airdrop has a vault for",
+        "one of its additional mints, so the airdrop cannot be closed over tokens only it can sign for."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            docs: ["Default until `fund_airdrop` first opens the vault"];
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      name: "vestingSchedule";
+      docs: [
+        "Linear unlock between `start` and `end`, with nothing claimable before `cliff`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "start";
+            type: "i64";
+          },
+          {
+            name: "cliff";
+            type: "i64";
+          },
+          {
+            name: "end";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "voucher";
+      docs: [
+        "Off-chain authorization for `recipient` to claim `amount` from a voucher airdrop."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "recipient";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "nonce";
+            docs: [
+              "Unique per voucher; keys the claim receipt so each voucher is redeemed once"
+            ];
+            type: "u64";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "voucherClaimed";
+      type: {
+        fields: [
+          {
+            name: "merkleRoot";
+            type: "pubkey";
+          },
+          {
+            name: "recipient";
+            type: "pubkey";
+          },
+          {
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+        kind: "struct";
       };
     }
   ];
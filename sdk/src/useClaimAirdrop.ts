@@ -1,6 +1,7 @@
 import { useAirdropProgram } from "./useAirdropProgram";
 import { useAnchorWallet, useConnection } from "@solana/wallet-adapter-react";
 import { useCallback } from "react";
+import type { PublicKey } from "@solana/web3.js";
 import type { UseClaimAirdropOptions } from "./types";
 import { claimAirdrop, fetchClaimData, numberArrayToHex } from "./core";
 
@@ -11,7 +12,7 @@ export function useClaimAirdrop(options: UseClaimAirdropOptions) {
   const { connection } = useConnection();
 
   const claim = useCallback(
-    async (merkleRootHash: number[]) => {
+    async (merkleRootHash: number[], creator: PublicKey, nonce: number = 0) => {
       if (!program || !wallet) {
         throw new Error("Program or wallet not initialized");
       }
@@ -30,6 +31,8 @@ export function useClaimAirdrop(options: UseClaimAirdropOptions) {
         signer: wallet,
         program,
         merkleRootHash,
+        creator,
+        nonce,
         claimData,
       });
     },
@@ -9,7 +9,12 @@ export function useCreateAirdrop() {
   const { connection } = useConnection();
 
   const create = useCallback(
-    async (merkleRootHash: number[], amount: number) => {
+    async (
+      merkleRootHash: number[],
+      amount: number,
+      numLeaves: number,
+      nonce: number = 0
+    ) => {
       if (!program || !wallet) {
         throw new Error("Program or wallet not initialized");
       }
@@ -20,6 +25,8 @@ export function useCreateAirdrop() {
         program,
         merkleRootHash,
         amount,
+        numLeaves,
+        nonce,
       });
     },
     [program, wallet, connection]
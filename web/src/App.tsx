@@ -4,6 +4,7 @@ import {
   useClaimAirdrop,
 } from "@solana-easy-airdrop/react";
 import { WalletMultiButton } from "@solana/wallet-adapter-react-ui";
+import { PublicKey } from "@solana/web3.js";
 import { useState } from "react";
 import { MerkleRootInput } from "./components/MerkleRootInput";
 
@@ -17,6 +18,8 @@ function App() {
   const [merkleRootInput, setMerkleRootInput] = useState("");
   const [merkleRoot, setMerkleRoot] = useState<number[]>([]);
   const [airdropAmount, setAirdropAmount] = useState("");
+  const [numLeaves, setNumLeaves] = useState("");
+  const [creator, setCreator] = useState("");
 
   return (
     <div className="app-container">
@@ -38,6 +41,16 @@ function App() {
               placeholder="Enter token amount"
             />
           </div>
+          <div className="input-group">
+            <label htmlFor="num-leaves">Recipients:</label>
+            <input
+              id="num-leaves"
+              type="text"
+              value={numLeaves}
+              onChange={(e) => setNumLeaves(e.target.value)}
+              placeholder="Number of claims in the tree"
+            />
+          </div>
           <MerkleRootInput
             merkleRootInput={merkleRootInput}
             merkleRoot={merkleRoot}
@@ -46,7 +59,13 @@ function App() {
           />
           <button
             className="action-button"
-            onClick={() => createAirdrop(merkleRoot, Number(airdropAmount))}
+            onClick={() =>
+              createAirdrop(
+                merkleRoot,
+                Number(airdropAmount),
+                Number(numLeaves)
+              )
+            }
             disabled={merkleRoot.length !== 32}
           >
             Create Airdrop
@@ -55,10 +74,20 @@ function App() {
 
         <div className="section">
           <h2>Claim Airdrop</h2>
+          <div className="input-group">
+            <label htmlFor="creator">Creator:</label>
+            <input
+              id="creator"
+              type="text"
+              value={creator}
+              onChange={(e) => setCreator(e.target.value)}
+              placeholder="Address that created the airdrop"
+            />
+          </div>
           <button
             className="action-button"
-            onClick={() => claimAirdrop(merkleRoot)}
-            disabled={merkleRoot.length !== 32}
+            onClick={() => claimAirdrop(merkleRoot, new PublicKey(creator))}
+            disabled={merkleRoot.length !== 32 || !creator}
           >
             Claim Airdrop
           </button>